@@ -35,12 +35,15 @@ constructor(
             WpRequestResult.WpRequestSuccess(data = executeRequest(requestBuilder))
         } catch (exception: WpApiException) {
             when (exception) {
+                is WpApiException.InvalidBatchRequest -> WpRequestResult.InvalidBatchRequest(
+                    index = exception.index,
+                    reason = exception.reason,
+                )
                 is WpApiException.InvalidHttpStatusCode -> WpRequestResult.InvalidHttpStatusCode(
                     statusCode = exception.statusCode,
                 )
                 is WpApiException.RequestExecutionFailed -> WpRequestResult.RequestExecutionFailed(
-                    statusCode = exception.statusCode,
-                    reason = exception.reason
+                    error = exception.error,
                 )
                 is WpApiException.ResponseParsingException -> WpRequestResult.ResponseParsingError(
                     reason = exception.reason,
@@ -49,6 +52,14 @@ constructor(
                 is WpApiException.SiteUrlParsingException -> WpRequestResult.SiteUrlParsingError(
                     reason = exception.reason,
                 )
+                is WpApiException.TooManyBatchRequests -> WpRequestResult.TooManyBatchRequests(
+                    requestCount = exception.requestCount,
+                    maxRequests = exception.maxRequests,
+                )
+                is WpApiException.UnfollowableLink -> WpRequestResult.UnfollowableLink(
+                    href = exception.href,
+                    reason = exception.reason,
+                )
                 is WpApiException.UnknownException -> WpRequestResult.UnknownError(
                     statusCode = exception.statusCode,
                     response = exception.response,
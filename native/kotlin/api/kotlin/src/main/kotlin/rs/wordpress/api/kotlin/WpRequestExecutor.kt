@@ -3,9 +3,17 @@ package rs.wordpress.api.kotlin
 import kotlinx.coroutines.CoroutineDispatcher
 import kotlinx.coroutines.Dispatchers
 import kotlinx.coroutines.withContext
+import java.io.IOException
+import java.net.ConnectException
+import java.net.NoRouteToHostException
+import java.net.ProtocolException
+import java.net.SocketTimeoutException
+import java.net.UnknownHostException
+import javax.net.ssl.SSLException
 import okhttp3.OkHttpClient
 import okhttp3.Request
 import okhttp3.RequestBody.Companion.toRequestBody
+import uniffi.wp_api.RequestExecutionException
 import uniffi.wp_api.RequestExecutor
 import uniffi.wp_api.WpNetworkHeaderMap
 import uniffi.wp_api.WpNetworkRequest
@@ -29,12 +37,44 @@ class WpRequestExecutor(
                 }
             }
 
-            okHttpClient.newCall(requestBuilder.build()).execute().use { response ->
-                return@withContext WpNetworkResponse(
-                    body = response.body?.bytes() ?: ByteArray(0),
-                    statusCode = response.code.toUShort(),
-                    headerMap = WpNetworkHeaderMap.fromMultiMap(response.headers.toMultimap())
-                )
+            // The redirect response is returned as it is for requests such as the cookie authentication
+            // login, whose `Set-Cookie` headers would otherwise be lost
+            val client = if (request.followRedirects()) {
+                okHttpClient
+            } else {
+                okHttpClient.newBuilder().followRedirects(false).followSslRedirects(false).build()
             }
+            val call = client.newCall(requestBuilder.build())
+            try {
+                call.execute().use { response ->
+                    return@withContext WpNetworkResponse(
+                        body = response.body?.bytes() ?: ByteArray(0),
+                        statusCode = response.code.toUShort(),
+                        headerMap = WpNetworkHeaderMap.fromMultiMap(response.headers.toMultimap())
+                    )
+                }
+            } catch (exception: IOException) {
+                throw requestExecutionException(exception, isCanceled = call.isCanceled())
+            }
+        }
+
+    // Maps OkHttp's exceptions to `RequestExecutionException`, so the UI can tell the reason apart
+    private fun requestExecutionException(
+        exception: IOException,
+        isCanceled: Boolean
+    ): RequestExecutionException {
+        val reason = exception.toString()
+        return when {
+            isCanceled -> RequestExecutionException.RequestCancelled(reason = reason)
+            exception is UnknownHostException -> RequestExecutionException.HostNotFound(reason = reason)
+            exception is NoRouteToHostException -> RequestExecutionException.DeviceIsOffline(reason = reason)
+            exception is ConnectException -> RequestExecutionException.ConnectionFailed(reason = reason)
+            exception is SocketTimeoutException -> RequestExecutionException.RequestTimedOut(reason = reason)
+            exception is SSLException -> RequestExecutionException.InvalidSslCertificate(reason = reason)
+            // OkHttp throws a `ProtocolException` after too many follow-up requests
+            exception is ProtocolException && exception.message?.startsWith("Too many follow-up requests") == true ->
+                RequestExecutionException.TooManyRedirects(reason = reason)
+            else -> RequestExecutionException.RequestExecutionFailed(statusCode = null, reason = reason)
         }
+    }
 }
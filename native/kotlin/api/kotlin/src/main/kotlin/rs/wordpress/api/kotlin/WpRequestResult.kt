@@ -1,5 +1,8 @@
 package rs.wordpress.api.kotlin
 
+import uniffi.wp_api.InvalidBatchRequestReason
+import uniffi.wp_api.RequestExecutionException
+import uniffi.wp_api.UnfollowableLinkReason
 import uniffi.wp_api.WpErrorCode
 
 sealed class WpRequestResult<T> {
@@ -11,13 +14,17 @@ sealed class WpRequestResult<T> {
         val response: String,
     ) : WpRequestResult<T>()
 
+    class InvalidBatchRequest<T>(
+        val index: ULong,
+        val reason: InvalidBatchRequestReason,
+    ) : WpRequestResult<T>()
+
     class InvalidHttpStatusCode<T>(
         val statusCode: UShort
     ) : WpRequestResult<T>()
 
     class RequestExecutionFailed<T>(
-        val statusCode: UShort?,
-        val reason: String,
+        val error: RequestExecutionException,
     ) : WpRequestResult<T>()
 
     class SiteUrlParsingError<T>(
@@ -29,6 +36,16 @@ sealed class WpRequestResult<T> {
         val response: String,
     ) : WpRequestResult<T>()
 
+    class TooManyBatchRequests<T>(
+        val requestCount: ULong,
+        val maxRequests: ULong,
+    ) : WpRequestResult<T>()
+
+    class UnfollowableLink<T>(
+        val href: String,
+        val reason: UnfollowableLinkReason,
+    ) : WpRequestResult<T>()
+
     class UnknownError<T>(
         val statusCode: UShort,
         val response: String,
@@ -29,11 +29,27 @@ extension URLSession: @retroactive RequestExecutor {}
 extension URLSession: SafeRequestExecutor {
 
     public func execute(_ request: WpNetworkRequest) async -> Result<WpNetworkResponse, RequestExecutionError> {
+        // The redirect response is returned as it is for requests such as the cookie authentication login,
+        // whose `Set-Cookie` headers would otherwise be lost. Redirects can only be refused by a session
+        // delegate, so these requests use a separate session.
+        guard request.followRedirects() else {
+            let session = URLSession(
+                configuration: configuration,
+                delegate: RedirectRefusingDelegate(),
+                delegateQueue: nil
+            )
+            defer { session.finishTasksAndInvalidate() }
+            return await session.perform(request.asURLRequest())
+        }
+        return await perform(request.asURLRequest())
+    }
+
+    private func perform(_ urlRequest: URLRequest) async -> Result<WpNetworkResponse, RequestExecutionError> {
         let (data, response): (Data, URLResponse)
         do {
-            (data, response) = try await self.data(for: request.asURLRequest())
+            (data, response) = try await self.data(for: urlRequest)
         } catch {
-            return .failure(.RequestExecutionFailed(statusCode: nil, reason: error.localizedDescription))
+            return .failure(RequestExecutionError(error))
         }
 
         // swiftlint:disable force_cast
@@ -59,3 +75,48 @@ extension URLSession: SafeRequestExecutor {
         )
     }
 }
+
+private final class RedirectRefusingDelegate: NSObject, URLSessionTaskDelegate {
+    func urlSession(
+        _ session: URLSession,
+        task: URLSessionTask,
+        willPerformHTTPRedirection response: HTTPURLResponse,
+        newRequest request: URLRequest,
+        completionHandler: @escaping (URLRequest?) -> Void
+    ) {
+        completionHandler(nil)
+    }
+}
+
+extension RequestExecutionError {
+
+    // Maps `URLSession`'s errors, so the UI can tell the reason apart
+    public init(_ error: Error) {
+        let reason = error.localizedDescription
+        guard let urlError = error as? URLError else {
+            self = .RequestExecutionFailed(statusCode: nil, reason: reason)
+            return
+        }
+
+        switch urlError.code {
+        case .notConnectedToInternet, .networkConnectionLost, .dataNotAllowed, .internationalRoamingOff:
+            self = .DeviceIsOffline(reason: reason)
+        case .cannotFindHost, .dnsLookupFailed:
+            self = .HostNotFound(reason: reason)
+        case .cannotConnectToHost:
+            self = .ConnectionFailed(reason: reason)
+        case .serverCertificateUntrusted, .serverCertificateHasBadDate, .serverCertificateHasUnknownRoot,
+             .serverCertificateNotYetValid, .secureConnectionFailed, .clientCertificateRejected,
+             .clientCertificateRequired:
+            self = .InvalidSslCertificate(reason: reason)
+        case .timedOut:
+            self = .RequestTimedOut(reason: reason)
+        case .cancelled:
+            self = .RequestCancelled(reason: reason)
+        case .httpTooManyRedirects, .redirectToNonExistentLocation:
+            self = .TooManyRedirects(reason: reason)
+        default:
+            self = .RequestExecutionFailed(statusCode: nil, reason: reason)
+        }
+    }
+}
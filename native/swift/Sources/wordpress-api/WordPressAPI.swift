@@ -209,6 +209,7 @@ extension RequestMethod {
         case .put: "PUT"
         case .delete: "DELETE"
         case .head: "HEAD"
+        case .options: "OPTIONS"
         }
     }
 }
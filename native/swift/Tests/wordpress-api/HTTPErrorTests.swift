@@ -29,10 +29,14 @@ class HTTPErrorTests: XCTestCase {
             XCTAssertEqual(error.code, .timedOut)
         } catch {
             #if canImport(WordPressAPIInternal)
-            XCTAssertTrue(error is WordPressAPIInternal.WpApiError)
+            let apiError = error as? WordPressAPIInternal.WpApiError
             #else
-            XCTAssertTrue(error is WpApiError)
+            let apiError = error as? WpApiError
             #endif
+            guard case .RequestExecutionFailed(error: .RequestTimedOut)? = apiError else {
+                XCTFail("Unexpected error: \(error)")
+                return
+            }
         }
     }
 #endif
@@ -19,9 +19,8 @@ class HTTPStubs: SafeRequestExecutor {
         switch missingStub {
         case let .success(response):
             return .success(response)
-        case .failure:
-            // TODO: Translate error into the Rust type
-            return .failure(.RequestExecutionFailed(statusCode: nil, reason: ""))
+        case let .failure(error):
+            return .failure(RequestExecutionError(error))
         default:
             // TODO: Translate error into the Rust type
             return .failure(.RequestExecutionFailed(statusCode: nil, reason: ""))
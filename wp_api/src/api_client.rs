@@ -3,10 +3,21 @@ use crate::request::{
         application_passwords_endpoint::{
             ApplicationPasswordsRequestBuilder, ApplicationPasswordsRequestExecutor,
         },
+        batch_endpoint::{BatchRequestBuilder, BatchRequestExecutor},
+        categories_endpoint::{CategoriesRequestBuilder, CategoriesRequestExecutor},
+        comments_endpoint::{CommentsRequestBuilder, CommentsRequestExecutor},
+        custom_posts_endpoint::{CustomPostsRequestBuilder, CustomPostsRequestExecutor},
+        links_endpoint::{LinksRequestBuilder, LinksRequestExecutor},
+        media_endpoint::{MediaRequestBuilder, MediaRequestExecutor},
+        pages_endpoint::{PagesRequestBuilder, PagesRequestExecutor},
         plugins_endpoint::{PluginsRequestBuilder, PluginsRequestExecutor},
+        post_revisions_endpoint::{PostRevisionsRequestBuilder, PostRevisionsRequestExecutor},
         post_types_endpoint::{PostTypesRequestBuilder, PostTypesRequestExecutor},
         posts_endpoint::{PostsRequestBuilder, PostsRequestExecutor},
+        schema_endpoint::{SchemaRequestBuilder, SchemaRequestExecutor},
         site_settings_endpoint::{SiteSettingsRequestBuilder, SiteSettingsRequestExecutor},
+        tags_endpoint::{TagsRequestBuilder, TagsRequestExecutor},
+        taxonomies_endpoint::{TaxonomiesRequestBuilder, TaxonomiesRequestExecutor},
         users_endpoint::{UsersRequestBuilder, UsersRequestExecutor},
         wp_site_health_tests_endpoint::{
             WpSiteHealthTestsRequestBuilder, WpSiteHealthTestsRequestExecutor,
@@ -17,7 +28,9 @@ use crate::request::{
 };
 use crate::{
     api_client_generate_api_client, api_client_generate_endpoint_impl,
-    api_client_generate_request_builder, ParsedUrl, WpAuthentication,
+    api_client_generate_request_builder,
+    authentication::{AuthenticationProvider, ReauthenticatingRequestExecutor},
+    ParsedUrl, WpAuthentication,
 };
 use std::sync::Arc;
 
@@ -34,31 +47,73 @@ impl UniffiWpApiRequestBuilder {
             inner: WpApiRequestBuilder::new(site_url, authentication),
         }
     }
+
+    #[uniffi::constructor]
+    pub fn with_authentication_provider(
+        site_url: Arc<ParsedUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+    ) -> Self {
+        Self {
+            inner: WpApiRequestBuilder::with_authentication_provider(
+                site_url,
+                authentication_provider,
+            ),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct WpApiRequestBuilder {
     application_passwords: Arc<ApplicationPasswordsRequestBuilder>,
+    batch: Arc<BatchRequestBuilder>,
+    categories: Arc<CategoriesRequestBuilder>,
+    comments: Arc<CommentsRequestBuilder>,
+    custom_posts: Arc<CustomPostsRequestBuilder>,
+    links: Arc<LinksRequestBuilder>,
+    media: Arc<MediaRequestBuilder>,
+    pages: Arc<PagesRequestBuilder>,
     plugins: Arc<PluginsRequestBuilder>,
+    post_revisions: Arc<PostRevisionsRequestBuilder>,
     post_types: Arc<PostTypesRequestBuilder>,
     posts: Arc<PostsRequestBuilder>,
+    schema: Arc<SchemaRequestBuilder>,
     site_settings: Arc<SiteSettingsRequestBuilder>,
+    tags: Arc<TagsRequestBuilder>,
+    taxonomies: Arc<TaxonomiesRequestBuilder>,
     users: Arc<UsersRequestBuilder>,
     wp_site_health_tests: Arc<WpSiteHealthTestsRequestBuilder>,
 }
 
 impl WpApiRequestBuilder {
     pub fn new(site_url: Arc<ParsedUrl>, authentication: WpAuthentication) -> Self {
+        Self::with_authentication_provider(site_url, authentication.into())
+    }
+
+    pub fn with_authentication_provider(
+        site_url: Arc<ParsedUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+    ) -> Self {
         let api_base_url: Arc<ApiBaseUrl> = Arc::new(site_url.inner.clone().into());
         api_client_generate_request_builder!(
             api_base_url,
-            authentication;
+            authentication_provider;
             application_passwords,
+            batch,
+            categories,
+            comments,
+            custom_posts,
+            links,
+            media,
+            pages,
             plugins,
+            post_revisions,
             post_types,
             posts,
+            schema,
             users,
             site_settings,
+            tags,
+            taxonomies,
             wp_site_health_tests
         )
     }
@@ -81,15 +136,41 @@ impl UniffiWpApiClient {
             inner: WpApiClient::new(site_url, authentication, request_executor),
         }
     }
+
+    #[uniffi::constructor]
+    fn with_authentication_provider(
+        site_url: Arc<ParsedUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+        request_executor: Arc<dyn RequestExecutor>,
+    ) -> Self {
+        Self {
+            inner: WpApiClient::with_authentication_provider(
+                site_url,
+                authentication_provider,
+                request_executor,
+            ),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct WpApiClient {
     application_passwords: Arc<ApplicationPasswordsRequestExecutor>,
+    batch: Arc<BatchRequestExecutor>,
+    categories: Arc<CategoriesRequestExecutor>,
+    comments: Arc<CommentsRequestExecutor>,
+    custom_posts: Arc<CustomPostsRequestExecutor>,
+    links: Arc<LinksRequestExecutor>,
+    media: Arc<MediaRequestExecutor>,
+    pages: Arc<PagesRequestExecutor>,
     plugins: Arc<PluginsRequestExecutor>,
+    post_revisions: Arc<PostRevisionsRequestExecutor>,
     post_types: Arc<PostTypesRequestExecutor>,
     posts: Arc<PostsRequestExecutor>,
+    schema: Arc<SchemaRequestExecutor>,
     site_settings: Arc<SiteSettingsRequestExecutor>,
+    tags: Arc<TagsRequestExecutor>,
+    taxonomies: Arc<TaxonomiesRequestExecutor>,
     users: Arc<UsersRequestExecutor>,
     wp_site_health_tests: Arc<WpSiteHealthTestsRequestExecutor>,
 }
@@ -99,18 +180,51 @@ impl WpApiClient {
         site_url: Arc<ParsedUrl>,
         authentication: WpAuthentication,
         request_executor: Arc<dyn RequestExecutor>,
+    ) -> Self {
+        let authentication_provider: Arc<dyn AuthenticationProvider> = authentication.into();
+        Self::build(site_url, authentication_provider, request_executor)
+    }
+
+    // Unauthorized requests are retried once the `authentication_provider` reauthenticates.
+    pub fn with_authentication_provider(
+        site_url: Arc<ParsedUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+        request_executor: Arc<dyn RequestExecutor>,
+    ) -> Self {
+        let request_executor = Arc::new(ReauthenticatingRequestExecutor::new(
+            request_executor,
+            authentication_provider.clone(),
+        ));
+        Self::build(site_url, authentication_provider, request_executor)
+    }
+
+    fn build(
+        site_url: Arc<ParsedUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+        request_executor: Arc<dyn RequestExecutor>,
     ) -> Self {
         let api_base_url: Arc<ApiBaseUrl> = Arc::new(site_url.inner.clone().into());
 
         api_client_generate_api_client!(
             api_base_url,
-            authentication,
+            authentication_provider,
             request_executor;
             application_passwords,
+            batch,
+            categories,
+            comments,
+            custom_posts,
+            links,
+            media,
+            pages,
             plugins,
+            post_revisions,
             post_types,
             posts,
+            schema,
             site_settings,
+            tags,
+            taxonomies,
             users,
             wp_site_health_tests
         )
@@ -118,10 +232,21 @@ impl WpApiClient {
 }
 
 api_client_generate_endpoint_impl!(WpApi, application_passwords);
+api_client_generate_endpoint_impl!(WpApi, batch);
+api_client_generate_endpoint_impl!(WpApi, categories);
+api_client_generate_endpoint_impl!(WpApi, comments);
+api_client_generate_endpoint_impl!(WpApi, custom_posts);
+api_client_generate_endpoint_impl!(WpApi, links);
+api_client_generate_endpoint_impl!(WpApi, media);
+api_client_generate_endpoint_impl!(WpApi, pages);
 api_client_generate_endpoint_impl!(WpApi, plugins);
+api_client_generate_endpoint_impl!(WpApi, post_revisions);
 api_client_generate_endpoint_impl!(WpApi, post_types);
 api_client_generate_endpoint_impl!(WpApi, posts);
+api_client_generate_endpoint_impl!(WpApi, schema);
 api_client_generate_endpoint_impl!(WpApi, site_settings);
+api_client_generate_endpoint_impl!(WpApi, tags);
+api_client_generate_endpoint_impl!(WpApi, taxonomies);
 api_client_generate_endpoint_impl!(WpApi, users);
 api_client_generate_endpoint_impl!(WpApi, wp_site_health_tests);
 
@@ -161,12 +286,12 @@ macro_rules! api_client_generate_endpoint_impl {
 
 #[macro_export]
 macro_rules! api_client_generate_request_builder {
-    ($api_base_url:ident, $authentication:ident; $($element:expr),*) => {
+    ($api_base_url:ident, $authentication_provider:ident; $($element:expr),*) => {
         paste::paste! {
             Self {
                 $($element: [<$element:camel RequestBuilder>]::new(
                     $api_base_url.clone(),
-                    $authentication.clone(),
+                    $authentication_provider.clone(),
                 )
                 .into(),)*
             }
@@ -176,12 +301,12 @@ macro_rules! api_client_generate_request_builder {
 
 #[macro_export]
 macro_rules! api_client_generate_api_client {
-    ($api_base_url:ident, $authentication:ident, $request_executor:ident; $($element:expr),*) => {
+    ($api_base_url:ident, $authentication_provider:ident, $request_executor:ident; $($element:expr),*) => {
         paste::paste! {
             Self {
                 $($element: [<$element:camel RequestExecutor>]::new(
                     $api_base_url.clone(),
-                    $authentication.clone(),
+                    $authentication_provider.clone(),
                     $request_executor.clone(),
                 )
                 .into(),)*
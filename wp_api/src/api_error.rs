@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::request::request_or_response_body_as_string;
 
@@ -12,21 +12,35 @@ where
 
 #[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
 pub enum WpApiError {
+    #[error("Request {} of the batch can't be batched: {:?}", index, reason)]
+    InvalidBatchRequest {
+        index: u64,
+        reason: InvalidBatchRequestReason,
+    },
     #[error("Status code ({}) is not valid", status_code)]
     InvalidHttpStatusCode { status_code: u16 },
-    #[error(
-        "Request execution failed!\nStatus Code: '{:?}'.\nResponse: '{}'",
-        status_code,
-        reason
-    )]
-    RequestExecutionFailed {
-        status_code: Option<u16>,
-        reason: String,
-    },
+    // The request couldn't be sent or its response couldn't be received, such as when the device is
+    // offline
+    #[error(transparent)]
+    RequestExecutionFailed { error: RequestExecutionError },
     #[error("Error while parsing. \nReason: {}\nResponse: {}", reason, response)]
     ResponseParsingError { reason: String, response: String },
     #[error("Error while parsing site url: {}", reason)]
     SiteUrlParsingError { reason: String },
+    #[error(
+        "A batch can have at most {} requests, but it has {}",
+        max_requests,
+        request_count
+    )]
+    TooManyBatchRequests {
+        request_count: u64,
+        max_requests: u64,
+    },
+    #[error("Link '{}' can't be followed: {:?}", href, reason)]
+    UnfollowableLink {
+        href: String,
+        reason: UnfollowableLinkReason,
+    },
     #[error(
         "Error that's not yet handled by the library:\nStatus Code: '{}'.\nResponse: '{}'",
         status_code,
@@ -81,6 +95,28 @@ impl ParsedRequestError for WpApiError {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum InvalidBatchRequestReason {
+    // Only JSON bodies can be batched, so requests such as media uploads can't be
+    NonJsonBody,
+    // The batched requests are sent as paths relative to the api root of the batch request
+    NotUnderApiRoot,
+    // `?rest_route=` urls don't have a path that can be batched
+    RestRouteUrl,
+    // WordPress only batches `POST`, `PUT`, `PATCH` & `DELETE` requests
+    UnsupportedMethod,
+}
+
+#[derive(Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum UnfollowableLinkReason {
+    InvalidUrl,
+    // The requests are authenticated, so only the links to the same site as the api base url can
+    // be followed
+    DifferentOrigin,
+    // Templated links, such as `curies`, need to be expanded first
+    Templated,
+}
+
 // This type is used to parse the API errors. It then gets converted to `WpApiError::WpError`.
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct WpError {
@@ -94,7 +130,7 @@ impl WpError {
     }
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq, uniffi::Error)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, uniffi::Error)]
 pub enum WpErrorCode {
     #[serde(rename = "rest_already_trashed")]
     AlreadyTrashed,
@@ -136,6 +172,12 @@ pub enum WpErrorCode {
     CannotViewPlugin,
     #[serde(rename = "rest_cannot_view_plugins")]
     CannotViewPlugins,
+    #[serde(rename = "rest_comment_content_invalid")]
+    CommentContentInvalid,
+    #[serde(rename = "rest_comment_invalid_id")]
+    CommentInvalidId,
+    #[serde(rename = "rest_comment_invalid_post_id")]
+    CommentInvalidPostId,
     #[serde(rename = "empty_content")]
     EmptyContent,
     #[serde(rename = "rest_forbidden_context")]
@@ -162,6 +204,12 @@ pub enum WpErrorCode {
     PostInvalidId,
     #[serde(rename = "rest_post_invalid_page_number")]
     PostInvalidPageNumber,
+    #[serde(rename = "rest_taxonomy_invalid")]
+    TaxonomyInvalid,
+    #[serde(rename = "term_exists")]
+    TermExists,
+    #[serde(rename = "rest_term_invalid")]
+    TermInvalid,
     #[serde(rename = "rest_type_invalid")]
     TypeInvalid,
     #[serde(rename = "rest_not_logged_in")]
@@ -293,8 +341,9 @@ pub enum WpErrorCode {
     CustomError(String),
 }
 
-#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error, uniffi::Error)]
 pub enum RequestExecutionError {
+    // Any other failure, such as an unexpected error of the networking library
     #[error(
         "Request execution failed!\nStatus Code: '{:?}'.\nResponse: '{}'",
         status_code,
@@ -304,18 +353,28 @@ pub enum RequestExecutionError {
         status_code: Option<u16>,
         reason: String,
     },
+    #[error("Device is offline!\nReason: '{}'", reason)]
+    DeviceIsOffline { reason: String },
+    #[error("Host couldn't be found!\nReason: '{}'", reason)]
+    HostNotFound { reason: String },
+    #[error("Connection to the host failed!\nReason: '{}'", reason)]
+    ConnectionFailed { reason: String },
+    #[error("SSL certificate of the host is invalid!\nReason: '{}'", reason)]
+    InvalidSslCertificate { reason: String },
+    #[error("Request timed out!\nReason: '{}'", reason)]
+    RequestTimedOut { reason: String },
+    #[error("Request was cancelled!\nReason: '{}'", reason)]
+    RequestCancelled { reason: String },
+    #[error("Request was redirected too many times!\nReason: '{}'", reason)]
+    TooManyRedirects { reason: String },
+    // The `WpAuthentication` can't be sent as a header, such as when a token provided by the app
+    // contains a line break
+    #[error("Authentication is invalid!\nReason: '{}'", reason)]
+    InvalidAuthentication { reason: String },
 }
 
 impl From<RequestExecutionError> for WpApiError {
-    fn from(value: RequestExecutionError) -> Self {
-        match value {
-            RequestExecutionError::RequestExecutionFailed {
-                status_code,
-                reason,
-            } => Self::RequestExecutionFailed {
-                status_code,
-                reason,
-            },
-        }
+    fn from(error: RequestExecutionError) -> Self {
+        Self::RequestExecutionFailed { error }
     }
 }
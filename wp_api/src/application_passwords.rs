@@ -22,6 +22,10 @@ pub struct SparseApplicationPassword {
     #[WpContextualOption]
     #[WpContext(edit)]
     pub password: Option<String>,
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, uniffi::Record)]
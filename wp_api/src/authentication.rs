@@ -0,0 +1,504 @@
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use crate::{
+    api_error::{RequestExecutionError, WpError, WpErrorCode},
+    request::{
+        authentication_header_value, insert_authentication_headers, RequestExecutor,
+        WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
+    },
+    WpAuthentication,
+};
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+// Provides the authentication of every request, so apps can rotate application passwords or
+// tokens without rebuilding `WpApiClient`.
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait AuthenticationProvider: Send + Sync + Debug {
+    // Called whenever a request is built.
+    fn authentication(&self) -> WpAuthentication;
+
+    // Called when a request is rejected with a `401` status code or a `rest_not_logged_in` error.
+    // Returns `true` if the authentication is updated, in which case the request is retried once
+    // with the new authentication.
+    async fn reauthenticate(&self) -> bool;
+}
+
+// Always provides the same authentication.
+#[derive(Debug)]
+pub struct StaticAuthenticationProvider {
+    authentication: WpAuthentication,
+}
+
+impl StaticAuthenticationProvider {
+    pub fn new(authentication: WpAuthentication) -> Self {
+        Self { authentication }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthenticationProvider for StaticAuthenticationProvider {
+    fn authentication(&self) -> WpAuthentication {
+        self.authentication.clone()
+    }
+
+    async fn reauthenticate(&self) -> bool {
+        false
+    }
+}
+
+impl From<WpAuthentication> for Arc<dyn AuthenticationProvider> {
+    fn from(value: WpAuthentication) -> Self {
+        Arc::new(StaticAuthenticationProvider::new(value))
+    }
+}
+
+// Wraps a `RequestExecutor` to retry unauthorized requests once the `AuthenticationProvider`
+// reauthenticates. `WpApiClient::with_authentication_provider` uses it automatically.
+//
+// Concurrent unauthorized requests share a single reauthentication, so the provider doesn't log
+// in once for each of them.
+#[derive(Debug, uniffi::Object)]
+pub struct ReauthenticatingRequestExecutor {
+    inner: Arc<dyn RequestExecutor>,
+    authentication_provider: Arc<dyn AuthenticationProvider>,
+    // Incremented after each reauthentication
+    generation: AtomicU64,
+    // Held while reauthenticating, along with the result of the last reauthentication
+    last_reauthentication: futures::lock::Mutex<bool>,
+}
+
+#[uniffi::export]
+impl ReauthenticatingRequestExecutor {
+    #[uniffi::constructor]
+    pub fn new(
+        inner: Arc<dyn RequestExecutor>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+    ) -> Self {
+        Self {
+            inner,
+            authentication_provider,
+            generation: AtomicU64::new(0),
+            last_reauthentication: futures::lock::Mutex::new(false),
+        }
+    }
+}
+
+impl ReauthenticatingRequestExecutor {
+    // `generation` is the generation the unauthorized request was sent in. If another request has
+    // reauthenticated since then, its result is reused instead of reauthenticating again.
+    async fn reauthenticate(&self, generation: u64) -> bool {
+        let mut last_reauthentication = self.last_reauthentication.lock().await;
+        if self.generation.load(Ordering::Acquire) != generation {
+            return *last_reauthentication;
+        }
+        *last_reauthentication = self.authentication_provider.reauthenticate().await;
+        self.generation.fetch_add(1, Ordering::Release);
+        *last_reauthentication
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for ReauthenticatingRequestExecutor {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        let generation = self.generation.load(Ordering::Acquire);
+        let response = self.inner.execute(request.clone()).await?;
+        if !is_unauthorized(&response) || !self.reauthenticate(generation).await {
+            return Ok(response);
+        }
+        let mut header_map = request.header_map.as_header_map();
+        insert_authentication_headers(
+            &mut header_map,
+            &self.authentication_provider.authentication(),
+        )?;
+        self.inner
+            .execute(Arc::new(
+                request.with_header_map(WpNetworkHeaderMap::new(header_map).into()),
+            ))
+            .await
+    }
+}
+
+// Implemented by the app to refresh an expired `WpAuthentication::BearerToken`, for example by
+// exchanging an OAuth2 refresh token or by requesting a new JWT.
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait BearerTokenRefresher: Send + Sync + Debug {
+    // Returns the new token, or `None` if the token can't be refreshed, in which case the
+    // unauthorized response is returned as is.
+    async fn refresh_token(&self, expired_token: String) -> Option<String>;
+}
+
+// Wraps a `RequestExecutor` to refresh the bearer token when a request is rejected with a `401`
+// status code or a `rest_not_logged_in` error, then retries the request once with the new token.
+//
+// The request builders keep using the token they were created with, so the refreshed token
+// replaces the bearer token of every request that goes through this executor.
+//
+// Concurrent unauthorized requests share a single refresh, since refresh tokens are often rotated
+// and can only be used once.
+#[derive(Debug, uniffi::Object)]
+pub struct TokenRefreshingRequestExecutor {
+    inner: Arc<dyn RequestExecutor>,
+    token_refresher: Arc<dyn BearerTokenRefresher>,
+    refreshed_token: Mutex<Option<String>>,
+    refresh_lock: futures::lock::Mutex<()>,
+}
+
+#[uniffi::export]
+impl TokenRefreshingRequestExecutor {
+    #[uniffi::constructor]
+    pub fn new(
+        inner: Arc<dyn RequestExecutor>,
+        token_refresher: Arc<dyn BearerTokenRefresher>,
+    ) -> Self {
+        Self {
+            inner,
+            token_refresher,
+            refreshed_token: Mutex::new(None),
+            refresh_lock: futures::lock::Mutex::new(()),
+        }
+    }
+}
+
+impl TokenRefreshingRequestExecutor {
+    fn refreshed_token(&self) -> Option<String> {
+        self.refreshed_token
+            .lock()
+            .ok()
+            .and_then(|token| token.clone())
+    }
+
+    // If the token was already refreshed by another request while waiting for the lock, the new
+    // token is used instead of refreshing it again.
+    async fn refresh_token(&self, expired_token: String) -> Option<String> {
+        let _refresh_guard = self.refresh_lock.lock().await;
+        if let Some(token) = self
+            .refreshed_token()
+            .filter(|token| *token != expired_token)
+        {
+            return Some(token);
+        }
+        let token = self.token_refresher.refresh_token(expired_token).await?;
+        if let Ok(mut refreshed_token) = self.refreshed_token.lock() {
+            *refreshed_token = Some(token.clone());
+        }
+        Some(token)
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for TokenRefreshingRequestExecutor {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        let request = match self.refreshed_token() {
+            Some(token) => with_bearer_token(&request, &token)?,
+            None => request,
+        };
+        let response = self.inner.execute(request.clone()).await?;
+        if !is_unauthorized(&response) {
+            return Ok(response);
+        }
+        let Some(expired_token) = bearer_token(&request) else {
+            return Ok(response);
+        };
+        let Some(token) = self.refresh_token(expired_token).await else {
+            return Ok(response);
+        };
+        self.inner
+            .execute(with_bearer_token(&request, &token)?)
+            .await
+    }
+}
+
+fn is_unauthorized(response: &WpNetworkResponse) -> bool {
+    response.status_code == 401
+        || WpError::try_parse(&response.body, response.status_code)
+            .is_some_and(|wp_error| wp_error.code == WpErrorCode::Unauthorized)
+}
+
+fn bearer_token(request: &WpNetworkRequest) -> Option<String> {
+    request
+        .header_map
+        .as_header_map()
+        .get(http::header::AUTHORIZATION)
+        .and_then(|header_value| header_value.to_str().ok())
+        .and_then(|header_value| header_value.strip_prefix(BEARER_PREFIX))
+        .map(|token| token.to_string())
+}
+
+// Requests that aren't authenticated with a bearer token are returned as is.
+fn with_bearer_token(
+    request: &Arc<WpNetworkRequest>,
+    token: &str,
+) -> Result<Arc<WpNetworkRequest>, RequestExecutionError> {
+    if bearer_token(request).is_none() {
+        return Ok(request.clone());
+    }
+    let mut header_map = request.header_map.as_header_map();
+    header_map.insert(
+        http::header::AUTHORIZATION,
+        authentication_header_value(&format!("{}{}", BEARER_PREFIX, token))?,
+    );
+    Ok(Arc::new(request.with_header_map(
+        WpNetworkHeaderMap::new(header_map).into(),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::{users_endpoint::UsersRequestBuilder, ApiBaseUrl};
+    use rstest::*;
+
+    // Only accepts the `valid` token and records the tokens of the requests it receives
+    #[derive(Debug, Default)]
+    struct TestRequestExecutor {
+        received_tokens: Mutex<Vec<Option<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl RequestExecutor for TestRequestExecutor {
+        async fn execute(
+            &self,
+            request: Arc<WpNetworkRequest>,
+        ) -> Result<WpNetworkResponse, RequestExecutionError> {
+            let token = bearer_token(&request);
+            let is_valid = token.as_deref() == Some("valid");
+            self.received_tokens.lock().unwrap().push(token);
+            yield_now().await;
+            Ok(WpNetworkResponse {
+                body: if is_valid {
+                    b"{}".to_vec()
+                } else {
+                    br#"{"code":"rest_not_logged_in","message":"You are not currently logged in.","data":{"status":401}}"#.to_vec()
+                },
+                status_code: if is_valid { 200 } else { 401 },
+                header_map: Arc::new(WpNetworkHeaderMap::default()),
+            })
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct TestBearerTokenRefresher {
+        token: Option<String>,
+        expired_tokens: Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl BearerTokenRefresher for TestBearerTokenRefresher {
+        async fn refresh_token(&self, expired_token: String) -> Option<String> {
+            self.expired_tokens.lock().unwrap().push(expired_token);
+            yield_now().await;
+            self.token.clone()
+        }
+    }
+
+    // Lets the other futures run, so concurrent requests are all sent before any of them is
+    // rejected
+    async fn yield_now() {
+        let mut has_yielded = false;
+        futures::future::poll_fn(|cx| {
+            if has_yielded {
+                std::task::Poll::Ready(())
+            } else {
+                has_yielded = true;
+                cx.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+        })
+        .await
+    }
+
+    // Starts with the `expired` token and switches to the `valid` one once it reauthenticates
+    #[derive(Debug)]
+    struct TestAuthenticationProvider {
+        token: Mutex<String>,
+        reauthentication_count: AtomicU64,
+    }
+
+    #[async_trait::async_trait]
+    impl AuthenticationProvider for TestAuthenticationProvider {
+        fn authentication(&self) -> WpAuthentication {
+            WpAuthentication::BearerToken {
+                token: self.token.lock().unwrap().clone(),
+            }
+        }
+
+        async fn reauthenticate(&self) -> bool {
+            self.reauthentication_count.fetch_add(1, Ordering::Relaxed);
+            yield_now().await;
+            *self.token.lock().unwrap() = "valid".to_string();
+            true
+        }
+    }
+
+    #[fixture]
+    fn fixture_api_base_url() -> Arc<ApiBaseUrl> {
+        ApiBaseUrl::try_from("https://example.com").unwrap().into()
+    }
+
+    #[rstest]
+    fn refresh_expired_token(fixture_api_base_url: Arc<ApiBaseUrl>) {
+        let inner = Arc::new(TestRequestExecutor::default());
+        let executor = TokenRefreshingRequestExecutor::new(
+            inner.clone(),
+            Arc::new(TestBearerTokenRefresher {
+                token: Some("valid".to_string()),
+                ..Default::default()
+            }),
+        );
+        let request = Arc::new(request(fixture_api_base_url, "expired"));
+        let response = futures::executor::block_on(executor.execute(request.clone())).unwrap();
+        assert_eq!(response.status_code, 200);
+        // The refreshed token is used for the following requests
+        let response = futures::executor::block_on(executor.execute(request)).unwrap();
+        assert_eq!(response.status_code, 200);
+        assert_eq!(
+            *inner.received_tokens.lock().unwrap(),
+            vec![
+                Some("expired".to_string()),
+                Some("valid".to_string()),
+                Some("valid".to_string())
+            ]
+        );
+    }
+
+    #[rstest]
+    fn concurrent_unauthorized_requests_refresh_token_once(fixture_api_base_url: Arc<ApiBaseUrl>) {
+        let inner = Arc::new(TestRequestExecutor::default());
+        let token_refresher = Arc::new(TestBearerTokenRefresher {
+            token: Some("valid".to_string()),
+            ..Default::default()
+        });
+        let executor = TokenRefreshingRequestExecutor::new(inner.clone(), token_refresher.clone());
+        let request = Arc::new(request(fixture_api_base_url, "expired"));
+        let responses = futures::executor::block_on(futures::future::join_all(
+            (0..3).map(|_| executor.execute(request.clone())),
+        ));
+        assert!(responses
+            .into_iter()
+            .all(|response| response.unwrap().status_code == 200));
+        assert_eq!(
+            *token_refresher.expired_tokens.lock().unwrap(),
+            vec!["expired".to_string()]
+        );
+        assert_eq!(inner.received_tokens.lock().unwrap().len(), 6);
+    }
+
+    #[rstest]
+    fn unauthorized_response_is_returned_if_token_is_not_refreshed(
+        fixture_api_base_url: Arc<ApiBaseUrl>,
+    ) {
+        let inner = Arc::new(TestRequestExecutor::default());
+        let executor = TokenRefreshingRequestExecutor::new(
+            inner.clone(),
+            Arc::new(TestBearerTokenRefresher::default()),
+        );
+        let response = futures::executor::block_on(
+            executor.execute(Arc::new(request(fixture_api_base_url, "expired"))),
+        )
+        .unwrap();
+        assert_eq!(response.status_code, 401);
+        assert_eq!(inner.received_tokens.lock().unwrap().len(), 1);
+    }
+
+    #[rstest]
+    fn reauthenticate_with_authentication_provider(fixture_api_base_url: Arc<ApiBaseUrl>) {
+        let inner = Arc::new(TestRequestExecutor::default());
+        let authentication_provider = Arc::new(TestAuthenticationProvider {
+            token: Mutex::new("expired".to_string()),
+            reauthentication_count: AtomicU64::new(0),
+        });
+        let executor =
+            ReauthenticatingRequestExecutor::new(inner.clone(), authentication_provider.clone());
+        let request_builder =
+            UsersRequestBuilder::new(fixture_api_base_url, authentication_provider);
+        let response = futures::executor::block_on(executor.execute(Arc::new(
+            request_builder.retrieve_me_with_edit_context().unwrap(),
+        )))
+        .unwrap();
+        assert_eq!(response.status_code, 200);
+        // The provider is queried for each request
+        assert_eq!(
+            bearer_token(&request_builder.retrieve_me_with_edit_context().unwrap()).as_deref(),
+            Some("valid")
+        );
+        assert_eq!(
+            *inner.received_tokens.lock().unwrap(),
+            vec![Some("expired".to_string()), Some("valid".to_string())]
+        );
+    }
+
+    #[rstest]
+    fn concurrent_unauthorized_requests_reauthenticate_once(fixture_api_base_url: Arc<ApiBaseUrl>) {
+        let inner = Arc::new(TestRequestExecutor::default());
+        let authentication_provider = Arc::new(TestAuthenticationProvider {
+            token: Mutex::new("expired".to_string()),
+            reauthentication_count: AtomicU64::new(0),
+        });
+        let executor =
+            ReauthenticatingRequestExecutor::new(inner.clone(), authentication_provider.clone());
+        let request = Arc::new(
+            UsersRequestBuilder::new(fixture_api_base_url, authentication_provider.clone())
+                .retrieve_me_with_edit_context()
+                .unwrap(),
+        );
+        let responses = futures::executor::block_on(futures::future::join_all(
+            (0..3).map(|_| executor.execute(request.clone())),
+        ));
+        assert!(responses
+            .into_iter()
+            .all(|response| response.unwrap().status_code == 200));
+        assert_eq!(
+            authentication_provider
+                .reauthentication_count
+                .load(Ordering::Relaxed),
+            1
+        );
+        assert_eq!(inner.received_tokens.lock().unwrap().len(), 6);
+    }
+
+    #[rstest]
+    fn static_authentication_provider_does_not_reauthenticate(
+        fixture_api_base_url: Arc<ApiBaseUrl>,
+    ) {
+        let inner = Arc::new(TestRequestExecutor::default());
+        let authentication_provider: Arc<dyn AuthenticationProvider> =
+            WpAuthentication::BearerToken {
+                token: "expired".to_string(),
+            }
+            .into();
+        let executor = ReauthenticatingRequestExecutor::new(inner.clone(), authentication_provider);
+        let response = futures::executor::block_on(
+            executor.execute(Arc::new(request(fixture_api_base_url, "expired"))),
+        )
+        .unwrap();
+        assert_eq!(response.status_code, 401);
+        assert_eq!(inner.received_tokens.lock().unwrap().len(), 1);
+    }
+
+    fn request(api_base_url: Arc<ApiBaseUrl>, token: &str) -> WpNetworkRequest {
+        UsersRequestBuilder::new(
+            api_base_url,
+            WpAuthentication::BearerToken {
+                token: token.to_string(),
+            }
+            .into(),
+        )
+        .retrieve_me_with_edit_context()
+        .unwrap()
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use http::{HeaderMap, HeaderName, HeaderValue};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    api_error::InvalidBatchRequestReason,
+    request::{RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse},
+    WpApiError,
+};
+
+// WordPress rejects batches with more requests than this.
+pub const MAX_BATCH_REQUESTS: usize = 25;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, uniffi::Enum)]
+#[serde(rename_all = "kebab-case")]
+pub enum BatchValidation {
+    // Each request is validated & executed independently.
+    #[default]
+    Normal,
+    // None of the requests are executed unless all of them pass validation.
+    RequireAllValidate,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct BatchRequestBody {
+    validation: BatchValidation,
+    requests: Vec<BatchRequestItem>,
+}
+
+impl BatchRequestBody {
+    // `api_root` is the url the paths of the batched requests are relative to, for example
+    // `https://example.com/wp-json`.
+    pub(crate) fn new(
+        api_root: &str,
+        requests: &[Arc<WpNetworkRequest>],
+        validation: BatchValidation,
+    ) -> Result<Self, WpApiError> {
+        let requests = requests
+            .iter()
+            .enumerate()
+            .map(|(index, request)| {
+                BatchRequestItem::new(api_root, request).map_err(|reason| {
+                    WpApiError::InvalidBatchRequest {
+                        index: index as u64,
+                        reason,
+                    }
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            validation,
+            requests,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct BatchRequestItem {
+    method: &'static str,
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<serde_json::Value>,
+}
+
+impl BatchRequestItem {
+    fn new(api_root: &str, request: &WpNetworkRequest) -> Result<Self, InvalidBatchRequestReason> {
+        let method = request.method();
+        if !matches!(
+            method,
+            RequestMethod::POST | RequestMethod::PUT | RequestMethod::DELETE
+        ) {
+            return Err(InvalidBatchRequestReason::UnsupportedMethod);
+        }
+        let url = request.url().0;
+        if url::Url::parse(&url)
+            .is_ok_and(|url| url.query_pairs().any(|(name, _)| name == "rest_route"))
+        {
+            return Err(InvalidBatchRequestReason::RestRouteUrl);
+        }
+        let path = url
+            .strip_prefix(api_root.trim_end_matches('/'))
+            .filter(|path| path.starts_with('/'))
+            .ok_or(InvalidBatchRequestReason::NotUnderApiRoot)?;
+        let body = request
+            .body()
+            .map(|body| serde_json::from_slice(&body.contents()))
+            .transpose()
+            .map_err(|_| InvalidBatchRequestReason::NonJsonBody)?;
+        Ok(Self {
+            method: method.as_str(),
+            path: path.to_string(),
+            body,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BatchResponseBody {
+    #[serde(default)]
+    failed: Option<String>,
+    responses: Vec<Option<BatchResponseItem>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchResponseItem {
+    body: serde_json::Value,
+    status: u16,
+    // PHP encodes empty headers as an empty array instead of an object
+    #[serde(default)]
+    headers: serde_json::Value,
+}
+
+impl From<BatchResponseItem> for WpNetworkResponse {
+    fn from(value: BatchResponseItem) -> Self {
+        let headers = match value.headers {
+            serde_json::Value::Object(map) => map,
+            _ => serde_json::Map::new(),
+        };
+        let header_map = headers
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let value = match value {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                Some((
+                    HeaderName::from_bytes(name.as_bytes()).ok()?,
+                    HeaderValue::from_str(&value).ok()?,
+                ))
+            })
+            .collect::<HeaderMap>();
+        Self {
+            body: serde_json::to_vec(&value.body).unwrap_or_default(),
+            status_code: value.status,
+            header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct BatchResponse {
+    // Only set in `BatchValidation::RequireAllValidate` mode, if any of the requests failed
+    // validation. In that case, none of the requests are executed.
+    pub failed_validation: bool,
+    // The responses in the same order as the batched requests.
+    //
+    // If `failed_validation` is set, the requests that passed validation won't have a response.
+    pub responses: Vec<Option<WpNetworkResponse>>,
+}
+
+impl From<BatchResponseBody> for BatchResponse {
+    fn from(value: BatchResponseBody) -> Self {
+        Self {
+            failed_validation: value.failed.as_deref() == Some("validation"),
+            responses: value
+                .responses
+                .into_iter()
+                .map(|response| response.map(WpNetworkResponse::from))
+                .collect(),
+        }
+    }
+}
+
+impl BatchResponse {
+    // Parses the response of the request at `index`, returning `None` if it doesn't have one.
+    pub fn parse<T>(&self, index: usize) -> Option<Result<T, WpApiError>>
+    where
+        T: DeserializeOwned,
+    {
+        self.responses
+            .get(index)
+            .and_then(|response| response.as_ref())
+            .map(|response| response.parse())
+    }
+}
+
+impl<'de> Deserialize<'de> for BatchResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        BatchResponseBody::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl std::fmt::Debug for BatchResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchResponse")
+            .field("failed_validation", &self.failed_validation)
+            .field(
+                "responses",
+                &self
+                    .responses
+                    .iter()
+                    .map(|response| {
+                        response
+                            .as_ref()
+                            .map(|r| (r.status_code, r.body_as_string()))
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{posts::PostId, WpErrorCode};
+    use rstest::*;
+
+    #[derive(Debug, Deserialize)]
+    struct TestPost {
+        id: PostId,
+    }
+
+    #[rstest]
+    fn parse_batch_response() {
+        let batch_response: BatchResponse = serde_json::from_str(
+            r#"{"responses":[{"body":{"id":5},"status":201,"headers":{"Location":"https://example.com/wp-json/wp/v2/posts/5","Allow":"GET, POST"}},{"body":{"code":"rest_post_invalid_id","message":"Invalid post ID.","data":{"status":404}},"status":404,"headers":[]}]}"#,
+        )
+        .unwrap();
+        assert!(!batch_response.failed_validation);
+        assert_eq!(batch_response.responses.len(), 2);
+        let post = batch_response.parse::<TestPost>(0).unwrap().unwrap();
+        assert_eq!(post.id, PostId(5));
+        assert_eq!(
+            batch_response.responses[0]
+                .as_ref()
+                .and_then(|response| response.get_header("Location")),
+            Some("https://example.com/wp-json/wp/v2/posts/5")
+        );
+        assert!(matches!(
+            batch_response.parse::<TestPost>(1),
+            Some(Err(WpApiError::WpError {
+                error_code: WpErrorCode::PostInvalidId,
+                status_code: 404,
+                ..
+            }))
+        ));
+        assert!(batch_response.parse::<TestPost>(2).is_none());
+    }
+
+    #[rstest]
+    fn parse_batch_response_with_failed_validation() {
+        let batch_response: BatchResponse = serde_json::from_str(
+            r#"{"failed":"validation","responses":[null,{"body":{"code":"rest_invalid_param","message":"Invalid parameter(s): status","data":{"status":400}},"status":400,"headers":{}}]}"#,
+        )
+        .unwrap();
+        assert!(batch_response.failed_validation);
+        assert!(batch_response.parse::<TestPost>(0).is_none());
+        assert!(matches!(
+            batch_response.parse::<TestPost>(1),
+            Some(Err(WpApiError::WpError {
+                error_code: WpErrorCode::InvalidParam,
+                ..
+            }))
+        ));
+    }
+}
@@ -0,0 +1,298 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use http::HeaderValue;
+
+use crate::{
+    api_error::RequestExecutionError,
+    request::{
+        RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
+    },
+};
+
+// Stores the cached responses of `CachingRequestExecutor`, for example in memory or on disk.
+//
+// The keys include a hash of the request's credentials, not the credentials themselves.
+#[uniffi::export(with_foreign)]
+pub trait ResponseCacheStorage: Send + Sync + Debug {
+    fn get(&self, key: String) -> Option<WpNetworkResponse>;
+    fn set(&self, key: String, response: WpNetworkResponse);
+    fn remove(&self, key: String);
+}
+
+#[derive(Debug, Default, uniffi::Object)]
+pub struct InMemoryResponseCacheStorage {
+    responses: Mutex<HashMap<String, WpNetworkResponse>>,
+}
+
+#[uniffi::export]
+impl InMemoryResponseCacheStorage {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ResponseCacheStorage for InMemoryResponseCacheStorage {
+    fn get(&self, key: String) -> Option<WpNetworkResponse> {
+        self.responses
+            .lock()
+            .ok()
+            .and_then(|responses| responses.get(&key).cloned())
+    }
+
+    fn set(&self, key: String, response: WpNetworkResponse) {
+        if let Ok(mut responses) = self.responses.lock() {
+            responses.insert(key, response);
+        }
+    }
+
+    fn remove(&self, key: String) {
+        if let Ok(mut responses) = self.responses.lock() {
+            responses.remove(&key);
+        }
+    }
+}
+
+// Wraps a `RequestExecutor` to cache the responses of `GET` requests that have an `ETag` or a
+// `Last-Modified` header.
+//
+// Cached responses are always revalidated with an `If-None-Match` or `If-Modified-Since` header, and
+// they are returned in place of a `304 Not Modified` response, so they are never stale.
+#[derive(Debug, uniffi::Object)]
+pub struct CachingRequestExecutor {
+    inner: Arc<dyn RequestExecutor>,
+    storage: Arc<dyn ResponseCacheStorage>,
+}
+
+#[uniffi::export]
+impl CachingRequestExecutor {
+    #[uniffi::constructor]
+    pub fn new(inner: Arc<dyn RequestExecutor>, storage: Arc<dyn ResponseCacheStorage>) -> Self {
+        Self { inner, storage }
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for CachingRequestExecutor {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        if !matches!(request.method, RequestMethod::GET) {
+            return self.inner.execute(request).await;
+        }
+        let key = cache_key(&request);
+        let cached_response = self.storage.get(key.clone());
+        let request = match cached_response {
+            Some(ref cached_response) => conditional_request(&request, cached_response),
+            None => request,
+        };
+        let response = self.inner.execute(request).await?;
+        match (response.status_code, cached_response) {
+            (304, Some(cached_response)) => Ok(cached_response),
+            (200, _) if is_cacheable(&response) => {
+                self.storage.set(key, response.clone());
+                Ok(response)
+            }
+            (200, Some(_)) => {
+                self.storage.remove(key);
+                Ok(response)
+            }
+            _ => Ok(response),
+        }
+    }
+}
+
+// Responses depend on who is asking, so the key combines the url with a hash of the credentials.
+fn cache_key(request: &WpNetworkRequest) -> String {
+    let header_map = request.header_map.as_header_map();
+    let credentials = [http::header::AUTHORIZATION, http::header::COOKIE]
+        .iter()
+        .flat_map(|name| header_map.get_all(name))
+        .map(HeaderValue::as_bytes)
+        .collect::<Vec<_>>()
+        .join(&b'\n');
+    format!("{:016x}:{}", fnv1a_hash(&credentials), request.url.0)
+}
+
+// Unlike `DefaultHasher`, the FNV-1a hash is stable across releases & processes, which is
+// important for on-disk storage.
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn conditional_request(
+    request: &Arc<WpNetworkRequest>,
+    cached_response: &WpNetworkResponse,
+) -> Arc<WpNetworkRequest> {
+    let cached_header_map = cached_response.header_map.as_header_map();
+    let mut header_map = request.header_map.as_header_map();
+    if let Some(etag) = cached_header_map.get(http::header::ETAG) {
+        header_map.insert(http::header::IF_NONE_MATCH, etag.clone());
+    }
+    if let Some(last_modified) = cached_header_map.get(http::header::LAST_MODIFIED) {
+        header_map.insert(http::header::IF_MODIFIED_SINCE, last_modified.clone());
+    }
+    Arc::new(request.with_header_map(WpNetworkHeaderMap::new(header_map).into()))
+}
+
+fn is_cacheable(response: &WpNetworkResponse) -> bool {
+    let header_map = response.header_map.as_header_map();
+    let is_no_store = header_map
+        .get_all(http::header::CACHE_CONTROL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .any(|value| value.to_lowercase().contains("no-store"));
+    !is_no_store
+        && (header_map.contains_key(http::header::ETAG)
+            || header_map.contains_key(http::header::LAST_MODIFIED))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::endpoint::WpEndpointUrl;
+    use http::HeaderMap;
+    use rstest::*;
+
+    // Responds with `304` if the `If-None-Match` header matches the `ETag` of the current version
+    #[derive(Debug)]
+    struct TestRequestExecutor {
+        etag: Mutex<&'static str>,
+        requests: Mutex<Vec<Arc<WpNetworkRequest>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl RequestExecutor for TestRequestExecutor {
+        async fn execute(
+            &self,
+            request: Arc<WpNetworkRequest>,
+        ) -> Result<WpNetworkResponse, RequestExecutionError> {
+            self.requests.lock().unwrap().push(request.clone());
+            let etag = *self.etag.lock().unwrap();
+            let is_not_modified = request
+                .header_map
+                .as_header_map()
+                .get(http::header::IF_NONE_MATCH)
+                .is_some_and(|value| value == etag);
+            Ok(response(
+                if is_not_modified { 304 } else { 200 },
+                if is_not_modified { "" } else { etag },
+                &[("ETag", etag)],
+            ))
+        }
+    }
+
+    #[rstest]
+    fn serve_not_modified_responses_from_cache() {
+        let inner = Arc::new(TestRequestExecutor {
+            etag: Mutex::new("\"v1\""),
+            requests: Mutex::new(Vec::new()),
+        });
+        let executor = CachingRequestExecutor::new(
+            inner.clone(),
+            Arc::new(InMemoryResponseCacheStorage::new()),
+        );
+        let execute = || {
+            futures::executor::block_on(
+                executor.execute(Arc::new(request(RequestMethod::GET, "foo"))),
+            )
+            .unwrap()
+        };
+        assert_eq!(execute().body_as_string(), "\"v1\"");
+        let cached_response = execute();
+        assert_eq!(cached_response.status_code, 200);
+        assert_eq!(cached_response.body_as_string(), "\"v1\"");
+        *inner.etag.lock().unwrap() = "\"v2\"";
+        assert_eq!(execute().body_as_string(), "\"v2\"");
+        let if_none_match_headers = inner
+            .requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| {
+                request
+                    .header_map
+                    .as_header_map()
+                    .get(http::header::IF_NONE_MATCH)
+                    .map(|value| value.to_str().unwrap().to_string())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            if_none_match_headers,
+            vec![None, Some("\"v1\"".to_string()), Some("\"v1\"".to_string())]
+        );
+    }
+
+    #[rstest]
+    #[case(response(200, "", &[("ETag", "\"v1\"")]), true)]
+    #[case(response(200, "", &[("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")]), true)]
+    #[case(response(200, "", &[("ETag", "\"v1\""), ("Cache-Control", "private, no-store")]), false)]
+    #[case(response(200, "", &[]), false)]
+    fn test_is_cacheable(#[case] response: WpNetworkResponse, #[case] expected: bool) {
+        assert_eq!(is_cacheable(&response), expected);
+    }
+
+    #[rstest]
+    fn cache_key_depends_on_credentials() {
+        let key = |authorization: &str| cache_key(&request(RequestMethod::GET, authorization));
+        assert_eq!(key("foo"), key("foo"));
+        assert_ne!(key("foo"), key("bar"));
+        assert!(key("foo").ends_with(":https://example.com/wp-json/wp/v2/settings"));
+        assert!(!key("foo").contains("foo"));
+    }
+
+    #[rstest]
+    fn non_get_requests_are_not_cached() {
+        let storage = Arc::new(InMemoryResponseCacheStorage::new());
+        let executor = CachingRequestExecutor::new(
+            Arc::new(TestRequestExecutor {
+                etag: Mutex::new("\"v1\""),
+                requests: Mutex::new(Vec::new()),
+            }),
+            storage.clone(),
+        );
+        futures::executor::block_on(
+            executor.execute(Arc::new(request(RequestMethod::POST, "foo"))),
+        )
+        .unwrap();
+        assert!(storage.responses.lock().unwrap().is_empty());
+    }
+
+    fn request(method: RequestMethod, authorization: &str) -> WpNetworkRequest {
+        let mut header_map = HeaderMap::new();
+        header_map.insert(
+            http::header::AUTHORIZATION,
+            HeaderValue::from_str(authorization).unwrap(),
+        );
+        WpNetworkRequest {
+            method,
+            url: WpEndpointUrl("https://example.com/wp-json/wp/v2/settings".to_string()),
+            header_map: WpNetworkHeaderMap::new(header_map).into(),
+            body: None,
+            follow_redirects: true,
+        }
+    }
+
+    fn response(
+        status_code: u16,
+        body: &str,
+        headers: &[(&'static str, &'static str)],
+    ) -> WpNetworkResponse {
+        let mut header_map = HeaderMap::new();
+        headers.iter().for_each(|(name, value)| {
+            header_map.insert(*name, HeaderValue::from_static(value));
+        });
+        WpNetworkResponse {
+            body: body.as_bytes().to_vec(),
+            status_code,
+            header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
+        }
+    }
+}
@@ -0,0 +1,415 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+
+use crate::{
+    impl_as_query_value_for_new_type, impl_as_query_value_from_as_str, impl_pagination_params,
+    posts::PostId,
+    url_query::{AppendUrlQueryPairs, AsQueryValue, QueryPairs, QueryPairsExtension},
+    UserId, WpApiParamOrder,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum WpApiParamCommentsOrderBy {
+    #[default]
+    DateGmt,
+    Date,
+    Id,
+    Include,
+    Parent,
+    Post,
+    Type,
+}
+
+impl_as_query_value_from_as_str!(WpApiParamCommentsOrderBy);
+
+impl WpApiParamCommentsOrderBy {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::DateGmt => "date_gmt",
+            Self::Date => "date",
+            Self::Id => "id",
+            Self::Include => "include",
+            Self::Parent => "parent",
+            Self::Post => "post",
+            Self::Type => "type",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, uniffi::Record)]
+pub struct CommentListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Limit response to comments published after a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub after: Option<String>,
+    /// Limit result set to comments assigned to specific user IDs. Requires authorization.
+    #[uniffi(default = [])]
+    pub author: Vec<UserId>,
+    /// Ensure result set excludes comments assigned to specific user IDs. Requires authorization.
+    #[uniffi(default = [])]
+    pub author_exclude: Vec<UserId>,
+    /// Limit result set to that from a specific author email. Requires authorization.
+    #[uniffi(default = None)]
+    pub author_email: Option<String>,
+    /// Limit response to comments published before a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub before: Option<String>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<CommentId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<CommentId>,
+    /// Offset the result set by a specific number of items.
+    #[uniffi(default = None)]
+    pub offset: Option<u32>,
+    /// Order sort attribute ascending or descending.
+    /// Default: desc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Sort collection by comment attribute.
+    /// Default: date_gmt
+    /// One of: date, date_gmt, id, include, post, parent, type
+    #[uniffi(default = None)]
+    pub orderby: Option<WpApiParamCommentsOrderBy>,
+    /// Limit result set to comments of specific parent IDs.
+    #[uniffi(default = [])]
+    pub parent: Vec<CommentId>,
+    /// Ensure result set excludes specific parent IDs.
+    #[uniffi(default = [])]
+    pub parent_exclude: Vec<CommentId>,
+    /// Limit result set to comments assigned to specific post IDs.
+    #[uniffi(default = [])]
+    pub post: Vec<PostId>,
+    /// Limit result set to comments assigned a specific status. Requires authorization.
+    /// Default: approve
+    #[uniffi(default = None)]
+    pub status: Option<CommentStatus>,
+    /// Limit result set to comments assigned a specific type. Requires authorization.
+    /// Default: comment
+    #[uniffi(default = None)]
+    pub comment_type: Option<CommentType>,
+    /// The password for the post if it is password protected.
+    #[uniffi(default = None)]
+    pub password: Option<String>,
+}
+
+impl AppendUrlQueryPairs for CommentListParams {
+    fn append_query_pairs(&self, query_pairs_mut: &mut QueryPairs) {
+        query_pairs_mut
+            .append_option_query_value_pair("page", self.page.as_ref())
+            .append_option_query_value_pair("per_page", self.per_page.as_ref())
+            .append_option_query_value_pair("search", self.search.as_ref())
+            .append_option_query_value_pair("after", self.after.as_ref())
+            .append_vec_query_value_pair("author", &self.author)
+            .append_vec_query_value_pair("author_exclude", &self.author_exclude)
+            .append_option_query_value_pair("author_email", self.author_email.as_ref())
+            .append_option_query_value_pair("before", self.before.as_ref())
+            .append_vec_query_value_pair("exclude", &self.exclude)
+            .append_vec_query_value_pair("include", &self.include)
+            .append_option_query_value_pair("offset", self.offset.as_ref())
+            .append_option_query_value_pair("order", self.order.as_ref())
+            .append_option_query_value_pair("orderby", self.orderby.as_ref())
+            .append_vec_query_value_pair("parent", &self.parent)
+            .append_vec_query_value_pair("parent_exclude", &self.parent_exclude)
+            .append_vec_query_value_pair("post", &self.post)
+            .append_option_query_value_pair("status", self.status.as_ref())
+            .append_option_query_value_pair("type", self.comment_type.as_ref())
+            .append_option_query_value_pair("password", self.password.as_ref());
+    }
+}
+
+impl_pagination_params!(CommentListParams);
+
+#[derive(Debug, Default, uniffi::Record)]
+pub struct CommentRetrieveParams {
+    /// The password for the parent post of the comment (if the post is password protected).
+    #[uniffi(default = None)]
+    pub password: Option<String>,
+}
+
+impl AppendUrlQueryPairs for CommentRetrieveParams {
+    fn append_query_pairs(&self, query_pairs_mut: &mut QueryPairs) {
+        query_pairs_mut.append_option_query_value_pair("password", self.password.as_ref());
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct CommentDeleteResponse {
+    pub deleted: bool,
+    pub previous: CommentWithEditContext,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct CommentCreateParams {
+    // The ID of the user object, if author was a user.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserId>,
+    // Email address for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    // IP address for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_ip: Option<String>,
+    // Display name for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    // URL for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_url: Option<String>,
+    // User agent for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_user_agent: Option<String>,
+    // The content for the comment.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    // The date the comment was published, in the site's timezone.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    // The date the comment was published, as GMT.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_gmt: Option<String>,
+    // The ID for the parent of the comment.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<CommentId>,
+    // The ID of the associated post object.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<PostId>,
+    // State of the comment.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CommentStatus>,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct CommentUpdateParams {
+    // The ID of the user object, if author was a user.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserId>,
+    // Email address for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    // IP address for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_ip: Option<String>,
+    // Display name for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    // URL for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_url: Option<String>,
+    // User agent for the comment author.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_user_agent: Option<String>,
+    // The content for the comment.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    // The date the comment was published, in the site's timezone.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    // The date the comment was published, as GMT.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_gmt: Option<String>,
+    // The ID for the parent of the comment.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<CommentId>,
+    // The ID of the associated post object.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<PostId>,
+    // State of the comment.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CommentStatus>,
+}
+
+impl_as_query_value_for_new_type!(CommentId);
+uniffi::custom_newtype!(CommentId, i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentId(pub i32);
+
+impl std::fmt::Display for CommentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// The API returns `approved` for approved comments, but `approve` has to be used to filter them
+// in a list request. The remaining statuses are the same in both cases.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, uniffi::Enum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentStatus {
+    Approved,
+    Hold,
+    Spam,
+    Trash,
+    #[serde(untagged)]
+    Custom(String),
+}
+
+impl_as_query_value_from_as_str!(CommentStatus);
+
+impl CommentStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Approved => "approve",
+            Self::Hold => "hold",
+            Self::Spam => "spam",
+            Self::Trash => "trash",
+            Self::Custom(status) => status,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, uniffi::Enum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentType {
+    Comment,
+    Pingback,
+    Trackback,
+    #[serde(untagged)]
+    Custom(String),
+}
+
+impl_as_query_value_from_as_str!(CommentType);
+
+impl CommentType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Comment => "comment",
+            Self::Pingback => "pingback",
+            Self::Trackback => "trackback",
+            Self::Custom(comment_type) => comment_type,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseComment {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<CommentId>,
+    // `0` if the comment author is not a registered user
+    #[WpContext(edit, embed, view)]
+    pub author: Option<UserId>,
+    #[WpContext(edit)]
+    pub author_email: Option<String>,
+    #[WpContext(edit)]
+    pub author_ip: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub author_name: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub author_url: Option<String>,
+    #[WpContext(edit)]
+    pub author_user_agent: Option<String>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub content: Option<SparseCommentContent>,
+    #[WpContext(edit, embed, view)]
+    pub date: Option<String>,
+    #[WpContext(edit, view)]
+    pub date_gmt: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub link: Option<String>,
+    // `0` if the comment is not a reply
+    #[WpContext(edit, embed, view)]
+    pub parent: Option<CommentId>,
+    #[WpContext(edit, view)]
+    pub post: Option<PostId>,
+    #[WpContext(edit, view)]
+    pub status: Option<CommentStatus>,
+    #[serde(rename = "type")]
+    #[WpContext(edit, embed, view)]
+    pub comment_type: Option<CommentType>,
+    #[WpContext(edit, embed, view)]
+    // `author_avatar_urls` is only available if avatars are enabled in the discussion settings
+    #[WpContextualOption]
+    pub author_avatar_urls: Option<HashMap<String, String>>,
+    // meta field is omitted for now: https://github.com/Automattic/wordpress-rs/issues/57
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseCommentContent {
+    #[WpContext(edit)]
+    pub raw: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub rendered: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+
+    #[rstest]
+    #[case(CommentListParams::default(), "")]
+    #[case(generate!(CommentListParams, (post, vec![PostId(1), PostId(2)])), "post=1%2C2")]
+    #[case(generate!(CommentListParams, (parent, vec![CommentId(3)])), "parent=3")]
+    #[case(generate!(CommentListParams, (status, Some(CommentStatus::Approved))), "status=approve")]
+    #[case(generate!(CommentListParams, (status, Some(CommentStatus::Hold))), "status=hold")]
+    #[case(generate!(CommentListParams, (author_email, Some("foo@example.com".to_string()))), "author_email=foo%40example.com")]
+    #[case(generate!(CommentListParams, (comment_type, Some(CommentType::Pingback))), "type=pingback")]
+    #[case(generate!(CommentListParams, (orderby, Some(WpApiParamCommentsOrderBy::DateGmt))), "orderby=date_gmt")]
+    #[trace]
+    fn test_comment_list_params(#[case] params: CommentListParams, #[case] expected_query: &str) {
+        assert_expected_query_pairs(params, expected_query);
+    }
+
+    #[rstest]
+    #[case(r#""approved""#, CommentStatus::Approved)]
+    #[case(r#""hold""#, CommentStatus::Hold)]
+    #[case(r#""spam""#, CommentStatus::Spam)]
+    #[case(r#""trash""#, CommentStatus::Trash)]
+    #[case(r#""foo""#, CommentStatus::Custom("foo".to_string()))]
+    fn test_comment_status_deserialization(
+        #[case] json: &str,
+        #[case] expected_status: CommentStatus,
+    ) {
+        assert_eq!(
+            serde_json::from_str::<CommentStatus>(json).unwrap(),
+            expected_status
+        );
+    }
+}
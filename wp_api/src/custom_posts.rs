@@ -0,0 +1,262 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::OnceLock,
+};
+
+use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    post_types::PostTypeDetailsWithEditContext,
+    posts::{PostCreateParams, PostUpdateParams, SparsePost},
+};
+
+// Identifies the REST route of a custom post type, such as `wp/v2` & `product`.
+//
+// It's typically created from the `rest_namespace` & `rest_base` of a post type returned by the
+// `/types` endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct CustomPostType {
+    pub rest_namespace: String,
+    pub rest_base: String,
+}
+
+impl CustomPostType {
+    pub fn new(rest_namespace: impl Into<String>, rest_base: impl Into<String>) -> Self {
+        Self {
+            rest_namespace: rest_namespace.into(),
+            rest_base: rest_base.into(),
+        }
+    }
+}
+
+impl From<&PostTypeDetailsWithEditContext> for CustomPostType {
+    fn from(value: &PostTypeDetailsWithEditContext) -> Self {
+        Self::new(value.rest_namespace.clone(), value.rest_base.clone())
+    }
+}
+
+impl std::fmt::Display for CustomPostType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.rest_namespace, self.rest_base)
+    }
+}
+
+#[derive(Debug, Default, uniffi::Record)]
+pub struct CustomPostCreateParams {
+    pub post: PostCreateParams,
+    // Additional fields registered for the custom post type, such as `price` for a product.
+    //
+    // The values are raw JSON, for example `"\"foo\""` for a string or `"42"` for a number. A
+    // value that is not valid JSON will be sent as a string.
+    pub extra_fields: HashMap<String, String>,
+}
+
+impl Serialize for CustomPostCreateParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_with_extra_fields(&self.post, &self.extra_fields, serializer)
+    }
+}
+
+#[derive(Debug, Default, uniffi::Record)]
+pub struct CustomPostUpdateParams {
+    pub post: PostUpdateParams,
+    // See `CustomPostCreateParams::extra_fields`.
+    pub extra_fields: HashMap<String, String>,
+}
+
+impl Serialize for CustomPostUpdateParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_with_extra_fields(&self.post, &self.extra_fields, serializer)
+    }
+}
+
+fn serialize_with_extra_fields<T, S>(
+    params: &T,
+    extra_fields: &HashMap<String, String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    // Unset fields, such as `meta`, are left out since custom post types may not support them
+    let mut fields = match serde_json::to_value(params).map_err(serde::ser::Error::custom)? {
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .filter(|(_, value)| !value.is_null())
+            .collect(),
+        _ => serde_json::Map::new(),
+    };
+    extra_fields.iter().for_each(|(name, raw_value)| {
+        let value = serde_json::from_str(raw_value)
+            .unwrap_or_else(|_| serde_json::Value::String(raw_value.clone()));
+        fields.insert(name.clone(), value);
+    });
+    let mut map = serializer.serialize_map(Some(fields.len()))?;
+    for (name, value) in fields {
+        map.serialize_entry(&name, &value)?;
+    }
+    map.end()
+}
+
+// A post of any post type.
+//
+// Custom post types don't necessarily support all the fields of a post, and they may register
+// additional fields of their own. So, the known fields are parsed into a `SparsePost` and anything
+// else - including known fields in a format `SparsePost` doesn't understand, such as an empty
+// `meta` - is kept as raw JSON in `extra_fields`.
+#[derive(Debug, uniffi::Record)]
+pub struct CustomPost {
+    pub post: SparsePost,
+    pub extra_fields: HashMap<String, String>,
+}
+
+impl<'de> Deserialize<'de> for CustomPost {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let fields = serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+        let known_field_names = sparse_post_field_names();
+        let mut post_fields = serde_json::Map::new();
+        let mut extra_fields = HashMap::new();
+        for (name, value) in fields {
+            if known_field_names.contains(&name) {
+                post_fields.insert(name, value);
+            } else {
+                extra_fields.insert(name, value.to_string());
+            }
+        }
+        let post = match serde_json::from_value(serde_json::Value::Object(post_fields.clone())) {
+            Ok(post) => post,
+            // Only when a known field has a format `SparsePost` doesn't understand, each field is
+            // checked on its own to find which ones to keep as raw JSON.
+            Err(_) => {
+                let (valid_fields, invalid_fields): (serde_json::Map<_, _>, serde_json::Map<_, _>) =
+                    post_fields
+                        .into_iter()
+                        .partition(|(name, value)| is_valid_sparse_post_field(name, value));
+                extra_fields.extend(
+                    invalid_fields
+                        .into_iter()
+                        .map(|(name, value)| (name, value.to_string())),
+                );
+                serde_json::from_value(serde_json::Value::Object(valid_fields))
+                    .map_err(serde::de::Error::custom)?
+            }
+        };
+        Ok(Self { post, extra_fields })
+    }
+}
+
+// All the fields of `SparsePost` are optional, so an empty post lists every field name when it's
+// serialized. They are only computed once, since every custom post in a response needs them.
+fn sparse_post_field_names() -> &'static HashSet<String> {
+    static FIELD_NAMES: OnceLock<HashSet<String>> = OnceLock::new();
+    FIELD_NAMES.get_or_init(|| {
+        serde_json::from_value::<SparsePost>(serde_json::Value::Object(serde_json::Map::new()))
+            .ok()
+            .and_then(|post| serde_json::to_value(post).ok())
+            .and_then(|value| match value {
+                serde_json::Value::Object(map) => {
+                    Some(map.into_iter().map(|(name, _)| name).collect())
+                }
+                _ => None,
+            })
+            .unwrap_or_default()
+    })
+}
+
+fn is_valid_sparse_post_field(name: &str, value: &serde_json::Value) -> bool {
+    let mut map = serde_json::Map::new();
+    map.insert(name.to_string(), value.clone());
+    serde_json::from_value::<SparsePost>(serde_json::Value::Object(map)).is_ok()
+}
+
+#[derive(Debug, Deserialize, uniffi::Record)]
+pub struct CustomPostDeleteResponse {
+    pub deleted: bool,
+    pub previous: CustomPost,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        links::WpLinkRelation,
+        posts::{PostId, PostStatus},
+    };
+    use rstest::*;
+
+    #[rstest]
+    fn parse_custom_post_with_extra_fields() {
+        let custom_post: CustomPost = serde_json::from_str(
+            r#"{"id":5,"type":"product","status":"publish","meta":[],"price":"9.99","sku":{"value":42}}"#,
+        )
+        .expect("Custom post should be parsed");
+        assert_eq!(custom_post.post.id, Some(PostId(5)));
+        assert_eq!(custom_post.post.post_type.as_deref(), Some("product"));
+        assert_eq!(custom_post.post.status, Some(PostStatus::Publish));
+        assert!(custom_post.post.meta.is_none());
+        assert_eq!(
+            custom_post.extra_fields,
+            HashMap::from([
+                ("meta".to_string(), "[]".to_string()),
+                ("price".to_string(), r#""9.99""#.to_string()),
+                ("sku".to_string(), r#"{"value":42}"#.to_string()),
+            ])
+        );
+    }
+
+    #[rstest]
+    fn parse_custom_post_without_extra_fields() {
+        let custom_post: CustomPost =
+            serde_json::from_str(r#"{"id":5,"type":"product","status":"draft"}"#)
+                .expect("Custom post should be parsed");
+        assert_eq!(custom_post.post.id, Some(PostId(5)));
+        assert_eq!(custom_post.post.status, Some(PostStatus::Draft));
+        assert!(custom_post.extra_fields.is_empty());
+    }
+
+    #[rstest]
+    fn parse_custom_post_links() {
+        let custom_post: CustomPost = serde_json::from_str(
+            r#"{"id":5,"_links":{"self":[{"href":"https://example.com/wp-json/wp/v2/product/5"}]}}"#,
+        )
+        .expect("Custom post should be parsed");
+        assert_eq!(
+            custom_post
+                .post
+                .links
+                .as_ref()
+                .and_then(|links| links.first(&WpLinkRelation::SelfLink))
+                .map(|link| link.href.as_str()),
+            Some("https://example.com/wp-json/wp/v2/product/5")
+        );
+        assert!(custom_post.extra_fields.is_empty());
+    }
+
+    #[rstest]
+    fn serialize_custom_post_create_params_with_extra_fields() {
+        let params = CustomPostCreateParams {
+            post: PostCreateParams {
+                title: Some("foo".to_string()),
+                ..Default::default()
+            },
+            extra_fields: HashMap::from([
+                ("price".to_string(), "9.99".to_string()),
+                ("color".to_string(), "red".to_string()),
+            ]),
+        };
+        assert_eq!(
+            serde_json::to_value(&params).expect("Params should be serialized"),
+            serde_json::json!({ "title": "foo", "price": 9.99, "color": "red" })
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+
+use crate::{media::MediaWithEmbedContext, users::UserWithEmbedContext};
+
+// The link relations that can be embedded using the `_embed` parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum WpEmbedRelation {
+    Author,
+    FeaturedMedia,
+    Term,
+}
+
+impl WpEmbedRelation {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Author => "author",
+            Self::FeaturedMedia => "wp:featuredmedia",
+            Self::Term => "wp:term",
+        }
+    }
+}
+
+// The linked resources WordPress includes under `_embedded` when they are requested with `_embed`.
+//
+// Only the requested link relations are included, so the others will be empty. Embedded resources
+// are always in the `embed` context.
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct WpEmbedded {
+    #[serde(default, deserialize_with = "deserialize_embedded_resources")]
+    pub author: Vec<UserWithEmbedContext>,
+    #[serde(rename = "wp:featuredmedia")]
+    #[serde(default, deserialize_with = "deserialize_embedded_resources")]
+    pub featured_media: Vec<MediaWithEmbedContext>,
+    // A list of terms for each taxonomy of the resource, such as `category` & `post_tag`
+    #[serde(rename = "wp:term")]
+    #[serde(default)]
+    pub terms: Vec<Vec<WpEmbeddedTerm>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct WpEmbeddedTerm {
+    pub id: i64,
+    pub link: String,
+    pub name: String,
+    pub slug: String,
+    pub taxonomy: String,
+}
+
+// A linked resource the current user can't read, such as a private attachment, is embedded as an
+// error object: `{"code": "rest_forbidden", "message": "...", "data": {"status": 403}}`. These are
+// skipped, so they don't fail the parsing of the whole response.
+fn deserialize_embedded_resources<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    Vec::<serde_json::Value>::deserialize(deserializer)?
+        .into_iter()
+        .filter(|value| !is_wp_error(value))
+        .map(|value| serde_json::from_value(value).map_err(serde::de::Error::custom))
+        .collect()
+}
+
+fn is_wp_error(value: &serde_json::Value) -> bool {
+    value.get("code").is_some_and(|code| code.is_string()) && value.get("message").is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{posts::PostWithEmbedContext, users::UserId};
+    use rstest::*;
+
+    #[rstest]
+    fn parse_embedded_resources() {
+        let post: PostWithEmbedContext = serde_json::from_str(&post_json(EMBEDDED_JSON)).unwrap();
+        let embedded = post.embedded.unwrap();
+        assert_eq!(embedded.author.len(), 1);
+        assert_eq!(embedded.author[0].id, UserId(1));
+        // The private featured media is embedded as an error
+        assert!(embedded.featured_media.is_empty());
+        assert_eq!(
+            embedded
+                .terms
+                .iter()
+                .flatten()
+                .map(|term| (term.taxonomy.as_str(), term.slug.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("category", "uncategorized"), ("post_tag", "foo")]
+        );
+    }
+
+    #[rstest]
+    fn parse_post_without_embedded_resources() {
+        let post: PostWithEmbedContext = serde_json::from_str(&post_json("")).unwrap();
+        assert!(post.embedded.is_none());
+    }
+
+    #[rstest]
+    #[case(WpEmbedRelation::Author, "author")]
+    #[case(WpEmbedRelation::FeaturedMedia, "wp:featuredmedia")]
+    #[case(WpEmbedRelation::Term, "wp:term")]
+    fn test_embed_relation_as_str(#[case] relation: WpEmbedRelation, #[case] expected: &str) {
+        assert_eq!(relation.as_str(), expected);
+    }
+
+    const EMBEDDED_JSON: &str = r#","_embedded": {
+        "author": [
+            {
+                "id": 1,
+                "name": "foo",
+                "url": "",
+                "description": "",
+                "link": "https://example.com/author/foo/",
+                "slug": "foo"
+            }
+        ],
+        "wp:featuredmedia": [
+            {
+                "code": "rest_forbidden",
+                "message": "Sorry, you are not allowed to do that.",
+                "data": { "status": 403 }
+            }
+        ],
+        "wp:term": [
+            [
+                {
+                    "id": 1,
+                    "link": "https://example.com/category/uncategorized/",
+                    "name": "Uncategorized",
+                    "slug": "uncategorized",
+                    "taxonomy": "category"
+                }
+            ],
+            [
+                {
+                    "id": 2,
+                    "link": "https://example.com/tag/foo/",
+                    "name": "foo",
+                    "slug": "foo",
+                    "taxonomy": "post_tag"
+                }
+            ]
+        ]
+    }"#;
+
+    fn post_json(embedded_json: &str) -> String {
+        format!(
+            r#"{{
+                "id": 1,
+                "link": "https://example.com/hello-world/",
+                "slug": "hello-world",
+                "type": "post",
+                "title": {{ "rendered": "Hello world!" }},
+                "author": 1,
+                "excerpt": {{ "rendered": "", "protected": false }},
+                "featured_media": 5
+                {}
+            }}"#,
+            embedded_json
+        )
+    }
+}
@@ -14,12 +14,34 @@ mod parsed_url; // re-exported relevant types
 mod uuid; // re-exported relevant types
 
 pub mod application_passwords;
+pub mod authentication;
+pub mod batch;
+pub mod cache;
+pub mod categories;
+pub mod comments;
+pub mod custom_posts;
+pub mod embed;
+pub mod links;
 pub mod login;
+pub mod media;
+pub mod middleware;
+pub mod mock;
+pub mod pages;
+pub mod pagination;
 pub mod plugins;
+pub mod post_revisions;
 pub mod post_types;
 pub mod posts;
+pub mod recording;
 pub mod request;
+#[cfg(feature = "reqwest")]
+pub mod reqwest_request_executor;
+pub mod retry;
+pub mod schema;
+pub mod site_capabilities;
 pub mod site_settings;
+pub mod tags;
+pub mod taxonomies;
 pub mod url_query;
 pub mod users;
 pub mod wp_site_health_tests;
@@ -48,6 +70,16 @@ impl WpContext {
 #[derive(Debug, Clone, uniffi::Enum)]
 pub enum WpAuthentication {
     AuthorizationHeader { token: String },
+    // Sent as `Authorization: Bearer <token>`, as expected by WordPress.com and JWT plugins.
+    //
+    // See `TokenRefreshingRequestExecutor` to refresh expired tokens.
+    BearerToken { token: String },
+    // Authenticates the same way as the block editor: `cookie` is sent as the `Cookie` header,
+    // such as `wordpress_logged_in_abc=foo; wordpress_sec_abc=bar`, and `nonce` is a `wp_rest`
+    // nonce sent as the `X-WP-Nonce` header.
+    //
+    // See `WpLoginClient::authenticate_with_cookies`.
+    Cookie { cookie: String, nonce: String },
     None,
 }
 
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+// The link relations WordPress uses in the `_links` of its resources
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum WpLinkRelation {
+    About,
+    Attachment,
+    Author,
+    Collection,
+    FeaturedMedia,
+    PredecessorVersion,
+    Replies,
+    SelfLink,
+    Term,
+    Up,
+    VersionHistory,
+    // Any other relation, such as the ones added by plugins: `wp:action-publish`
+    Custom(String),
+}
+
+impl WpLinkRelation {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::About => "about",
+            Self::Attachment => "wp:attachment",
+            Self::Author => "author",
+            Self::Collection => "collection",
+            Self::FeaturedMedia => "wp:featuredmedia",
+            Self::PredecessorVersion => "predecessor-version",
+            Self::Replies => "replies",
+            Self::SelfLink => "self",
+            Self::Term => "wp:term",
+            Self::Up => "up",
+            Self::VersionHistory => "version-history",
+            Self::Custom(relation) => relation,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct WpLink {
+    pub href: String,
+    // Whether the linked resource can be embedded using `_embed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeddable: Option<bool>,
+    // Templated links, such as `curies`, need to be expanded before they can be followed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub templated: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    // The taxonomy of a `wp:term` link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxonomy: Option<String>,
+    // The number of revisions of a `version-history` link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    // The revision id of a `predecessor-version` link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+}
+
+impl WpLink {
+    pub fn is_templated(&self) -> bool {
+        self.templated.unwrap_or(false)
+    }
+}
+
+// The `_links` of a resource, as described by the [HAL](https://datatracker.ietf.org/doc/html/draft-kelly-json-hal)
+// format. Each relation can have several links, for example `wp:term` has one for each taxonomy.
+//
+// Use `LinksRequestBuilder` or `LinksRequestExecutor` to follow them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+pub struct WpLinks {
+    #[serde(rename = "self", default)]
+    pub self_link: Vec<WpLink>,
+    #[serde(default)]
+    pub collection: Vec<WpLink>,
+    #[serde(default)]
+    pub about: Vec<WpLink>,
+    #[serde(default)]
+    pub author: Vec<WpLink>,
+    #[serde(default)]
+    pub replies: Vec<WpLink>,
+    #[serde(rename = "version-history", default)]
+    pub version_history: Vec<WpLink>,
+    #[serde(rename = "predecessor-version", default)]
+    pub predecessor_version: Vec<WpLink>,
+    #[serde(rename = "wp:featuredmedia", default)]
+    pub featured_media: Vec<WpLink>,
+    #[serde(rename = "wp:attachment", default)]
+    pub attachment: Vec<WpLink>,
+    #[serde(rename = "wp:term", default)]
+    pub term: Vec<WpLink>,
+    #[serde(default)]
+    pub up: Vec<WpLink>,
+    // The compact URIs used by the relations such as `wp:term`
+    #[serde(default)]
+    pub curies: Vec<WpLink>,
+    // The relations that don't have a dedicated field, such as the ones added by plugins
+    #[serde(flatten)]
+    pub other: HashMap<String, Vec<WpLink>>,
+}
+
+impl WpLinks {
+    pub fn get(&self, relation: &WpLinkRelation) -> &[WpLink] {
+        match relation {
+            WpLinkRelation::About => &self.about,
+            WpLinkRelation::Attachment => &self.attachment,
+            WpLinkRelation::Author => &self.author,
+            WpLinkRelation::Collection => &self.collection,
+            WpLinkRelation::FeaturedMedia => &self.featured_media,
+            WpLinkRelation::PredecessorVersion => &self.predecessor_version,
+            WpLinkRelation::Replies => &self.replies,
+            WpLinkRelation::SelfLink => &self.self_link,
+            WpLinkRelation::Term => &self.term,
+            WpLinkRelation::Up => &self.up,
+            WpLinkRelation::VersionHistory => &self.version_history,
+            WpLinkRelation::Custom(relation) => self
+                .other
+                .get(relation)
+                .map(|links| links.as_slice())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn first(&self, relation: &WpLinkRelation) -> Option<&WpLink> {
+        self.get(relation).first()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    fn parse_links() {
+        let links: WpLinks = serde_json::from_str(POST_LINKS_JSON).unwrap();
+        assert_eq!(
+            links.first(&WpLinkRelation::SelfLink).unwrap().href,
+            "https://example.com/wp-json/wp/v2/posts/1"
+        );
+        assert_eq!(
+            links.first(&WpLinkRelation::Author),
+            Some(&WpLink {
+                href: "https://example.com/wp-json/wp/v2/users/1".to_string(),
+                embeddable: Some(true),
+                templated: None,
+                name: None,
+                taxonomy: None,
+                count: None,
+                id: None,
+            })
+        );
+        assert_eq!(
+            links
+                .get(&WpLinkRelation::Term)
+                .iter()
+                .filter_map(|link| link.taxonomy.as_deref())
+                .collect::<Vec<_>>(),
+            vec!["category", "post_tag"]
+        );
+        assert_eq!(
+            links.first(&WpLinkRelation::VersionHistory).unwrap().count,
+            Some(2)
+        );
+        assert!(links.curies[0].is_templated());
+        assert_eq!(
+            links
+                .first(&WpLinkRelation::Custom("wp:action-publish".to_string()))
+                .unwrap()
+                .href,
+            "https://example.com/wp-json/wp/v2/posts/1"
+        );
+        assert!(links.get(&WpLinkRelation::Up).is_empty());
+    }
+
+    const POST_LINKS_JSON: &str = r#"{
+        "self": [{ "href": "https://example.com/wp-json/wp/v2/posts/1" }],
+        "collection": [{ "href": "https://example.com/wp-json/wp/v2/posts" }],
+        "about": [{ "href": "https://example.com/wp-json/wp/v2/types/post" }],
+        "author": [{ "embeddable": true, "href": "https://example.com/wp-json/wp/v2/users/1" }],
+        "replies": [{ "embeddable": true, "href": "https://example.com/wp-json/wp/v2/comments?post=1" }],
+        "version-history": [{ "count": 2, "href": "https://example.com/wp-json/wp/v2/posts/1/revisions" }],
+        "predecessor-version": [{ "id": 5, "href": "https://example.com/wp-json/wp/v2/posts/1/revisions/5" }],
+        "wp:attachment": [{ "href": "https://example.com/wp-json/wp/v2/media?parent=1" }],
+        "wp:term": [
+            { "taxonomy": "category", "embeddable": true, "href": "https://example.com/wp-json/wp/v2/categories?post=1" },
+            { "taxonomy": "post_tag", "embeddable": true, "href": "https://example.com/wp-json/wp/v2/tags?post=1" }
+        ],
+        "wp:action-publish": [{ "href": "https://example.com/wp-json/wp/v2/posts/1" }],
+        "curies": [{ "name": "wp", "href": "https://api.w.org/{rel}", "templated": true }]
+    }"#;
+}
@@ -4,14 +4,18 @@ use std::str;
 use std::sync::Arc;
 use wp_serde_helper::deserialize_i64_or_string;
 
+pub use cookie_authentication::CookieAuthenticationError;
 pub use login_client::WpLoginClient;
-pub use url_discovery::{UrlDiscoveryState, UrlDiscoverySuccess};
+pub use url_discovery::{UrlDiscoveryError, UrlDiscoveryState, UrlDiscoverySuccess};
 
+use crate::request::RequestMethod;
+use crate::schema::{deserialize_map_or_empty_array, WpRoute, WpRoutes};
 use crate::ParsedUrl;
 use crate::WpUuid;
 
 const KEY_APPLICATION_PASSWORDS: &str = "application-passwords";
 
+mod cookie_authentication;
 mod login_client;
 mod url_discovery;
 
@@ -57,8 +61,14 @@ pub struct WpApiDetails {
     pub gmt_offset: i64,
     pub timezone_string: String,
     pub namespaces: Vec<String>,
+    // Empty, and encoded as `[]` by PHP, if the site doesn't have any authentication schemes, such as
+    // when application passwords are disabled
+    #[serde(deserialize_with = "deserialize_map_or_empty_array")]
     pub authentication: HashMap<String, WpRestApiAuthenticationScheme>,
     pub site_icon_url: Option<String>,
+    // The route index of all namespaces, such as `/wp/v2/posts` & `/wp-site-health/v1/tests/background-updates`
+    #[serde(default)]
+    pub routes: WpRoutes,
 }
 
 #[uniffi::export]
@@ -68,6 +78,20 @@ impl WpApiDetails {
             .get(KEY_APPLICATION_PASSWORDS)
             .map(|auth_scheme| auth_scheme.endpoints.authorization.clone())
     }
+
+    // The route can either be a pattern of the route index, such as `/wp/v2/posts/(?P<id>[\d]+)`, or
+    // a path, such as `/wp/v2/posts/1`.
+    pub fn find_route(&self, route: String) -> Option<WpRoute> {
+        self.routes.find(&route).cloned()
+    }
+
+    // Whether the site has the route and it accepts the given method, for example
+    // `supports_route("/wp/v2/posts", RequestMethod::POST)`.
+    pub fn supports_route(&self, route: String, method: RequestMethod) -> bool {
+        self.routes
+            .find(&route)
+            .is_some_and(|route| route.allows_method(&method))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, uniffi::Record)]
@@ -200,4 +224,76 @@ mod tests {
         );
         assert_eq!(auth_url, ParsedUrl::parse(expected_url.as_str()).unwrap());
     }
+
+    #[rstest]
+    #[case("/wp/v2/posts", RequestMethod::POST, true)]
+    #[case("/wp/v2/posts/1", RequestMethod::DELETE, true)]
+    #[case("/wp/v2/posts", RequestMethod::DELETE, false)]
+    #[case(
+        "/wp-site-health/v1/tests/background-updates",
+        RequestMethod::GET,
+        true
+    )]
+    #[case("/wp-block-editor/v1/url-details", RequestMethod::GET, false)]
+    fn test_supports_route(
+        #[case] route: &str,
+        #[case] method: RequestMethod,
+        #[case] expected: bool,
+    ) {
+        let api_details: WpApiDetails = serde_json::from_str(API_DETAILS_JSON).unwrap();
+        assert_eq!(
+            api_details.supports_route(route.to_string(), method),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_api_details_without_routes() {
+        let api_details: WpApiDetails =
+            serde_json::from_str(&API_DETAILS_JSON.replace(r#""routes""#, r#""_routes""#)).unwrap();
+        assert!(api_details.routes.is_empty());
+        assert!(!api_details.supports_route("/wp/v2/posts".to_string(), RequestMethod::GET));
+    }
+
+    const API_DETAILS_JSON: &str = r#"{
+        "name": "example",
+        "description": "",
+        "url": "https://example.com",
+        "home": "https://example.com",
+        "gmt_offset": "0",
+        "timezone_string": "",
+        "namespaces": ["oembed/1.0", "wp/v2", "wp-site-health/v1"],
+        "authentication": {},
+        "site_icon_url": null,
+        "routes": {
+            "/wp/v2/posts": {
+                "namespace": "wp/v2",
+                "methods": ["GET", "POST"],
+                "endpoints": [
+                    {
+                        "methods": ["GET"],
+                        "args": {
+                            "per_page": { "type": "integer", "default": 10, "required": false }
+                        }
+                    },
+                    { "methods": ["POST"], "args": [] }
+                ],
+                "_links": { "self": [{ "href": "https://example.com/wp-json/wp/v2/posts" }] }
+            },
+            "/wp/v2/posts/(?P<id>[\\d]+)": {
+                "namespace": "wp/v2",
+                "methods": ["GET", "POST", "PUT", "PATCH", "DELETE"],
+                "endpoints": [
+                    { "methods": ["GET"], "args": {} },
+                    { "methods": ["POST", "PUT", "PATCH"], "args": {} },
+                    { "methods": ["DELETE"], "args": {} }
+                ]
+            },
+            "/wp-site-health/v1/tests/background-updates": {
+                "namespace": "wp-site-health/v1",
+                "methods": ["GET"],
+                "endpoints": [{ "methods": ["GET"], "args": [] }]
+            }
+        }
+    }"#;
 }
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use http::{HeaderMap, HeaderValue};
+
+use crate::api_error::RequestExecutionError;
+use crate::request::endpoint::WpEndpointUrl;
+use crate::request::{
+    RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkRequestBody, WpNetworkResponse,
+};
+use crate::ParsedUrl;
+
+const LOGGED_IN_COOKIE_PREFIX: &str = "wordpress_logged_in_";
+const CONTENT_TYPE_FORM_URLENCODED: &str = "application/x-www-form-urlencoded";
+
+// Logs in by submitting the `wp-login.php` form, the same way a browser would.
+//
+// WordPress responds with a redirect that sets the authentication cookies, so the request asks the
+// request executor not to follow redirects, otherwise the `Set-Cookie` headers would be lost.
+pub(super) fn login_request(
+    site_url: &ParsedUrl,
+    username: &str,
+    password: &str,
+) -> WpNetworkRequest {
+    let body = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("log", username)
+        .append_pair("pwd", password)
+        .append_pair("rememberme", "forever")
+        .finish();
+    let mut header_map = HeaderMap::new();
+    header_map.insert(
+        http::header::CONTENT_TYPE,
+        HeaderValue::from_static(CONTENT_TYPE_FORM_URLENCODED),
+    );
+    WpNetworkRequest {
+        method: RequestMethod::POST,
+        url: WpEndpointUrl(site_url_by_extending(site_url, &["wp-login.php"]).to_string()),
+        header_map: WpNetworkHeaderMap::new(header_map).into(),
+        body: Some(Arc::new(WpNetworkRequestBody::new(body.into_bytes()))),
+        follow_redirects: false,
+    }
+}
+
+// Returns the cookies set by a successful login as a `Cookie` header value.
+pub(super) fn parse_login_response(
+    response: &WpNetworkResponse,
+) -> Result<String, CookieAuthenticationError> {
+    let header_map = response.header_map.as_header_map();
+    let mut cookies: Vec<(String, String)> = Vec::new();
+    header_map
+        .get_all(http::header::SET_COOKIE)
+        .iter()
+        .filter_map(|header_value| header_value.to_str().ok())
+        .filter_map(cookie_from_set_cookie_header)
+        .for_each(|(name, value)| {
+            // Later cookies replace the earlier ones with the same name, as they would in a browser
+            match cookies.iter_mut().find(|(n, _)| *n == name) {
+                Some(cookie) => cookie.1 = value,
+                None => cookies.push((name, value)),
+            }
+        });
+    if !cookies
+        .iter()
+        .any(|(name, _)| name.starts_with(LOGGED_IN_COOKIE_PREFIX))
+    {
+        return Err(CookieAuthenticationError::InvalidCredentials {
+            status_code: response.status_code,
+        });
+    }
+    Ok(cookies
+        .into_iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<_>>()
+        .join("; "))
+}
+
+// Extracts the `name=value` pair from a `Set-Cookie` header, ignoring its attributes.
+//
+// WordPress clears the previous authentication cookies by setting them to a blank value before
+// setting new ones, so cookies without a value are ignored. `WpNetworkHeaderMap` may split a header
+// at the `,` of an `expires` date, but the second half doesn't have a `name=value` pair, so it's
+// ignored as well.
+fn cookie_from_set_cookie_header(header_value: &str) -> Option<(String, String)> {
+    let (name, value) = header_value.split(';').next()?.split_once('=')?;
+    let (name, value) = (name.trim(), value.trim());
+    if name.is_empty() || name.contains(' ') || matches!(value, "" | "+" | "%20" | "deleted") {
+        return None;
+    }
+    Some((name.to_string(), value.to_string()))
+}
+
+// Requests a `wp_rest` nonce, which is required to use the REST API with cookie authentication.
+pub(super) fn nonce_request(site_url: &ParsedUrl, cookie: &str) -> WpNetworkRequest {
+    let mut url = site_url_by_extending(site_url, &["wp-admin", "admin-ajax.php"]);
+    url.query_pairs_mut().append_pair("action", "rest-nonce");
+    let mut header_map = HeaderMap::new();
+    if let Ok(hv) = HeaderValue::from_str(cookie) {
+        header_map.insert(http::header::COOKIE, hv);
+    }
+    WpNetworkRequest {
+        method: RequestMethod::GET,
+        url: WpEndpointUrl(url.to_string()),
+        header_map: WpNetworkHeaderMap::new(header_map).into(),
+        body: None,
+        follow_redirects: true,
+    }
+}
+
+// `admin-ajax.php` responds with the nonce as plain text, or with `0` if the cookies aren't valid.
+pub(super) fn parse_nonce_response(
+    response: &WpNetworkResponse,
+) -> Result<String, CookieAuthenticationError> {
+    let body = response.body_as_string();
+    let nonce = body.trim();
+    if response.status_code == 200
+        && !nonce.is_empty()
+        && nonce != "0"
+        && nonce.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        Ok(nonce.to_string())
+    } else {
+        Err(CookieAuthenticationError::NonceNotFound {
+            status_code: response.status_code,
+            response: body,
+        })
+    }
+}
+
+fn site_url_by_extending(site_url: &ParsedUrl, segments: &[&str]) -> url::Url {
+    let mut url = site_url.inner.clone();
+    url.set_query(None);
+    url.set_fragment(None);
+    if let Ok(mut path_segments) = url.path_segments_mut() {
+        path_segments.pop_if_empty().extend(segments);
+    }
+    url
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+pub enum CookieAuthenticationError {
+    #[error(transparent)]
+    RequestExecutionFailed { error: RequestExecutionError },
+    #[error(
+        "Login didn't set the authentication cookies!\nStatus Code: '{}'",
+        status_code
+    )]
+    InvalidCredentials { status_code: u16 },
+    #[error(
+        "Nonce couldn't be found in response!\nStatus Code: '{}'.\nResponse: '{}'",
+        status_code,
+        response
+    )]
+    NonceNotFound { status_code: u16, response: String },
+}
+
+impl From<RequestExecutionError> for CookieAuthenticationError {
+    fn from(error: RequestExecutionError) -> Self {
+        Self::RequestExecutionFailed { error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("https://example.com", "https://example.com/wp-login.php")]
+    #[case("https://example.com/blog/", "https://example.com/blog/wp-login.php")]
+    #[case(
+        "https://example.com/blog?foo=bar",
+        "https://example.com/blog/wp-login.php"
+    )]
+    fn test_login_request(#[case] site_url: &str, #[case] expected_url: &str) {
+        let request = login_request(&ParsedUrl::parse(site_url).unwrap(), "foo bar", "p&ss");
+        assert!(matches!(request.method(), RequestMethod::POST));
+        assert_eq!(request.url().0, expected_url);
+        assert_eq!(
+            request.body_as_string().as_deref(),
+            Some("log=foo+bar&pwd=p%26ss&rememberme=forever")
+        );
+        assert!(!request.follow_redirects());
+    }
+
+    #[rstest]
+    fn test_nonce_request() {
+        let request = nonce_request(
+            &ParsedUrl::parse("https://example.com/blog").unwrap(),
+            "wordpress_logged_in_abc=foo",
+        );
+        assert_eq!(
+            request.url().0,
+            "https://example.com/blog/wp-admin/admin-ajax.php?action=rest-nonce"
+        );
+        assert_eq!(
+            request
+                .header_map()
+                .as_header_map()
+                .get(http::header::COOKIE),
+            Some(&HeaderValue::from_static("wordpress_logged_in_abc=foo"))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_login_response() {
+        let response = response(
+            302,
+            "",
+            &[
+                "wordpress_sec_abc=+; expires=Sun, 18-Oct-2025 10:00:00 GMT; path=/wp-admin",
+                "wordpress_logged_in_abc=+; path=/",
+                "wordpress_sec_abc=bar; expires=Sun",
+                "01-Nov-2026 10:00:00 GMT; path=/wp-content/plugins; secure; HttpOnly",
+                "wordpress_logged_in_abc=foo; path=/; HttpOnly",
+            ],
+        );
+        assert_eq!(
+            parse_login_response(&response),
+            Ok("wordpress_sec_abc=bar; wordpress_logged_in_abc=foo".to_string())
+        );
+    }
+
+    #[rstest]
+    fn test_parse_login_response_without_logged_in_cookie() {
+        let response = response(
+            200,
+            "<html></html>",
+            &["wordpress_test_cookie=WP+Cookie+check"],
+        );
+        assert_eq!(
+            parse_login_response(&response),
+            Err(CookieAuthenticationError::InvalidCredentials { status_code: 200 })
+        );
+    }
+
+    #[rstest]
+    #[case(200, "1a2b3c4d5e\n", Ok("1a2b3c4d5e"))]
+    #[case(400, "0", Err(()))]
+    #[case(200, "0", Err(()))]
+    #[case(200, "<html></html>", Err(()))]
+    fn test_parse_nonce_response(
+        #[case] status_code: u16,
+        #[case] body: &str,
+        #[case] expected_result: Result<&str, ()>,
+    ) {
+        assert_eq!(
+            parse_nonce_response(&response(status_code, body, &[])),
+            expected_result.map(|nonce| nonce.to_string()).map_err(|_| {
+                CookieAuthenticationError::NonceNotFound {
+                    status_code,
+                    response: body.to_string(),
+                }
+            })
+        );
+    }
+
+    fn response(status_code: u16, body: &str, set_cookies: &[&'static str]) -> WpNetworkResponse {
+        let mut header_map = HeaderMap::new();
+        set_cookies.iter().for_each(|value| {
+            header_map.append(http::header::SET_COOKIE, HeaderValue::from_static(value));
+        });
+        WpNetworkResponse {
+            body: body.as_bytes().to_vec(),
+            status_code,
+            header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
+        }
+    }
+}
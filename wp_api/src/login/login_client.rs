@@ -5,8 +5,9 @@ use crate::request::endpoint::WpEndpointUrl;
 use crate::request::{
     RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
 };
-use crate::ParsedUrl;
+use crate::{ParsedUrl, WpAuthentication};
 
+use super::cookie_authentication::{self, CookieAuthenticationError};
 use super::url_discovery::{
     self, FetchApiDetailsError, FetchApiRootUrlError, StateInitial, UrlDiscoveryAttemptError,
     UrlDiscoveryAttemptSuccess, UrlDiscoveryError, UrlDiscoveryState, UrlDiscoverySuccess,
@@ -34,6 +35,17 @@ impl UniffiWpLoginClient {
     ) -> Result<UrlDiscoverySuccess, UrlDiscoveryError> {
         self.inner.api_discovery(site_url).await
     }
+
+    async fn authenticate_with_cookies(
+        &self,
+        site_url: Arc<ParsedUrl>,
+        username: String,
+        password: String,
+    ) -> Result<WpAuthentication, CookieAuthenticationError> {
+        self.inner
+            .authenticate_with_cookies(&site_url, &username, &password)
+            .await
+    }
 }
 
 #[derive(Debug)]
@@ -87,6 +99,32 @@ impl WpLoginClient {
         }
     }
 
+    // Logs in through `wp-login.php` and fetches a REST API nonce from `admin-ajax.php`, returning
+    // a `WpAuthentication::Cookie` that can be used with `WpApiClient`.
+    //
+    // `site_url` is the WordPress address, where `wp-login.php` is, such as `WpApiDetails::url`.
+    // The authentication cookies are read from the `Set-Cookie` headers of the login response, so
+    // the request executor must not follow redirects for requests with `follow_redirects` turned
+    // off, nor handle cookies on its own.
+    pub async fn authenticate_with_cookies(
+        &self,
+        site_url: &ParsedUrl,
+        username: &str,
+        password: &str,
+    ) -> Result<WpAuthentication, CookieAuthenticationError> {
+        let login_response = self
+            .request_executor
+            .execute(cookie_authentication::login_request(site_url, username, password).into())
+            .await?;
+        let cookie = cookie_authentication::parse_login_response(&login_response)?;
+        let nonce_response = self
+            .request_executor
+            .execute(cookie_authentication::nonce_request(site_url, &cookie).into())
+            .await?;
+        let nonce = cookie_authentication::parse_nonce_response(&nonce_response)?;
+        Ok(WpAuthentication::Cookie { cookie, nonce })
+    }
+
     async fn attempt_api_discovery(
         &self,
         site_url: &str,
@@ -132,6 +170,7 @@ impl WpLoginClient {
             url: WpEndpointUrl(parsed_site_url.url()),
             header_map: WpNetworkHeaderMap::default().into(),
             body: None,
+            follow_redirects: true,
         };
         self.request_executor
             .execute(api_root_request.into())
@@ -150,6 +189,7 @@ impl WpLoginClient {
                     url: WpEndpointUrl(api_root_url.url()),
                     header_map: WpNetworkHeaderMap::default().into(),
                     body: None,
+                    follow_redirects: true,
                 }
                 .into(),
             )
@@ -180,15 +180,8 @@ pub(super) struct StateFetchedApiDetails {
 
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 pub enum FetchApiRootUrlError {
-    #[error(
-        "Request execution failed!\nStatus Code: '{:?}'\nResponse: '{}'",
-        status_code,
-        reason
-    )]
-    RequestExecutionFailed {
-        status_code: Option<u16>,
-        reason: String,
-    },
+    #[error(transparent)]
+    RequestExecutionFailed { error: RequestExecutionError },
     #[error(
         "Api root link header not found!\nStatus Code: '{:#?}'\nHeader Map: '{:#?}'",
         status_code,
@@ -201,45 +194,22 @@ pub enum FetchApiRootUrlError {
 }
 
 impl From<RequestExecutionError> for FetchApiRootUrlError {
-    fn from(value: RequestExecutionError) -> Self {
-        match value {
-            RequestExecutionError::RequestExecutionFailed {
-                status_code,
-                reason,
-            } => Self::RequestExecutionFailed {
-                status_code,
-                reason,
-            },
-        }
+    fn from(error: RequestExecutionError) -> Self {
+        Self::RequestExecutionFailed { error }
     }
 }
 
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 pub enum FetchApiDetailsError {
-    #[error(
-        "Request execution failed!\nStatus Code: '{:?}'.\nResponse: '{}'",
-        status_code,
-        reason
-    )]
-    RequestExecutionFailed {
-        status_code: Option<u16>,
-        reason: String,
-    },
+    #[error(transparent)]
+    RequestExecutionFailed { error: RequestExecutionError },
     #[error("Api details couldn't be parsed from response: {:?}", response)]
     ApiDetailsCouldntBeParsed { reason: String, response: String },
 }
 
 impl From<RequestExecutionError> for FetchApiDetailsError {
-    fn from(value: RequestExecutionError) -> Self {
-        match value {
-            RequestExecutionError::RequestExecutionFailed {
-                status_code,
-                reason,
-            } => Self::RequestExecutionFailed {
-                status_code,
-                reason,
-            },
-        }
+    fn from(error: RequestExecutionError) -> Self {
+        Self::RequestExecutionFailed { error }
     }
 }
 
@@ -0,0 +1,452 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+
+use crate::{
+    impl_as_query_value_from_as_str, impl_pagination_params,
+    posts::{MediaId, PostCommentStatus, PostId, PostPingStatus, PostStatus},
+    url_query::{AppendUrlQueryPairs, AsQueryValue, QueryPairs, QueryPairsExtension},
+    UserId, WpApiParamOrder,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum WpApiParamMediaOrderBy {
+    Author,
+    #[default]
+    Date,
+    Id,
+    Include,
+    IncludeSlugs,
+    Modified,
+    Parent,
+    Relevance,
+    Slug,
+    Title,
+}
+
+impl_as_query_value_from_as_str!(WpApiParamMediaOrderBy);
+
+impl WpApiParamMediaOrderBy {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Author => "author",
+            Self::Date => "date",
+            Self::Id => "id",
+            Self::Include => "include",
+            Self::IncludeSlugs => "include_slugs",
+            Self::Modified => "modified",
+            Self::Parent => "parent",
+            Self::Relevance => "relevance",
+            Self::Slug => "slug",
+            Self::Title => "title",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum WpApiParamMediaType {
+    Application,
+    Audio,
+    Image,
+    Text,
+    Video,
+}
+
+impl_as_query_value_from_as_str!(WpApiParamMediaType);
+
+impl WpApiParamMediaType {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Application => "application",
+            Self::Audio => "audio",
+            Self::Image => "image",
+            Self::Text => "text",
+            Self::Video => "video",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, uniffi::Record)]
+pub struct MediaListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Limit response to media items published after a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub after: Option<String>,
+    /// Limit response to media items modified after a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub modified_after: Option<String>,
+    /// Limit result set to media items assigned to specific authors.
+    #[uniffi(default = [])]
+    pub author: Vec<UserId>,
+    /// Ensure result set excludes media items assigned to specific authors.
+    #[uniffi(default = [])]
+    pub author_exclude: Vec<UserId>,
+    /// Limit response to media items published before a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub before: Option<String>,
+    /// Limit response to media items modified before a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub modified_before: Option<String>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<MediaId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<MediaId>,
+    /// Offset the result set by a specific number of items.
+    #[uniffi(default = None)]
+    pub offset: Option<u32>,
+    /// Order sort attribute ascending or descending.
+    /// Default: desc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Sort collection by media attribute.
+    /// Default: date
+    /// One of: author, date, id, include, modified, parent, relevance, slug, include_slugs, title
+    #[uniffi(default = None)]
+    pub orderby: Option<WpApiParamMediaOrderBy>,
+    /// Limit result set to items with particular parent IDs.
+    #[uniffi(default = [])]
+    pub parent: Vec<PostId>,
+    /// Limit result set to all items except those of a particular parent ID.
+    #[uniffi(default = [])]
+    pub parent_exclude: Vec<PostId>,
+    /// Array of column names to be searched.
+    #[uniffi(default = [])]
+    pub search_columns: Vec<crate::posts::WpApiParamPostsSearchColumn>,
+    /// Limit result set to media items with one or more specific slugs.
+    #[uniffi(default = [])]
+    pub slug: Vec<String>,
+    /// Limit result set to media items assigned one or more statuses.
+    /// Default: inherit
+    #[uniffi(default = [])]
+    pub status: Vec<PostStatus>,
+    /// Limit result set to attachments of a particular media type.
+    /// One of: image, video, text, application, audio
+    #[uniffi(default = None)]
+    pub media_type: Option<WpApiParamMediaType>,
+    /// Limit result set to attachments of a particular MIME type.
+    #[uniffi(default = None)]
+    pub mime_type: Option<String>,
+}
+
+impl AppendUrlQueryPairs for MediaListParams {
+    fn append_query_pairs(&self, query_pairs_mut: &mut QueryPairs) {
+        query_pairs_mut
+            .append_option_query_value_pair("page", self.page.as_ref())
+            .append_option_query_value_pair("per_page", self.per_page.as_ref())
+            .append_option_query_value_pair("search", self.search.as_ref())
+            .append_option_query_value_pair("after", self.after.as_ref())
+            .append_option_query_value_pair("modified_after", self.modified_after.as_ref())
+            .append_vec_query_value_pair("author", &self.author)
+            .append_vec_query_value_pair("author_exclude", &self.author_exclude)
+            .append_option_query_value_pair("before", self.before.as_ref())
+            .append_option_query_value_pair("modified_before", self.modified_before.as_ref())
+            .append_vec_query_value_pair("exclude", &self.exclude)
+            .append_vec_query_value_pair("include", &self.include)
+            .append_option_query_value_pair("offset", self.offset.as_ref())
+            .append_option_query_value_pair("order", self.order.as_ref())
+            .append_option_query_value_pair("orderby", self.orderby.as_ref())
+            .append_vec_query_value_pair("parent", &self.parent)
+            .append_vec_query_value_pair("parent_exclude", &self.parent_exclude)
+            .append_vec_query_value_pair("search_columns", &self.search_columns)
+            .append_vec_query_value_pair("slug", &self.slug)
+            .append_vec_query_value_pair("status", &self.status)
+            .append_option_query_value_pair("media_type", self.media_type.as_ref())
+            .append_option_query_value_pair("mime_type", self.mime_type.as_ref());
+    }
+}
+
+impl_pagination_params!(MediaListParams);
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct MediaDeleteResponse {
+    pub deleted: bool,
+    pub previous: MediaWithEditContext,
+}
+
+/// Fields that can be set while uploading a new media item.
+///
+/// These are sent as `multipart/form-data` fields alongside the file, so only
+/// values that can be represented as a plain form field are supported.
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct MediaCreateParams {
+    // The date the media item was published, in the site's timezone.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    // The date the media item was published, as GMT.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_gmt: Option<String>,
+    // An alphanumeric identifier for the media item unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    // A named status for the media item.
+    // One of: publish, future, draft, pending, private
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    // The title for the media item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    // The ID for the author of the media item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserId>,
+    // Whether or not comments are open on the media item.
+    // One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_status: Option<PostCommentStatus>,
+    // Whether or not the media item can be pinged.
+    // One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ping_status: Option<PostPingStatus>,
+    // The theme file to use to display the media item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    // Alternative text to display when attachment is not displayed.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<String>,
+    // The attachment caption.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    // The attachment description.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    // The ID for the associated post of the attachment.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<PostId>,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct MediaUpdateParams {
+    // The date the media item was published, in the site's timezone.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    // The date the media item was published, as GMT.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_gmt: Option<String>,
+    // An alphanumeric identifier for the media item unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    // A named status for the media item.
+    // One of: publish, future, draft, pending, private
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    // The title for the media item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    // The ID for the author of the media item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserId>,
+    // Whether or not comments are open on the media item.
+    // One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_status: Option<PostCommentStatus>,
+    // Whether or not the media item can be pinged.
+    // One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ping_status: Option<PostPingStatus>,
+    // The theme file to use to display the media item.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    // Alternative text to display when attachment is not displayed.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<String>,
+    // The attachment caption.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    // The attachment description.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    // The ID for the associated post of the attachment.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<PostId>,
+}
+
+/// A file to be uploaded to the media library.
+#[derive(Debug, uniffi::Record)]
+pub struct MediaUploadFile {
+    /// Name of the file, including its extension. i.e. `image.jpg`
+    pub file_name: String,
+    /// MIME type of the file. i.e. `image/jpeg`
+    pub mime_type: String,
+    /// Raw contents of the file.
+    pub contents: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaType {
+    File,
+    Image,
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseMedia {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<MediaId>,
+    #[WpContext(edit, embed, view)]
+    pub date: Option<String>,
+    #[WpContext(edit, view)]
+    pub date_gmt: Option<String>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub guid: Option<crate::posts::SparsePostGuid>,
+    #[WpContext(edit, embed, view)]
+    pub link: Option<String>,
+    #[WpContext(edit, view)]
+    pub modified: Option<String>,
+    #[WpContext(edit, view)]
+    pub modified_gmt: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit, view)]
+    pub status: Option<PostStatus>,
+    #[serde(rename = "type")]
+    #[WpContext(edit, embed, view)]
+    pub post_type: Option<String>,
+    #[WpContext(edit)]
+    pub permalink_template: Option<String>,
+    #[WpContext(edit)]
+    pub generated_slug: Option<String>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub title: Option<crate::posts::SparsePostTitle>,
+    #[WpContext(edit, embed, view)]
+    pub author: Option<UserId>,
+    #[WpContext(edit, view)]
+    pub comment_status: Option<PostCommentStatus>,
+    #[WpContext(edit, view)]
+    pub ping_status: Option<PostPingStatus>,
+    #[WpContext(edit, view)]
+    pub template: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub alt_text: Option<String>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub caption: Option<SparseMediaCaption>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub description: Option<SparseMediaDescription>,
+    #[WpContext(edit, embed, view)]
+    pub media_type: Option<MediaType>,
+    #[WpContext(edit, embed, view)]
+    pub mime_type: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub media_details: Option<MediaDetails>,
+    #[WpContextualOption]
+    #[WpContext(edit, view)]
+    pub post: Option<PostId>,
+    #[WpContext(edit, embed, view)]
+    pub source_url: Option<String>,
+    #[WpContext(edit)]
+    pub missing_image_sizes: Option<Vec<String>>,
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseMediaCaption {
+    #[WpContext(edit)]
+    pub raw: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub rendered: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseMediaDescription {
+    #[WpContext(edit)]
+    pub raw: Option<String>,
+    #[WpContext(edit, view)]
+    pub rendered: Option<String>,
+}
+
+// The shape of `media_details` depends on the type of the attachment. Images will have their
+// dimensions & generated sizes, whereas other files may only have some of these fields.
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct MediaDetails {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub file: Option<String>,
+    pub filesize: Option<u64>,
+    #[serde(default)]
+    pub sizes: HashMap<String, MediaDetailsSize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct MediaDetailsSize {
+    pub file: String,
+    pub width: u32,
+    pub height: u32,
+    pub filesize: Option<u64>,
+    pub mime_type: String,
+    pub source_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+
+    #[rstest]
+    #[case(MediaListParams::default(), "")]
+    #[case(generate!(MediaListParams, (page, Some(2))), "page=2")]
+    #[case(generate!(MediaListParams, (parent, vec![PostId(1), PostId(2)])), "parent=1%2C2")]
+    #[case(generate!(MediaListParams, (media_type, Some(WpApiParamMediaType::Image))), "media_type=image")]
+    #[case(generate!(MediaListParams, (mime_type, Some("image/jpeg".to_string()))), "mime_type=image%2Fjpeg")]
+    #[case(generate!(MediaListParams, (orderby, Some(WpApiParamMediaOrderBy::IncludeSlugs)), (status, vec![PostStatus::Private])), "orderby=include_slugs&status=private")]
+    #[trace]
+    fn test_media_list_params(#[case] params: MediaListParams, #[case] expected_query: &str) {
+        assert_expected_query_pairs(params, expected_query);
+    }
+
+    #[test]
+    fn test_media_details_without_sizes() {
+        let media_details: MediaDetails =
+            serde_json::from_str(r#"{"filesize": 1024, "sizes": {}}"#).unwrap();
+        assert_eq!(media_details.filesize, Some(1024));
+        assert!(media_details.width.is_none());
+        assert!(media_details.sizes.is_empty());
+    }
+}
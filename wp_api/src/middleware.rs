@@ -0,0 +1,281 @@
+use std::{fmt::Debug, sync::Arc};
+
+use crate::{
+    api_error::RequestExecutionError,
+    request::{RequestExecutor, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse},
+};
+
+// Implemented to modify every request before it's executed and to inspect its outcome, for
+// example to add headers, log requests or collect metrics.
+#[uniffi::export(with_foreign)]
+pub trait RequestInterceptor: Send + Sync + Debug {
+    // Returns the request to execute, which is usually the given request or a modified copy of it,
+    // such as `request.with_header(..)`.
+    fn intercept_request(&self, request: Arc<WpNetworkRequest>) -> Arc<WpNetworkRequest>;
+
+    // Called with the request that was executed & its response. The returned response is passed
+    // to the next interceptor.
+    fn intercept_response(
+        &self,
+        request: Arc<WpNetworkRequest>,
+        response: WpNetworkResponse,
+    ) -> WpNetworkResponse;
+
+    // Called with the request that was executed if it failed. The returned error is passed to the
+    // next interceptor.
+    fn intercept_error(
+        &self,
+        request: Arc<WpNetworkRequest>,
+        error: RequestExecutionError,
+    ) -> RequestExecutionError;
+}
+
+// Wraps a `RequestExecutor` to run the requests through an ordered list of interceptors.
+//
+// Requests go through the interceptors in the order they are registered, and responses & errors
+// go through them in reverse order, so the first interceptor sees the final request first and the
+// final response last.
+#[derive(Debug, uniffi::Object)]
+pub struct MiddlewarePipeline {
+    inner: Arc<dyn RequestExecutor>,
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
+}
+
+impl MiddlewarePipeline {
+    pub fn with_interceptor(mut self, interceptor: Arc<dyn RequestInterceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+}
+
+#[uniffi::export]
+impl MiddlewarePipeline {
+    #[uniffi::constructor]
+    pub fn new(
+        inner: Arc<dyn RequestExecutor>,
+        interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    ) -> Self {
+        Self {
+            inner,
+            interceptors,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for MiddlewarePipeline {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        // Each interceptor is given the response to the request it returned
+        let mut requests = Vec::with_capacity(self.interceptors.len());
+        let request = self
+            .interceptors
+            .iter()
+            .fold(request, |request, interceptor| {
+                let request = interceptor.intercept_request(request);
+                requests.push(request.clone());
+                request
+            });
+        let result = self.inner.execute(request).await;
+        self.interceptors.iter().zip(requests).rev().fold(
+            result,
+            |result, (interceptor, request)| match result {
+                Ok(response) => Ok(interceptor.intercept_response(request, response)),
+                Err(error) => Err(interceptor.intercept_error(request, error)),
+            },
+        )
+    }
+}
+
+// Adds the given headers to every request, such as a `User-Agent`, replacing any existing values.
+#[derive(Debug, uniffi::Object)]
+pub struct HeaderInterceptor {
+    header_map: Arc<WpNetworkHeaderMap>,
+}
+
+#[uniffi::export]
+impl HeaderInterceptor {
+    #[uniffi::constructor]
+    pub fn new(header_map: Arc<WpNetworkHeaderMap>) -> Self {
+        Self { header_map }
+    }
+}
+
+impl RequestInterceptor for HeaderInterceptor {
+    fn intercept_request(&self, request: Arc<WpNetworkRequest>) -> Arc<WpNetworkRequest> {
+        let mut header_map = request.header_map.as_header_map();
+        let extra_header_map = self.header_map.as_header_map();
+        extra_header_map.keys().for_each(|name| {
+            header_map.remove(name);
+        });
+        header_map.extend(extra_header_map);
+        Arc::new(request.with_header_map(WpNetworkHeaderMap::new(header_map).into()))
+    }
+
+    fn intercept_response(
+        &self,
+        _request: Arc<WpNetworkRequest>,
+        response: WpNetworkResponse,
+    ) -> WpNetworkResponse {
+        response
+    }
+
+    fn intercept_error(
+        &self,
+        _request: Arc<WpNetworkRequest>,
+        error: RequestExecutionError,
+    ) -> RequestExecutionError {
+        error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::{endpoint::WpEndpointUrl, RequestMethod};
+    use http::{HeaderMap, HeaderValue};
+    use rstest::*;
+    use std::sync::Mutex;
+
+    // Echoes the `X-Test` header of the request back in the response
+    #[derive(Debug)]
+    struct TestRequestExecutor {
+        fail: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl RequestExecutor for TestRequestExecutor {
+        async fn execute(
+            &self,
+            request: Arc<WpNetworkRequest>,
+        ) -> Result<WpNetworkResponse, RequestExecutionError> {
+            if self.fail {
+                return Err(RequestExecutionError::RequestExecutionFailed {
+                    status_code: None,
+                    reason: "failed".to_string(),
+                });
+            }
+            let mut header_map = HeaderMap::new();
+            if let Some(value) = request.header_map.as_header_map().get("X-Test") {
+                header_map.insert("X-Test", value.clone());
+            }
+            Ok(WpNetworkResponse {
+                body: Vec::new(),
+                status_code: 200,
+                header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
+            })
+        }
+    }
+
+    // Appends its name to the `X-Test` header & records the order it's called in
+    #[derive(Debug)]
+    struct TestInterceptor {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RequestInterceptor for TestInterceptor {
+        fn intercept_request(&self, request: Arc<WpNetworkRequest>) -> Arc<WpNetworkRequest> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("request {}", self.name));
+            let value = match request.header_map.as_header_map().get("X-Test") {
+                Some(value) => format!("{},{}", value.to_str().unwrap(), self.name),
+                None => self.name.to_string(),
+            };
+            Arc::new(request.with_header("X-Test".to_string(), value).unwrap())
+        }
+
+        fn intercept_response(
+            &self,
+            request: Arc<WpNetworkRequest>,
+            response: WpNetworkResponse,
+        ) -> WpNetworkResponse {
+            self.calls.lock().unwrap().push(format!(
+                "response {} {}",
+                self.name,
+                request.header_map.as_header_map()["X-Test"]
+                    .to_str()
+                    .unwrap()
+            ));
+            response
+        }
+
+        fn intercept_error(
+            &self,
+            _request: Arc<WpNetworkRequest>,
+            error: RequestExecutionError,
+        ) -> RequestExecutionError {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("error {}", self.name));
+            error
+        }
+    }
+
+    #[rstest]
+    #[case(false, vec!["request a", "request b", "response b a,b", "response a a"])]
+    #[case(true, vec!["request a", "request b", "error b", "error a"])]
+    fn interceptors_are_called_in_order(#[case] fail: bool, #[case] expected_calls: Vec<&str>) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let interceptor = |name| {
+            Arc::new(TestInterceptor {
+                name,
+                calls: calls.clone(),
+            })
+        };
+        let pipeline = MiddlewarePipeline::new(Arc::new(TestRequestExecutor { fail }), vec![])
+            .with_interceptor(interceptor("a"))
+            .with_interceptor(interceptor("b"));
+        let result = futures::executor::block_on(pipeline.execute(Arc::new(request())));
+        if let Ok(response) = result {
+            assert_eq!(response.get_header("X-Test"), Some("a,b"));
+        }
+        assert_eq!(*calls.lock().unwrap(), expected_calls);
+    }
+
+    #[rstest]
+    fn header_interceptor_replaces_existing_headers() {
+        let mut header_map = HeaderMap::new();
+        header_map.insert(http::header::USER_AGENT, HeaderValue::from_static("foo"));
+        let request = Arc::new(
+            request()
+                .with_header("User-Agent".to_string(), "bar".to_string())
+                .unwrap(),
+        );
+        let request = HeaderInterceptor::new(WpNetworkHeaderMap::new(header_map).into())
+            .intercept_request(request);
+        assert_eq!(
+            request
+                .header_map
+                .as_header_map()
+                .get_all(http::header::USER_AGENT)
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![HeaderValue::from_static("foo")]
+        );
+        assert!(request
+            .header_map
+            .as_header_map()
+            .contains_key(http::header::ACCEPT));
+    }
+
+    fn request() -> WpNetworkRequest {
+        let mut header_map = HeaderMap::new();
+        header_map.insert(
+            http::header::ACCEPT,
+            HeaderValue::from_static("application/json"),
+        );
+        WpNetworkRequest {
+            method: RequestMethod::GET,
+            url: WpEndpointUrl("https://example.com/wp-json/wp/v2/posts".to_string()),
+            header_map: WpNetworkHeaderMap::new(header_map).into(),
+            body: None,
+            follow_redirects: true,
+        }
+    }
+}
@@ -0,0 +1,415 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use http::{HeaderMap, HeaderValue};
+use serde::Serialize;
+
+use crate::{
+    api_error::{RequestExecutionError, WpErrorCode},
+    request::{
+        RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
+    },
+};
+
+// Implemented to build the response to a request handled by `MockRequestExecutor`.
+#[uniffi::export(with_foreign)]
+pub trait MockRequestHandler: Send + Sync + Debug {
+    // `path_params` are the values of the route's placeholders, such as `post_id` for
+    // `/wp/v2/posts/<post_id>`.
+    fn handle(
+        &self,
+        request: Arc<WpNetworkRequest>,
+        path_params: HashMap<String, String>,
+    ) -> WpNetworkResponse;
+}
+
+#[derive(Debug)]
+struct MockRoute {
+    method: RequestMethod,
+    route: String,
+    handler: Arc<dyn MockRequestHandler>,
+}
+
+// Serves the given response to every request
+#[derive(Debug)]
+struct StaticResponseHandler {
+    response: WpNetworkResponse,
+}
+
+impl MockRequestHandler for StaticResponseHandler {
+    fn handle(
+        &self,
+        _request: Arc<WpNetworkRequest>,
+        _path_params: HashMap<String, String>,
+    ) -> WpNetworkResponse {
+        self.response.clone()
+    }
+}
+
+#[derive(Serialize)]
+struct WpErrorPayload {
+    code: WpErrorCode,
+    message: String,
+    data: WpErrorPayloadData,
+}
+
+#[derive(Serialize)]
+struct WpErrorPayloadData {
+    status: u16,
+}
+
+// An in-process fake WordPress site to test clients without a server.
+//
+// Handlers are registered for a method & a route, such as `/wp/v2/posts/<post_id>`, using the same
+// placeholder syntax as the endpoint definitions. When several handlers match a request, the one
+// registered last is used, so a test can override a default handler. Requests without a handler
+// get the `rest_no_route` error WordPress responds with.
+//
+// All requests are recorded, so tests can assert what the client sent.
+#[derive(Debug, Default, uniffi::Object)]
+pub struct MockRequestExecutor {
+    routes: Mutex<Vec<MockRoute>>,
+    requests: Mutex<Vec<Arc<WpNetworkRequest>>>,
+}
+
+#[uniffi::export]
+impl MockRequestExecutor {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on_request(
+        &self,
+        method: RequestMethod,
+        route: String,
+        handler: Arc<dyn MockRequestHandler>,
+    ) {
+        if let Ok(mut routes) = self.routes.lock() {
+            routes.push(MockRoute {
+                method,
+                route,
+                handler,
+            });
+        }
+    }
+
+    pub fn respond_with(&self, method: RequestMethod, route: String, response: WpNetworkResponse) {
+        self.on_request(method, route, Arc::new(StaticResponseHandler { response }));
+    }
+
+    pub fn respond_with_json(
+        &self,
+        method: RequestMethod,
+        route: String,
+        status_code: u16,
+        json: String,
+    ) {
+        self.respond_with(method, route, json_response(status_code, json.into_bytes()));
+    }
+
+    // Responds with the error payload WordPress uses, such as `rest_post_invalid_id`
+    pub fn respond_with_wp_error(
+        &self,
+        method: RequestMethod,
+        route: String,
+        status_code: u16,
+        error_code: WpErrorCode,
+        error_message: String,
+    ) {
+        self.respond_with(
+            method,
+            route,
+            wp_error_response(status_code, error_code, error_message),
+        );
+    }
+
+    // All the executed requests, in order
+    pub fn requests(&self) -> Vec<Arc<WpNetworkRequest>> {
+        self.requests
+            .lock()
+            .map(|requests| requests.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn requests_matching(
+        &self,
+        method: RequestMethod,
+        route: String,
+    ) -> Vec<Arc<WpNetworkRequest>> {
+        self.requests()
+            .into_iter()
+            .filter(|request| {
+                request.method == method && path_params(&route, &request.url.0).is_some()
+            })
+            .collect()
+    }
+
+    // Removes the handlers & the recorded requests
+    pub fn reset(&self) {
+        if let Ok(mut routes) = self.routes.lock() {
+            routes.clear();
+        }
+        if let Ok(mut requests) = self.requests.lock() {
+            requests.clear();
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for MockRequestExecutor {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        if let Ok(mut requests) = self.requests.lock() {
+            requests.push(request.clone());
+        }
+        // The lock is released before calling the handler, so it can register other handlers
+        let handler = self.routes.lock().ok().and_then(|routes| {
+            routes.iter().rev().find_map(|route| {
+                if route.method != request.method {
+                    return None;
+                }
+                path_params(&route.route, &request.url.0)
+                    .map(|path_params| (route.handler.clone(), path_params))
+            })
+        });
+        Ok(match handler {
+            Some((handler, path_params)) => handler.handle(request, path_params),
+            None => wp_error_response(
+                404,
+                WpErrorCode::CustomError("rest_no_route".to_string()),
+                "No route was found matching the URL and request method.".to_string(),
+            ),
+        })
+    }
+}
+
+// Returns the values of the route's placeholders if the url matches it, supporting both the
+// `/wp-json/<route>` & the `?rest_route=<route>` urls.
+fn path_params(route: &str, url: &str) -> Option<HashMap<String, String>> {
+    let url = url::Url::parse(url).ok()?;
+    let request_route = match url.query_pairs().find(|(name, _)| name == "rest_route") {
+        Some((_, rest_route)) => rest_route.into_owned(),
+        None => {
+            let path = url.path();
+            let (_, request_route) = path.split_once("/wp-json")?;
+            request_route.to_string()
+        }
+    };
+    let route_segments = route
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    let request_route_segments = request_route
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    if route_segments.len() != request_route_segments.len() {
+        return None;
+    }
+    let mut path_params = HashMap::new();
+    for (route_segment, request_route_segment) in route_segments.iter().zip(request_route_segments)
+    {
+        match route_segment
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+        {
+            Some(name) => {
+                path_params.insert(name.to_string(), request_route_segment.to_string());
+            }
+            None if *route_segment == request_route_segment => {}
+            None => return None,
+        }
+    }
+    Some(path_params)
+}
+
+fn json_response(status_code: u16, body: Vec<u8>) -> WpNetworkResponse {
+    let mut header_map = HeaderMap::new();
+    header_map.insert(
+        http::header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json; charset=UTF-8"),
+    );
+    WpNetworkResponse {
+        body,
+        status_code,
+        header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
+    }
+}
+
+fn wp_error_response(
+    status_code: u16,
+    error_code: WpErrorCode,
+    error_message: String,
+) -> WpNetworkResponse {
+    let payload = WpErrorPayload {
+        code: error_code,
+        message: error_message,
+        data: WpErrorPayloadData {
+            status: status_code,
+        },
+    };
+    json_response(
+        status_code,
+        serde_json::to_vec(&payload).expect("WpErrorPayload can always be serialized"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        posts::{PostId, PostRetrieveParams},
+        WpApiClient, WpApiError, WpAuthentication,
+    };
+    use rstest::*;
+
+    // Echoes the `post_id` placeholder back as the post's title
+    #[derive(Debug)]
+    struct EchoPostIdHandler;
+
+    impl MockRequestHandler for EchoPostIdHandler {
+        fn handle(
+            &self,
+            _request: Arc<WpNetworkRequest>,
+            path_params: HashMap<String, String>,
+        ) -> WpNetworkResponse {
+            json_response(
+                200,
+                format!("{{\"title\": \"{}\"}}", path_params["post_id"]).into_bytes(),
+            )
+        }
+    }
+
+    #[rstest]
+    #[case("/wp/v2/posts/<post_id>", "https://example.com/wp-json/wp/v2/posts/7?context=edit", Some(vec![("post_id", "7")]))]
+    #[case("/wp/v2/posts/<post_id>", "https://example.com/?rest_route=/wp/v2/posts/7", Some(vec![("post_id", "7")]))]
+    #[case("/wp/v2/users/me", "https://example.com/blog/wp-json/wp/v2/users/me", Some(vec![]))]
+    #[case(
+        "/wp/v2/posts/<post_id>",
+        "https://example.com/wp-json/wp/v2/posts",
+        None
+    )]
+    #[case(
+        "/wp/v2/posts/<post_id>",
+        "https://example.com/wp-json/wp/v2/pages/7",
+        None
+    )]
+    #[case("/wp/v2/posts", "https://example.com/wp/v2/posts", None)]
+    fn match_routes(
+        #[case] route: &str,
+        #[case] url: &str,
+        #[case] expected_path_params: Option<Vec<(&str, &str)>>,
+    ) {
+        assert_eq!(
+            path_params(route, url),
+            expected_path_params.map(|path_params| path_params
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect())
+        );
+    }
+
+    #[rstest]
+    fn respond_with_wp_error() {
+        let executor = Arc::new(MockRequestExecutor::new());
+        executor.respond_with_wp_error(
+            RequestMethod::GET,
+            "/wp/v2/posts/<post_id>".to_string(),
+            404,
+            WpErrorCode::PostInvalidId,
+            "Invalid post ID.".to_string(),
+        );
+        let result = futures::executor::block_on(
+            api_client(executor.clone())
+                .posts()
+                .retrieve_with_edit_context(&PostId(1), &PostRetrieveParams::default()),
+        );
+        assert!(
+            matches!(
+                result,
+                Err(WpApiError::WpError {
+                    error_code: WpErrorCode::PostInvalidId,
+                    status_code: 404,
+                    ..
+                })
+            ),
+            "{:?}",
+            result
+        );
+        assert_eq!(
+            executor
+                .requests_matching(RequestMethod::GET, "/wp/v2/posts/<post_id>".to_string())
+                .len(),
+            1
+        );
+        assert!(executor
+            .requests_matching(RequestMethod::DELETE, "/wp/v2/posts/<post_id>".to_string())
+            .is_empty());
+    }
+
+    #[rstest]
+    fn later_handlers_take_precedence() {
+        let executor = MockRequestExecutor::new();
+        executor.respond_with_json(
+            RequestMethod::GET,
+            "/wp/v2/posts/<post_id>".to_string(),
+            200,
+            "{}".to_string(),
+        );
+        executor.on_request(
+            RequestMethod::GET,
+            "/wp/v2/posts/<post_id>".to_string(),
+            Arc::new(EchoPostIdHandler),
+        );
+        let response = futures::executor::block_on(executor.execute(request(
+            RequestMethod::GET,
+            "https://example.com/wp-json/wp/v2/posts/42",
+        )))
+        .unwrap();
+        assert_eq!(response.body_as_string(), "{\"title\": \"42\"}");
+    }
+
+    #[rstest]
+    fn respond_with_no_route_error_without_handler() {
+        let executor = MockRequestExecutor::new();
+        let response = futures::executor::block_on(executor.execute(request(
+            RequestMethod::POST,
+            "https://example.com/wp-json/wp/v2/posts",
+        )))
+        .unwrap();
+        assert_eq!(response.status_code, 404);
+        assert_eq!(
+            response.body_as_string(),
+            "{\"code\":\"rest_no_route\",\"message\":\"No route was found matching the URL and request method.\",\"data\":{\"status\":404}}"
+        );
+        assert_eq!(executor.requests().len(), 1);
+        executor.reset();
+        assert!(executor.requests().is_empty());
+    }
+
+    fn api_client(executor: Arc<MockRequestExecutor>) -> WpApiClient {
+        WpApiClient::new(
+            crate::ParsedUrl::parse("https://example.com")
+                .unwrap()
+                .into(),
+            WpAuthentication::None,
+            executor,
+        )
+    }
+
+    fn request(method: RequestMethod, url: &str) -> Arc<WpNetworkRequest> {
+        Arc::new(WpNetworkRequest {
+            method,
+            url: crate::request::endpoint::WpEndpointUrl(url.to_string()),
+            header_map: WpNetworkHeaderMap::default().into(),
+            body: None,
+            follow_redirects: true,
+        })
+    }
+}
@@ -0,0 +1,402 @@
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+
+use crate::{
+    impl_as_query_value_for_new_type, impl_as_query_value_from_as_str, impl_pagination_params,
+    posts::{MediaId, PostCommentStatus, PostMeta, PostPingStatus, PostStatus},
+    url_query::{AppendUrlQueryPairs, AsQueryValue, QueryPairs, QueryPairsExtension},
+    UserId, WpApiParamOrder,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum WpApiParamPagesOrderBy {
+    Author,
+    #[default]
+    Date,
+    Id,
+    Include,
+    IncludeSlugs,
+    MenuOrder,
+    Modified,
+    Parent,
+    Relevance,
+    Slug,
+    Title,
+}
+
+impl_as_query_value_from_as_str!(WpApiParamPagesOrderBy);
+
+impl WpApiParamPagesOrderBy {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Author => "author",
+            Self::Date => "date",
+            Self::Id => "id",
+            Self::Include => "include",
+            Self::IncludeSlugs => "include_slugs",
+            Self::MenuOrder => "menu_order",
+            Self::Modified => "modified",
+            Self::Parent => "parent",
+            Self::Relevance => "relevance",
+            Self::Slug => "slug",
+            Self::Title => "title",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, uniffi::Record)]
+pub struct PageListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Limit response to pages published after a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub after: Option<String>,
+    /// Limit response to pages modified after a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub modified_after: Option<String>,
+    /// Limit result set to pages assigned to specific authors.
+    #[uniffi(default = [])]
+    pub author: Vec<UserId>,
+    /// Ensure result set excludes pages assigned to specific authors.
+    #[uniffi(default = [])]
+    pub author_exclude: Vec<UserId>,
+    /// Limit response to pages published before a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub before: Option<String>,
+    /// Limit response to pages modified before a given ISO8601 compliant date.
+    #[uniffi(default = None)]
+    pub modified_before: Option<String>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<PageId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<PageId>,
+    /// Limit result set to pages with a specific menu_order value.
+    #[uniffi(default = None)]
+    pub menu_order: Option<u32>,
+    /// Offset the result set by a specific number of items.
+    #[uniffi(default = None)]
+    pub offset: Option<u32>,
+    /// Order sort attribute ascending or descending.
+    /// Default: desc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Sort collection by page attribute.
+    /// Default: date
+    /// One of: author, date, id, include, modified, parent, relevance, slug, include_slugs, title, menu_order
+    #[uniffi(default = None)]
+    pub orderby: Option<WpApiParamPagesOrderBy>,
+    /// Limit result set to items with particular parent IDs.
+    #[uniffi(default = [])]
+    pub parent: Vec<PageId>,
+    /// Limit result set to all items except those of a particular parent ID.
+    #[uniffi(default = [])]
+    pub parent_exclude: Vec<PageId>,
+    /// Array of column names to be searched.
+    #[uniffi(default = [])]
+    pub search_columns: Vec<crate::posts::WpApiParamPostsSearchColumn>,
+    /// Limit result set to pages with one or more specific slugs.
+    #[uniffi(default = [])]
+    pub slug: Vec<String>,
+    /// Limit result set to pages assigned one or more statuses.
+    /// Default: publish
+    #[uniffi(default = [])]
+    pub status: Vec<PostStatus>,
+}
+
+impl AppendUrlQueryPairs for PageListParams {
+    fn append_query_pairs(&self, query_pairs_mut: &mut QueryPairs) {
+        query_pairs_mut
+            .append_option_query_value_pair("page", self.page.as_ref())
+            .append_option_query_value_pair("per_page", self.per_page.as_ref())
+            .append_option_query_value_pair("search", self.search.as_ref())
+            .append_option_query_value_pair("after", self.after.as_ref())
+            .append_option_query_value_pair("modified_after", self.modified_after.as_ref())
+            .append_vec_query_value_pair("author", &self.author)
+            .append_vec_query_value_pair("author_exclude", &self.author_exclude)
+            .append_option_query_value_pair("before", self.before.as_ref())
+            .append_option_query_value_pair("modified_before", self.modified_before.as_ref())
+            .append_vec_query_value_pair("exclude", &self.exclude)
+            .append_vec_query_value_pair("include", &self.include)
+            .append_option_query_value_pair("menu_order", self.menu_order.as_ref())
+            .append_option_query_value_pair("offset", self.offset.as_ref())
+            .append_option_query_value_pair("order", self.order.as_ref())
+            .append_option_query_value_pair("orderby", self.orderby.as_ref())
+            .append_vec_query_value_pair("parent", &self.parent)
+            .append_vec_query_value_pair("parent_exclude", &self.parent_exclude)
+            .append_vec_query_value_pair("search_columns", &self.search_columns)
+            .append_vec_query_value_pair("slug", &self.slug)
+            .append_vec_query_value_pair("status", &self.status);
+    }
+}
+
+impl_pagination_params!(PageListParams);
+
+#[derive(Debug, Default, uniffi::Record)]
+pub struct PageRetrieveParams {
+    /// The password for the page if it is password protected.
+    #[uniffi(default = None)]
+    pub password: Option<String>,
+}
+
+impl AppendUrlQueryPairs for PageRetrieveParams {
+    fn append_query_pairs(&self, query_pairs_mut: &mut QueryPairs) {
+        query_pairs_mut.append_option_query_value_pair("password", self.password.as_ref());
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct PageDeleteResponse {
+    pub deleted: bool,
+    pub previous: PageWithEditContext,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct PageCreateParams {
+    // The date the page was published, in the site's timezone.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    // The date the page was published, as GMT.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_gmt: Option<String>,
+    // An alphanumeric identifier for the page unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    // A named status for the page.
+    // One of: publish, future, draft, pending, private
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    // A password to protect access to the content and excerpt.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    // The ID for the parent of the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<PageId>,
+    // The title for the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    // The content for the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    // The ID for the author of the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserId>,
+    // The excerpt for the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excerpt: Option<String>,
+    // The ID of the featured media for the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub featured_media: Option<MediaId>,
+    // Whether or not comments are open on the page.
+    // One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_status: Option<PostCommentStatus>,
+    // Whether or not the page can be pinged.
+    // One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ping_status: Option<PostPingStatus>,
+    // The order of the page in relation to other pages.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_order: Option<u32>,
+    // Meta fields.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<PostMeta>,
+    // The theme file to use to display the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct PageUpdateParams {
+    // The date the page was published, in the site's timezone.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    // The date the page was published, as GMT.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_gmt: Option<String>,
+    // An alphanumeric identifier for the page unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    // A named status for the page.
+    // One of: publish, future, draft, pending, private
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PostStatus>,
+    // A password to protect access to the content and excerpt.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    // The ID for the parent of the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<PageId>,
+    // The title for the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    // The content for the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    // The ID for the author of the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserId>,
+    // The excerpt for the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excerpt: Option<String>,
+    // The ID of the featured media for the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub featured_media: Option<MediaId>,
+    // Whether or not comments are open on the page.
+    // One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_status: Option<PostCommentStatus>,
+    // Whether or not the page can be pinged.
+    // One of: open, closed
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ping_status: Option<PostPingStatus>,
+    // The order of the page in relation to other pages.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_order: Option<u32>,
+    // Meta fields.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<PostMeta>,
+    // The theme file to use to display the page.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+impl_as_query_value_for_new_type!(PageId);
+uniffi::custom_newtype!(PageId, i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageId(pub i32);
+
+impl std::fmt::Display for PageId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparsePage {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<PageId>,
+    #[WpContext(edit, view)]
+    pub date: Option<String>,
+    #[WpContext(edit, view)]
+    pub date_gmt: Option<String>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub guid: Option<crate::posts::SparsePostGuid>,
+    #[WpContext(edit, embed, view)]
+    pub link: Option<String>,
+    #[WpContext(edit, view)]
+    pub modified: Option<String>,
+    #[WpContext(edit, view)]
+    pub modified_gmt: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit, view)]
+    pub status: Option<PostStatus>,
+    #[serde(rename = "type")]
+    #[WpContext(edit, embed, view)]
+    pub post_type: Option<String>,
+    #[WpContext(edit)]
+    pub password: Option<String>,
+    #[WpContext(edit)]
+    pub permalink_template: Option<String>,
+    #[WpContext(edit)]
+    pub generated_slug: Option<String>,
+    #[WpContext(edit, view)]
+    pub parent: Option<PageId>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub title: Option<crate::posts::SparsePostTitle>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub content: Option<crate::posts::SparsePostContent>,
+    #[WpContext(edit, embed, view)]
+    pub author: Option<UserId>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub excerpt: Option<crate::posts::SparsePostExcerpt>,
+    #[WpContext(edit, embed, view)]
+    pub featured_media: Option<MediaId>,
+    #[WpContext(edit, view)]
+    pub comment_status: Option<PostCommentStatus>,
+    #[WpContext(edit, view)]
+    pub ping_status: Option<PostPingStatus>,
+    #[WpContext(edit, view)]
+    pub menu_order: Option<u32>,
+    #[WpContext(edit, view)]
+    pub meta: Option<PostMeta>,
+    #[WpContext(edit, view)]
+    pub template: Option<String>,
+    // Only available if the linked resources are requested using `_embed`
+    #[serde(rename = "_embedded")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub embedded: Option<crate::embed::WpEmbedded>,
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+
+    #[rstest]
+    #[case(PageListParams::default(), "")]
+    #[case(generate!(PageListParams, (page, Some(2))), "page=2")]
+    #[case(generate!(PageListParams, (menu_order, Some(3))), "menu_order=3")]
+    #[case(generate!(PageListParams, (orderby, Some(WpApiParamPagesOrderBy::MenuOrder))), "orderby=menu_order")]
+    #[case(generate!(PageListParams, (parent, vec![PageId(1), PageId(2)])), "parent=1%2C2")]
+    #[case(generate!(PageListParams, (parent_exclude, vec![PageId(3)])), "parent_exclude=3")]
+    #[case(generate!(PageListParams, (parent, vec![PageId(1)]), (parent_exclude, vec![PageId(2)]), (status, vec![PostStatus::Draft])), "parent=1&parent_exclude=2&status=draft")]
+    #[trace]
+    fn test_page_list_params(#[case] params: PageListParams, #[case] expected_query: &str) {
+        assert_expected_query_pairs(params, expected_query);
+    }
+}
@@ -0,0 +1,354 @@
+use std::{future::Future, sync::Mutex};
+
+use futures::Stream;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::{request::WpNetworkResponse, WpApiError};
+
+const TOTAL_ITEMS_HEADER_KEY: &str = "X-WP-Total";
+const TOTAL_PAGES_HEADER_KEY: &str = "X-WP-TotalPages";
+
+// List params that can be used to request a specific page of a collection.
+pub trait PaginationParams: Clone {
+    fn with_page(&self, page: u32) -> Self;
+}
+
+// A single page of a collection along with the pagination details WordPress sends in the
+// `X-WP-Total`, `X-WP-TotalPages` & `Link` headers.
+#[derive(Debug)]
+pub struct PaginatedResponse<T, P> {
+    pub data: Vec<T>,
+    pub total_items: Option<u32>,
+    pub total_pages: Option<u32>,
+    pub next_page_params: Option<P>,
+    pub prev_page_params: Option<P>,
+}
+
+impl<T, P> PaginatedResponse<T, P>
+where
+    T: DeserializeOwned,
+    P: PaginationParams,
+{
+    pub fn from_response(response: &WpNetworkResponse, params: &P) -> Result<Self, WpApiError> {
+        let data = response.parse::<_, WpApiError>()?;
+        let page_params = |rel: &str| {
+            response
+                .get_link_header(rel)
+                .first()
+                .map(|url| params.with_page(page_from_link(url)))
+        };
+        Ok(Self {
+            data,
+            total_items: header_as_u32(response, TOTAL_ITEMS_HEADER_KEY),
+            total_pages: header_as_u32(response, TOTAL_PAGES_HEADER_KEY),
+            next_page_params: page_params("next"),
+            prev_page_params: page_params("prev"),
+        })
+    }
+}
+
+fn header_as_u32(response: &WpNetworkResponse, header_name: &str) -> Option<u32> {
+    response
+        .get_header(header_name)
+        .and_then(|value| value.trim().parse().ok())
+}
+
+// WordPress omits the `page` query parameter when linking to the first page
+fn page_from_link(url: &Url) -> u32 {
+    url.query_pairs()
+        .find(|(name, _)| name == "page")
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(1)
+}
+
+// Returns a stream of pages, starting with the page for the given `params` and following the
+// `next` links until the last page is fetched or an error occurs.
+pub fn paginate<T, P, F, Fut>(
+    params: P,
+    fetch_page: F,
+) -> impl Stream<Item = Result<Vec<T>, WpApiError>>
+where
+    P: PaginationParams,
+    F: Fn(P) -> Fut + Clone,
+    Fut: Future<Output = Result<PaginatedResponse<T, P>, WpApiError>>,
+{
+    futures::stream::try_unfold(Some(params), move |params| {
+        let fetch_page = fetch_page.clone();
+        async move {
+            match params {
+                Some(params) => {
+                    let response = fetch_page(params).await?;
+                    Ok(Some((response.data, response.next_page_params)))
+                }
+                None => Ok(None),
+            }
+        }
+    })
+}
+
+// Keeps track of the next page to fetch for the generated `uniffi` paginator objects, because
+// streams can't be exported.
+#[derive(Debug)]
+pub struct Paginator<P> {
+    next_page_params: futures::lock::Mutex<Option<P>>,
+    totals: Mutex<(Option<u32>, Option<u32>)>,
+}
+
+impl<P> Paginator<P>
+where
+    P: PaginationParams,
+{
+    pub fn new(params: P) -> Self {
+        Self {
+            next_page_params: futures::lock::Mutex::new(Some(params)),
+            totals: Mutex::new((None, None)),
+        }
+    }
+
+    pub async fn next_page<T, F, Fut>(&self, fetch_page: F) -> Result<Option<Vec<T>>, WpApiError>
+    where
+        F: FnOnce(P) -> Fut,
+        Fut: Future<Output = Result<PaginatedResponse<T, P>, WpApiError>>,
+    {
+        // Holding the lock while the page is fetched ensures concurrent calls get consecutive pages
+        let mut next_page_params = self.next_page_params.lock().await;
+        let Some(params) = next_page_params.clone() else {
+            return Ok(None);
+        };
+        let response = fetch_page(params).await?;
+        *next_page_params = response.next_page_params;
+        if let Ok(mut totals) = self.totals.lock() {
+            *totals = (response.total_items, response.total_pages);
+        }
+        Ok(Some(response.data))
+    }
+
+    pub fn total_items(&self) -> Option<u32> {
+        self.totals.lock().ok().and_then(|totals| totals.0)
+    }
+
+    pub fn total_pages(&self) -> Option<u32> {
+        self.totals.lock().ok().and_then(|totals| totals.1)
+    }
+}
+
+mod macro_helper {
+    #[macro_export]
+    macro_rules! impl_pagination_params {
+        ($ident: ident) => {
+            impl $crate::pagination::PaginationParams for $ident {
+                fn with_page(&self, page: u32) -> Self {
+                    Self {
+                        page: Some(page),
+                        ..self.clone()
+                    }
+                }
+            }
+        };
+    }
+
+    // Generates paginated variants of the `list_with_{context}_context` functions of a request
+    // executor. It has to be used in the module of the request executor, because it accesses its
+    // private fields.
+    #[macro_export]
+    macro_rules! generate_paginated_list_impl {
+        ($request_prefix: ident, $params: ident; $($context: ident => $output: path),*) => {
+            paste::paste! {
+                impl [<$request_prefix RequestExecutor>] {
+                    $(
+                    pub async fn [<paginated_list_with_ $context _context>](
+                        &self,
+                        params: &$params,
+                    ) -> Result<$crate::pagination::PaginatedResponse<$output, $params>, $crate::WpApiError> {
+                        let request = self.request_builder.[<list_with_ $context _context>](params)?;
+                        let response = self
+                            .request_executor
+                            .execute(std::sync::Arc::new(request))
+                            .await?;
+                        $crate::pagination::PaginatedResponse::from_response(&response, params)
+                    }
+
+                    pub fn [<list_all_with_ $context _context>](
+                        &self,
+                        params: &$params,
+                    ) -> impl futures::Stream<Item = Result<Vec<$output>, $crate::WpApiError>> + '_ {
+                        $crate::pagination::paginate(params.clone(), move |params| async move {
+                            self.[<paginated_list_with_ $context _context>](&params).await
+                        })
+                    }
+                    )*
+                }
+
+                #[uniffi::export]
+                impl [<$request_prefix RequestExecutor>] {
+                    $(
+                    pub fn [<paginator_with_ $context _context>](
+                        self: std::sync::Arc<Self>,
+                        params: &$params,
+                    ) -> std::sync::Arc<[<$request_prefix ListWith $context:camel ContextPaginator>]> {
+                        std::sync::Arc::new([<$request_prefix ListWith $context:camel ContextPaginator>] {
+                            request_executor: self,
+                            inner: $crate::pagination::Paginator::new(params.clone()),
+                        })
+                    }
+                    )*
+                }
+
+                $(
+                #[derive(Debug, uniffi::Object)]
+                pub struct [<$request_prefix ListWith $context:camel ContextPaginator>] {
+                    request_executor: std::sync::Arc<[<$request_prefix RequestExecutor>]>,
+                    inner: $crate::pagination::Paginator<$params>,
+                }
+
+                #[uniffi::export]
+                impl [<$request_prefix ListWith $context:camel ContextPaginator>] {
+                    // Returns `None` once all the pages are fetched.
+                    pub async fn next_page(&self) -> Result<Option<Vec<$output>>, $crate::WpApiError> {
+                        self.inner
+                            .next_page(|params| async move {
+                                self.request_executor
+                                    .[<paginated_list_with_ $context _context>](&params)
+                                    .await
+                            })
+                            .await
+                    }
+
+                    // The total number of items as of the last fetched page.
+                    pub fn total_items(&self) -> Option<u32> {
+                        self.inner.total_items()
+                    }
+
+                    // The total number of pages as of the last fetched page.
+                    pub fn total_pages(&self) -> Option<u32> {
+                        self.inner.total_pages()
+                    }
+                }
+                )*
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{impl_pagination_params, request::WpNetworkHeaderMap};
+    use futures::TryStreamExt;
+    use rstest::*;
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    struct TestListParams {
+        page: Option<u32>,
+        search: Option<String>,
+    }
+
+    impl_pagination_params!(TestListParams);
+
+    #[rstest]
+    fn parse_paginated_response() {
+        let response = response(
+            "[1,2]",
+            &[
+                ("X-WP-Total", "5"),
+                ("X-WP-TotalPages", "3"),
+                ("Link", "<https://example.com/wp-json/wp/v2/posts?search=foo&page=1>; rel=\"prev\", <https://example.com/wp-json/wp/v2/posts?search=foo&page=3>; rel=\"next\""),
+            ],
+        );
+        let params = TestListParams {
+            page: Some(2),
+            search: Some("foo".to_string()),
+        };
+        let paginated_response: PaginatedResponse<u32, TestListParams> =
+            PaginatedResponse::from_response(&response, &params).unwrap();
+        assert_eq!(paginated_response.data, vec![1, 2]);
+        assert_eq!(paginated_response.total_items, Some(5));
+        assert_eq!(paginated_response.total_pages, Some(3));
+        assert_eq!(
+            paginated_response.next_page_params,
+            Some(params.with_page(3))
+        );
+        assert_eq!(
+            paginated_response.prev_page_params,
+            Some(params.with_page(1))
+        );
+    }
+
+    #[rstest]
+    fn parse_paginated_response_without_pagination_headers() {
+        let paginated_response: PaginatedResponse<u32, TestListParams> =
+            PaginatedResponse::from_response(&response("[]", &[]), &TestListParams::default())
+                .unwrap();
+        assert!(paginated_response.data.is_empty());
+        assert_eq!(paginated_response.total_items, None);
+        assert_eq!(paginated_response.total_pages, None);
+        assert_eq!(paginated_response.next_page_params, None);
+        assert_eq!(paginated_response.prev_page_params, None);
+    }
+
+    #[rstest]
+    #[case("https://example.com/wp-json/wp/v2/posts?page=4", 4)]
+    #[case("https://example.com/wp-json/wp/v2/posts?per_page=2", 1)]
+    fn test_page_from_link(#[case] url: &str, #[case] expected_page: u32) {
+        assert_eq!(page_from_link(&Url::parse(url).unwrap()), expected_page);
+    }
+
+    #[rstest]
+    fn paginate_until_the_last_page() {
+        let pages = futures::executor::block_on(
+            paginate(TestListParams::default(), |params| async move {
+                Ok(test_page(params.page.unwrap_or(1), 3))
+            })
+            .try_collect::<Vec<_>>(),
+        )
+        .unwrap();
+        assert_eq!(pages, vec![vec![1], vec![2], vec![3]]);
+    }
+
+    #[rstest]
+    fn paginator_returns_none_after_the_last_page() {
+        let fetch_count = Arc::new(AtomicU32::new(0));
+        let paginator = Paginator::new(TestListParams::default());
+        let next_page = || {
+            let fetch_count = fetch_count.clone();
+            futures::executor::block_on(paginator.next_page(|params| async move {
+                fetch_count.fetch_add(1, Ordering::SeqCst);
+                Ok(test_page(params.page.unwrap_or(1), 2))
+            }))
+            .unwrap()
+        };
+        assert_eq!(next_page(), Some(vec![1]));
+        assert_eq!(paginator.total_pages(), Some(2));
+        assert_eq!(next_page(), Some(vec![2]));
+        assert_eq!(next_page(), None);
+        assert_eq!(fetch_count.load(Ordering::SeqCst), 2);
+    }
+
+    fn test_page(page: u32, total_pages: u32) -> PaginatedResponse<u32, TestListParams> {
+        let params = TestListParams::default();
+        PaginatedResponse {
+            data: vec![page],
+            total_items: Some(total_pages),
+            total_pages: Some(total_pages),
+            next_page_params: (page < total_pages).then(|| params.with_page(page + 1)),
+            prev_page_params: (page > 1).then(|| params.with_page(page - 1)),
+        }
+    }
+
+    fn response(body: &str, headers: &[(&'static str, &'static str)]) -> WpNetworkResponse {
+        let mut header_map = http::HeaderMap::new();
+        headers.iter().for_each(|(name, value)| {
+            header_map.append(*name, http::HeaderValue::from_static(value));
+        });
+        WpNetworkResponse {
+            body: body.as_bytes().to_vec(),
+            status_code: 200,
+            header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use url::Url;
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, uniffi::Object)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, uniffi::Object)]
 pub struct ParsedUrl {
     pub inner: Url,
 }
@@ -70,6 +70,10 @@ pub struct SparsePlugin {
     pub requires_php: Option<String>,
     #[WpContext(edit, view)]
     pub textdomain: Option<String>,
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
 }
 
 #[derive(Debug, Serialize, Deserialize, uniffi::Record)]
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+
+use crate::{
+    impl_as_query_value_from_as_str,
+    posts::{PostId, RevisionId},
+    url_query::{AppendUrlQueryPairs, AsQueryValue, QueryPairs, QueryPairsExtension},
+    UserId, WpApiParamOrder,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum WpApiParamPostRevisionsOrderBy {
+    #[default]
+    Date,
+    Id,
+    Include,
+    IncludeSlugs,
+    Relevance,
+    Slug,
+    Title,
+}
+
+impl_as_query_value_from_as_str!(WpApiParamPostRevisionsOrderBy);
+
+impl WpApiParamPostRevisionsOrderBy {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Date => "date",
+            Self::Id => "id",
+            Self::Include => "include",
+            Self::IncludeSlugs => "include_slugs",
+            Self::Relevance => "relevance",
+            Self::Slug => "slug",
+            Self::Title => "title",
+        }
+    }
+}
+
+#[derive(Debug, Default, uniffi::Record)]
+pub struct PostRevisionListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<RevisionId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<RevisionId>,
+    /// Offset the result set by a specific number of items.
+    #[uniffi(default = None)]
+    pub offset: Option<u32>,
+    /// Order sort attribute ascending or descending.
+    /// Default: desc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Sort collection by object attribute.
+    /// Default: date
+    /// One of: date, id, include, relevance, slug, include_slugs, title
+    #[uniffi(default = None)]
+    pub orderby: Option<WpApiParamPostRevisionsOrderBy>,
+}
+
+impl AppendUrlQueryPairs for PostRevisionListParams {
+    fn append_query_pairs(&self, query_pairs_mut: &mut QueryPairs) {
+        query_pairs_mut
+            .append_option_query_value_pair("page", self.page.as_ref())
+            .append_option_query_value_pair("per_page", self.per_page.as_ref())
+            .append_option_query_value_pair("search", self.search.as_ref())
+            .append_vec_query_value_pair("exclude", &self.exclude)
+            .append_vec_query_value_pair("include", &self.include)
+            .append_option_query_value_pair("offset", self.offset.as_ref())
+            .append_option_query_value_pair("order", self.order.as_ref())
+            .append_option_query_value_pair("orderby", self.orderby.as_ref());
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct PostRevisionDeleteResponse {
+    pub deleted: bool,
+    pub previous: PostRevisionWithEditContext,
+}
+
+// Autosaves only store the title, content & excerpt of a post.
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct PostAutosaveCreateParams {
+    // The title for the post.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    // The content for the post.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    // The excerpt for the post.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excerpt: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparsePostRevision {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<RevisionId>,
+    #[WpContext(edit, embed, view)]
+    pub author: Option<UserId>,
+    #[WpContext(edit, embed, view)]
+    pub date: Option<String>,
+    #[WpContext(edit, view)]
+    pub date_gmt: Option<String>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub guid: Option<crate::posts::SparsePostGuid>,
+    #[WpContext(edit, view)]
+    pub modified: Option<String>,
+    #[WpContext(edit, view)]
+    pub modified_gmt: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub parent: Option<PostId>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub title: Option<crate::posts::SparsePostTitle>,
+    #[WpContext(edit, view)]
+    #[WpContextualField]
+    pub content: Option<crate::posts::SparsePostContent>,
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub excerpt: Option<crate::posts::SparsePostExcerpt>,
+    // Only available for autosaves
+    #[WpContext(edit)]
+    #[WpContextualOption]
+    pub preview_link: Option<String>,
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
+}
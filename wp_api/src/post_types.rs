@@ -3,6 +3,8 @@ use std::{collections::HashMap, fmt::Display};
 use serde::{Deserialize, Serialize};
 use wp_contextual::WpContextual;
 
+use crate::{impl_as_query_value_from_to_string, url_query::AsQueryValue};
+
 #[derive(
     Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, uniffi::Enum,
 )]
@@ -22,6 +24,8 @@ pub enum PostType {
     Custom(String),
 }
 
+impl_as_query_value_from_to_string!(PostType);
+
 impl Display for PostType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -81,6 +85,10 @@ pub struct SparsePostTypeDetails {
     #[WpContext(edit, embed, view)]
     #[WpContextualOption]
     pub icon: Option<String>,
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
@@ -3,7 +3,7 @@ use wp_contextual::WpContextual;
 use wp_serde_helper::{deserialize_from_string_of_json_array, serialize_as_json_string};
 
 use crate::{
-    impl_as_query_value_for_new_type, impl_as_query_value_from_as_str,
+    impl_as_query_value_for_new_type, impl_as_query_value_from_as_str, impl_pagination_params,
     url_query::{AppendUrlQueryPairs, AsQueryValue, QueryPairs, QueryPairsExtension},
     UserId, WpApiParamOrder,
 };
@@ -78,7 +78,7 @@ impl WpApiParamPostsSearchColumn {
     }
 }
 
-#[derive(Debug, Default, uniffi::Record)]
+#[derive(Debug, Default, Clone, uniffi::Record)]
 pub struct PostListParams {
     /// Current page of the collection.
     /// Default: `1`
@@ -188,6 +188,8 @@ impl AppendUrlQueryPairs for PostListParams {
     }
 }
 
+impl_pagination_params!(PostListParams);
+
 #[derive(Debug, Default, uniffi::Record)]
 pub struct PostRetrieveParams {
     /// The password for the post if it is password protected.
@@ -368,6 +370,11 @@ uniffi::custom_newtype!(PostId, i32);
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PostId(pub i32);
 
+impl_as_query_value_for_new_type!(RevisionId);
+uniffi::custom_newtype!(RevisionId, i32);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevisionId(pub i32);
+
 impl_as_query_value_for_new_type!(TagId);
 uniffi::custom_newtype!(TagId, i32);
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -389,6 +396,30 @@ impl std::fmt::Display for PostId {
     }
 }
 
+impl std::fmt::Display for RevisionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::fmt::Display for TagId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::fmt::Display for CategoryId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::fmt::Display for MediaId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
 pub struct SparsePost {
     #[WpContext(edit, embed, view)]
@@ -448,6 +479,15 @@ pub struct SparsePost {
     pub categories: Option<Vec<CategoryId>>,
     #[WpContext(edit, view)]
     pub tags: Option<Vec<TagId>>,
+    // Only available if the linked resources are requested using `_embed`
+    #[serde(rename = "_embedded")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub embedded: Option<crate::embed::WpEmbedded>,
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
 }
 
 #[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
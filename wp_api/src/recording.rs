@@ -0,0 +1,781 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use base64::prelude::*;
+use http::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api_error::RequestExecutionError,
+    request::{
+        RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
+    },
+};
+
+// The values of these headers are replaced before they are written to a fixture, so credentials
+// don't end up in a repository.
+const REDACTED_HEADER_NAMES: [HeaderName; 4] = [
+    http::header::AUTHORIZATION,
+    http::header::COOKIE,
+    http::header::SET_COOKIE,
+    HeaderName::from_static("x-wp-nonce"),
+];
+// The same goes for these fields of form & JSON bodies, such as the password of a `wp-login.php`
+// login or the password of a new application password.
+const REDACTED_FORM_FIELDS: [&str; 1] = ["pwd"];
+const REDACTED_JSON_FIELDS: [&str; 1] = ["password"];
+const REDACTED_VALUE: &str = "<redacted>";
+// `admin-ajax.php?action=rest-nonce` responds with the nonce as plain text, so there is no field to
+// redact. It's replaced with an alphanumeric value instead, so it's still a valid nonce on replay.
+const REDACTED_NONCE: &str = "redacted";
+const CONTENT_TYPE_FORM_URLENCODED: &str = "application/x-www-form-urlencoded";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedExchange {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: RequestMethod,
+    url: String,
+    headers: BTreeMap<String, Vec<String>>,
+    #[serde(flatten)]
+    body: RecordedBody,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status_code: u16,
+    headers: BTreeMap<String, Vec<String>>,
+    #[serde(flatten)]
+    body: RecordedBody,
+}
+
+// Bodies are stored as text when possible, so fixtures are easy to review & edit, and as base64
+// otherwise, such as for media uploads.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct RecordedBody {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_base64: Option<String>,
+}
+
+impl RecordedBody {
+    fn new(body: Option<Vec<u8>>) -> Self {
+        match body.map(String::from_utf8) {
+            Some(Ok(body)) => Self {
+                body: Some(body),
+                body_base64: None,
+            },
+            Some(Err(e)) => Self {
+                body: None,
+                body_base64: Some(BASE64_STANDARD.encode(e.into_bytes())),
+            },
+            None => Self::default(),
+        }
+    }
+
+    fn contents(&self) -> Option<Vec<u8>> {
+        match (&self.body, &self.body_base64) {
+            (Some(body), _) => Some(body.as_bytes().to_vec()),
+            (None, Some(body_base64)) => BASE64_STANDARD.decode(body_base64).ok(),
+            (None, None) => None,
+        }
+    }
+}
+
+fn recorded_headers(header_map: &WpNetworkHeaderMap) -> BTreeMap<String, Vec<String>> {
+    let mut headers = BTreeMap::<String, Vec<String>>::new();
+    header_map.as_header_map().iter().for_each(|(name, value)| {
+        let value = if REDACTED_HEADER_NAMES.contains(name) {
+            REDACTED_VALUE.to_string()
+        } else {
+            String::from_utf8_lossy(value.as_bytes()).into_owned()
+        };
+        headers.entry(name.to_string()).or_default().push(value);
+    });
+    headers
+}
+
+// Bodies without any redacted fields are returned as they are, so they are recorded exactly as
+// they were sent or received.
+fn redacted_body(body: Vec<u8>, header_map: &WpNetworkHeaderMap) -> Vec<u8> {
+    let is_form = header_map
+        .as_header_map()
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with(CONTENT_TYPE_FORM_URLENCODED));
+    if is_form {
+        redacted_form_body(&body)
+    } else {
+        redacted_json_body(&body)
+    }
+    .unwrap_or(body)
+}
+
+fn redacted_form_body(body: &[u8]) -> Option<Vec<u8>> {
+    let fields = url::form_urlencoded::parse(body).collect::<Vec<_>>();
+    if !fields
+        .iter()
+        .any(|(name, _)| REDACTED_FORM_FIELDS.contains(&name.as_ref()))
+    {
+        return None;
+    }
+    let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+    fields.iter().for_each(|(name, value)| {
+        if REDACTED_FORM_FIELDS.contains(&name.as_ref()) {
+            serializer.append_pair(name, REDACTED_VALUE);
+        } else {
+            serializer.append_pair(name, value);
+        }
+    });
+    Some(serializer.finish().into_bytes())
+}
+
+fn redacted_json_body(body: &[u8]) -> Option<Vec<u8>> {
+    let mut value = serde_json::from_slice::<serde_json::Value>(body).ok()?;
+    if !redact_json_fields(&mut value) {
+        return None;
+    }
+    serde_json::to_vec(&value).ok()
+}
+
+// Returns whether any field is redacted
+fn redact_json_fields(value: &mut serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Object(fields) => {
+            fields.iter_mut().fold(false, |is_redacted, (name, value)| {
+                if REDACTED_JSON_FIELDS.contains(&name.as_str()) && value.is_string() {
+                    *value = serde_json::Value::String(REDACTED_VALUE.to_string());
+                    true
+                } else {
+                    redact_json_fields(value) || is_redacted
+                }
+            })
+        }
+        serde_json::Value::Array(values) => {
+            // Every value is visited, so this doesn't stop at the first redacted one
+            let mut is_redacted = false;
+            for value in values {
+                is_redacted |= redact_json_fields(value);
+            }
+            is_redacted
+        }
+        _ => false,
+    }
+}
+
+// A response of `0` means the cookies weren't valid, so it's recorded as it is.
+fn is_nonce_response(request: &WpNetworkRequest, response: &WpNetworkResponse) -> bool {
+    let Ok(url) = url::Url::parse(&request.url.0) else {
+        return false;
+    };
+    url.path().ends_with("/admin-ajax.php")
+        && url
+            .query_pairs()
+            .any(|(name, value)| name == "action" && value == "rest-nonce")
+        && response.body_as_string().trim() != "0"
+}
+
+fn header_map(headers: &BTreeMap<String, Vec<String>>) -> HeaderMap {
+    let mut header_map = HeaderMap::new();
+    headers.iter().for_each(|(name, values)| {
+        if let Ok(name) = HeaderName::from_bytes(name.as_bytes()) {
+            values.iter().for_each(|value| {
+                if let Ok(value) = HeaderValue::from_str(value) {
+                    header_map.append(name.clone(), value);
+                }
+            });
+        }
+    });
+    header_map
+}
+
+impl RecordedExchange {
+    fn new(request: &WpNetworkRequest, response: &WpNetworkResponse) -> Self {
+        Self {
+            request: RecordedRequest {
+                method: request.method.clone(),
+                url: request.url.0.clone(),
+                headers: recorded_headers(&request.header_map),
+                body: RecordedBody::new(
+                    request
+                        .body
+                        .as_ref()
+                        .map(|body| redacted_body(body.contents(), &request.header_map)),
+                ),
+            },
+            response: RecordedResponse {
+                status_code: response.status_code,
+                headers: recorded_headers(&response.header_map),
+                body: RecordedBody::new(Some(if is_nonce_response(request, response) {
+                    REDACTED_NONCE.as_bytes().to_vec()
+                } else {
+                    redacted_body(response.body.clone(), &response.header_map)
+                })),
+            },
+        }
+    }
+
+    fn response(&self) -> WpNetworkResponse {
+        WpNetworkResponse {
+            body: self.response.body.contents().unwrap_or_default(),
+            status_code: self.response.status_code,
+            header_map: Arc::new(WpNetworkHeaderMap::new(header_map(&self.response.headers))),
+        }
+    }
+}
+
+// Wraps a `RequestExecutor` to record every request & its response, which `ReplayRequestExecutor`
+// can serve later without a network connection. Failed requests aren't recorded.
+//
+// The exchanges are kept in memory & written to the JSON fixture file by `finish`, or when the
+// executor is dropped, so requests don't wait on the file system.
+#[derive(Debug, uniffi::Object)]
+pub struct RecordingRequestExecutor {
+    inner: Arc<dyn RequestExecutor>,
+    fixture_path: String,
+    exchanges: Mutex<RecordedExchanges>,
+}
+
+#[derive(Debug, Default)]
+struct RecordedExchanges {
+    exchanges: Vec<RecordedExchange>,
+    // Whether there are exchanges that haven't been written to the fixture yet
+    has_changes: bool,
+}
+
+impl RecordedExchanges {
+    fn write(&mut self, fixture_path: &str) -> Result<(), RecordingFixtureError> {
+        let json = serde_json::to_string_pretty(&self.exchanges).map_err(|e| {
+            RecordingFixtureError::FixtureCouldntBeWritten {
+                reason: format!("{}: {}", fixture_path, e),
+            }
+        })?;
+        std::fs::write(fixture_path, json).map_err(|e| {
+            RecordingFixtureError::FixtureCouldntBeWritten {
+                reason: format!("{}: {}", fixture_path, e),
+            }
+        })?;
+        self.has_changes = false;
+        Ok(())
+    }
+}
+
+#[uniffi::export]
+impl RecordingRequestExecutor {
+    #[uniffi::constructor]
+    pub fn new(inner: Arc<dyn RequestExecutor>, fixture_path: String) -> Self {
+        Self {
+            inner,
+            fixture_path,
+            exchanges: Mutex::new(RecordedExchanges::default()),
+        }
+    }
+
+    // Writes the exchanges recorded so far to the fixture. Errors can't be reported when the
+    // executor is dropped, so this should be called once the requests are done.
+    pub fn finish(&self) -> Result<(), RecordingFixtureError> {
+        self.exchanges
+            .lock()
+            .map_err(|e| RecordingFixtureError::FixtureCouldntBeWritten {
+                reason: e.to_string(),
+            })?
+            .write(&self.fixture_path)
+    }
+}
+
+impl Drop for RecordingRequestExecutor {
+    fn drop(&mut self) {
+        if let Ok(exchanges) = self.exchanges.get_mut() {
+            if exchanges.has_changes {
+                let _ = exchanges.write(&self.fixture_path);
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for RecordingRequestExecutor {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        let response = self.inner.execute(request.clone()).await?;
+        let mut exchanges =
+            self.exchanges
+                .lock()
+                .map_err(|e| RequestExecutionError::RequestExecutionFailed {
+                    status_code: None,
+                    reason: e.to_string(),
+                })?;
+        exchanges
+            .exchanges
+            .push(RecordedExchange::new(&request, &response));
+        exchanges.has_changes = true;
+        Ok(response)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+pub enum RecordingFixtureError {
+    #[error("Fixture couldn't be written!\nReason: '{}'", reason)]
+    FixtureCouldntBeWritten { reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct ReplayMatchingRules {
+    // The method & the url without its query always have to match. The query parameters are
+    // compared regardless of their order.
+    pub match_query: bool,
+    // Query parameters that change between runs, such as a random `slug`
+    pub ignored_query_params: Vec<String>,
+    // Headers whose values have to match. Redacted headers, such as `Authorization`, only have to
+    // be present.
+    pub match_headers: Vec<String>,
+    pub match_body: bool,
+    // Each recorded response is served once, in the order they were recorded, so the same request
+    // can return different responses, for example before & after an update. Once they have all
+    // been served, the last one is served again, unless this is `false`.
+    pub reuse_responses: bool,
+}
+
+impl Default for ReplayMatchingRules {
+    fn default() -> Self {
+        Self {
+            match_query: true,
+            ignored_query_params: Vec::new(),
+            match_headers: Vec::new(),
+            match_body: false,
+            reuse_responses: true,
+        }
+    }
+}
+
+#[uniffi::export]
+fn default_replay_matching_rules() -> ReplayMatchingRules {
+    ReplayMatchingRules::default()
+}
+
+impl ReplayMatchingRules {
+    fn matches(&self, recorded_request: &RecordedRequest, request: &WpNetworkRequest) -> bool {
+        recorded_request.method == request.method
+            && self.url_matches(&recorded_request.url, &request.url.0)
+            && self.headers_match(&recorded_request.headers, &request.header_map)
+            && (!self.match_body
+                || recorded_request.body.contents()
+                    == request
+                        .body
+                        .as_ref()
+                        .map(|body| redacted_body(body.contents(), &request.header_map)))
+    }
+
+    fn url_matches(&self, recorded_url: &str, url: &str) -> bool {
+        let (Ok(recorded_url), Ok(url)) = (url::Url::parse(recorded_url), url::Url::parse(url))
+        else {
+            return recorded_url == url;
+        };
+        let without_query = |url: &url::Url| {
+            let mut url = url.clone();
+            url.set_query(None);
+            url.set_fragment(None);
+            url
+        };
+        let query_pairs = |url: &url::Url| {
+            let mut query_pairs = url
+                .query_pairs()
+                .filter(|(name, _)| !self.ignored_query_params.iter().any(|n| n == name))
+                .map(|(name, value)| (name.into_owned(), value.into_owned()))
+                .collect::<Vec<_>>();
+            query_pairs.sort();
+            query_pairs
+        };
+        without_query(&recorded_url) == without_query(&url)
+            && (!self.match_query || query_pairs(&recorded_url) == query_pairs(&url))
+    }
+
+    fn headers_match(
+        &self,
+        expected_headers: &BTreeMap<String, Vec<String>>,
+        header_map: &WpNetworkHeaderMap,
+    ) -> bool {
+        // Redacted the same way, so only their presence is compared
+        let headers = recorded_headers(header_map);
+        self.match_headers.iter().all(|name| {
+            let name = name.to_lowercase();
+            expected_headers.get(&name) == headers.get(&name)
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error, uniffi::Error)]
+pub enum ReplayFixtureError {
+    #[error("Fixture couldn't be read!\nReason: '{}'", reason)]
+    FixtureCouldntBeRead { reason: String },
+    #[error("Fixture couldn't be parsed!\nReason: '{}'", reason)]
+    FixtureCouldntBeParsed { reason: String },
+}
+
+// Serves the responses recorded by `RecordingRequestExecutor`, as described by the
+// `ReplayMatchingRules`. Requests without a matching recorded response fail.
+#[derive(Debug, uniffi::Object)]
+pub struct ReplayRequestExecutor {
+    exchanges: Vec<RecordedExchange>,
+    matching_rules: ReplayMatchingRules,
+    served_exchanges: Mutex<Vec<bool>>,
+}
+
+#[uniffi::export]
+impl ReplayRequestExecutor {
+    #[uniffi::constructor]
+    pub fn new(
+        fixture_path: String,
+        matching_rules: ReplayMatchingRules,
+    ) -> Result<Self, ReplayFixtureError> {
+        let json = std::fs::read_to_string(&fixture_path).map_err(|e| {
+            ReplayFixtureError::FixtureCouldntBeRead {
+                reason: format!("{}: {}", fixture_path, e),
+            }
+        })?;
+        Self::from_json(json, matching_rules)
+    }
+
+    // Useful when the fixture is bundled with an app instead of being a file
+    #[uniffi::constructor]
+    pub fn from_json(
+        json: String,
+        matching_rules: ReplayMatchingRules,
+    ) -> Result<Self, ReplayFixtureError> {
+        let exchanges: Vec<RecordedExchange> = serde_json::from_str(&json).map_err(|e| {
+            ReplayFixtureError::FixtureCouldntBeParsed {
+                reason: e.to_string(),
+            }
+        })?;
+        Ok(Self {
+            served_exchanges: Mutex::new(vec![false; exchanges.len()]),
+            exchanges,
+            matching_rules,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for ReplayRequestExecutor {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        let mut served_exchanges = self.served_exchanges.lock().map_err(|e| {
+            RequestExecutionError::RequestExecutionFailed {
+                status_code: None,
+                reason: e.to_string(),
+            }
+        })?;
+        let matching_indexes = self
+            .exchanges
+            .iter()
+            .enumerate()
+            .filter(|(_, exchange)| self.matching_rules.matches(&exchange.request, &request))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let index = matching_indexes
+            .iter()
+            .find(|index| !served_exchanges[**index])
+            .or(matching_indexes
+                .last()
+                .filter(|_| self.matching_rules.reuse_responses));
+        match index {
+            Some(index) => {
+                served_exchanges[*index] = true;
+                Ok(self.exchanges[*index].response())
+            }
+            None => Err(RequestExecutionError::RequestExecutionFailed {
+                status_code: None,
+                reason: format!(
+                    "No recorded response matches the request: {:?} {}",
+                    request.method, request.url.0
+                ),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::{endpoint::WpEndpointUrl, WpNetworkRequestBody};
+    use rstest::*;
+
+    // Responds with the request's url as the body & a `Set-Cookie` header
+    #[derive(Debug)]
+    struct TestRequestExecutor;
+
+    #[async_trait::async_trait]
+    impl RequestExecutor for TestRequestExecutor {
+        async fn execute(
+            &self,
+            request: Arc<WpNetworkRequest>,
+        ) -> Result<WpNetworkResponse, RequestExecutionError> {
+            let mut header_map = HeaderMap::new();
+            header_map.insert("X-WP-Total", HeaderValue::from_static("1"));
+            header_map.insert(
+                http::header::SET_COOKIE,
+                HeaderValue::from_static("wordpress_logged_in_abc=secret"),
+            );
+            Ok(WpNetworkResponse {
+                body: request.url.0.as_bytes().to_vec(),
+                status_code: 200,
+                header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
+            })
+        }
+    }
+
+    #[rstest]
+    fn record_and_replay() {
+        let fixture_path = std::env::temp_dir()
+            .join(format!("wp_api_recording_{}.json", uuid::Uuid::new_v4()))
+            .to_string_lossy()
+            .into_owned();
+        let recording_executor =
+            RecordingRequestExecutor::new(Arc::new(TestRequestExecutor), fixture_path.clone());
+        let requests = [
+            request(RequestMethod::GET, "posts?page=1&per_page=2", None),
+            request(RequestMethod::POST, "media", Some(vec![0xff, 0x00, 0xfe])),
+        ];
+        requests.iter().for_each(|request| {
+            futures::executor::block_on(recording_executor.execute(request.clone())).unwrap();
+        });
+        recording_executor.finish().unwrap();
+
+        let fixture = std::fs::read_to_string(&fixture_path).unwrap();
+        std::fs::remove_file(&fixture_path).unwrap();
+        assert!(!fixture.contains("secret"), "{}", fixture);
+        assert!(fixture.contains("\"body_base64\": \"/wD+\""), "{}", fixture);
+
+        let replay_executor =
+            ReplayRequestExecutor::from_json(fixture, Default::default()).unwrap();
+        requests.iter().for_each(|request| {
+            let response =
+                futures::executor::block_on(replay_executor.execute(request.clone())).unwrap();
+            assert_eq!(response.status_code, 200);
+            assert_eq!(response.body_as_string(), request.url.0);
+            assert_eq!(response.get_header("X-WP-Total"), Some("1"));
+            assert_eq!(response.get_header("Set-Cookie"), Some(REDACTED_VALUE));
+        });
+    }
+
+    #[rstest]
+    #[case(Default::default(), "posts?per_page=2&page=1", true)]
+    #[case(Default::default(), "posts?page=2&per_page=2", false)]
+    #[case(ReplayMatchingRules { match_query: false, ..Default::default() }, "posts", true)]
+    #[case(ReplayMatchingRules { ignored_query_params: vec!["page".to_string()], ..Default::default() }, "posts?page=2&per_page=2", true)]
+    #[case(Default::default(), "pages?page=1&per_page=2", false)]
+    fn match_requests(
+        #[case] matching_rules: ReplayMatchingRules,
+        #[case] path: &str,
+        #[case] expected_match: bool,
+    ) {
+        let recorded_request =
+            recorded_exchange(request(RequestMethod::GET, "posts?page=1&per_page=2", None)).request;
+        assert_eq!(
+            matching_rules.matches(&recorded_request, &request(RequestMethod::GET, path, None)),
+            expected_match
+        );
+        assert!(!matching_rules.matches(
+            &recorded_request,
+            &request(RequestMethod::DELETE, path, None)
+        ));
+    }
+
+    #[rstest]
+    #[case(true, vec![Some("1"), Some("2"), Some("2")])]
+    #[case(false, vec![Some("1"), Some("2"), None])]
+    fn serve_responses_in_order(
+        #[case] reuse_responses: bool,
+        #[case] expected_bodies: Vec<Option<&str>>,
+    ) {
+        let exchanges = ["1", "2"]
+            .into_iter()
+            .map(|body| {
+                let mut exchange = recorded_exchange(request(RequestMethod::GET, "settings", None));
+                exchange.response.body = RecordedBody::new(Some(body.as_bytes().to_vec()));
+                exchange
+            })
+            .collect::<Vec<_>>();
+        let replay_executor = ReplayRequestExecutor::from_json(
+            serde_json::to_string(&exchanges).unwrap(),
+            ReplayMatchingRules {
+                reuse_responses,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let bodies = expected_bodies
+            .iter()
+            .map(|_| {
+                futures::executor::block_on(replay_executor.execute(request(
+                    RequestMethod::GET,
+                    "settings",
+                    None,
+                )))
+                .ok()
+                .map(|response| response.body_as_string())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bodies,
+            expected_bodies
+                .into_iter()
+                .map(|body| body.map(|body| body.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    fn redact_passwords_in_bodies() {
+        let mut header_map = HeaderMap::new();
+        header_map.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static(CONTENT_TYPE_FORM_URLENCODED),
+        );
+        let login_request = Arc::new(WpNetworkRequest {
+            method: RequestMethod::POST,
+            url: WpEndpointUrl("https://example.com/wp-login.php".to_string()),
+            header_map: WpNetworkHeaderMap::new(header_map).into(),
+            body: Some(Arc::new(WpNetworkRequestBody::new(
+                b"log=foo&pwd=secret&rememberme=forever".to_vec(),
+            ))),
+            follow_redirects: true,
+        });
+        let application_password_response = WpNetworkResponse {
+            body:
+                br#"{"uuid":"1","name":"foo","password":"secret","items":[{"password":"secret"}]}"#
+                    .to_vec(),
+            status_code: 201,
+            header_map: Arc::new(WpNetworkHeaderMap::default()),
+        };
+        let exchange = RecordedExchange::new(&login_request, &application_password_response);
+        assert_eq!(
+            exchange.request.body.body.as_deref(),
+            Some("log=foo&pwd=%3Credacted%3E&rememberme=forever")
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(
+                exchange.response.body.body.as_deref().unwrap()
+            )
+            .unwrap(),
+            serde_json::json!({
+                "uuid": "1",
+                "name": "foo",
+                "password": REDACTED_VALUE,
+                "items": [{ "password": REDACTED_VALUE }]
+            })
+        );
+        // Requests are redacted the same way when their bodies are matched
+        assert!(ReplayMatchingRules {
+            match_body: true,
+            ..Default::default()
+        }
+        .matches(&exchange.request, &login_request));
+    }
+
+    #[rstest]
+    #[case(b"abc123".to_vec(), REDACTED_NONCE)]
+    #[case(b"0".to_vec(), "0")]
+    fn redact_nonces(#[case] body: Vec<u8>, #[case] expected_body: &str) {
+        let nonce_request = Arc::new(WpNetworkRequest {
+            method: RequestMethod::GET,
+            url: WpEndpointUrl(
+                "https://example.com/wp-admin/admin-ajax.php?action=rest-nonce".to_string(),
+            ),
+            header_map: Arc::new(WpNetworkHeaderMap::default()),
+            body: None,
+            follow_redirects: true,
+        });
+        let nonce_response = WpNetworkResponse {
+            body,
+            status_code: 200,
+            header_map: Arc::new(WpNetworkHeaderMap::default()),
+        };
+        let exchange = RecordedExchange::new(&nonce_request, &nonce_response);
+        assert_eq!(exchange.response.body.body.as_deref(), Some(expected_body));
+    }
+
+    #[rstest]
+    fn bodies_without_redacted_fields_are_recorded_as_they_are() {
+        let body = br#"{ "title": "foo" }"#.to_vec();
+        assert_eq!(
+            redacted_body(body.clone(), &WpNetworkHeaderMap::default()),
+            body
+        );
+    }
+
+    #[rstest]
+    fn record_concurrent_requests() {
+        let fixture_path = std::env::temp_dir()
+            .join(format!("wp_api_recording_{}.json", uuid::Uuid::new_v4()))
+            .to_string_lossy()
+            .into_owned();
+        let recording_executor = Arc::new(RecordingRequestExecutor::new(
+            Arc::new(TestRequestExecutor),
+            fixture_path.clone(),
+        ));
+        let threads = (0..10)
+            .map(|page| {
+                let recording_executor = recording_executor.clone();
+                std::thread::spawn(move || {
+                    futures::executor::block_on(recording_executor.execute(request(
+                        RequestMethod::GET,
+                        &format!("posts?page={}", page),
+                        None,
+                    )))
+                    .unwrap();
+                })
+            })
+            .collect::<Vec<_>>();
+        threads
+            .into_iter()
+            .for_each(|thread| thread.join().unwrap());
+        // The fixture is written when the last reference to the executor is dropped
+        drop(recording_executor);
+
+        let fixture = std::fs::read_to_string(&fixture_path).unwrap();
+        std::fs::remove_file(&fixture_path).unwrap();
+        let exchanges: Vec<RecordedExchange> = serde_json::from_str(&fixture).unwrap();
+        assert_eq!(exchanges.len(), 10);
+    }
+
+    #[rstest]
+    fn invalid_fixture() {
+        assert!(matches!(
+            ReplayRequestExecutor::from_json("{}".to_string(), Default::default()),
+            Err(ReplayFixtureError::FixtureCouldntBeParsed { .. })
+        ));
+    }
+
+    fn recorded_exchange(request: Arc<WpNetworkRequest>) -> RecordedExchange {
+        let response =
+            futures::executor::block_on(TestRequestExecutor.execute(request.clone())).unwrap();
+        RecordedExchange::new(&request, &response)
+    }
+
+    fn request(method: RequestMethod, path: &str, body: Option<Vec<u8>>) -> Arc<WpNetworkRequest> {
+        let mut header_map = HeaderMap::new();
+        header_map.insert(
+            http::header::AUTHORIZATION,
+            HeaderValue::from_static("Basic secret"),
+        );
+        Arc::new(WpNetworkRequest {
+            method,
+            url: WpEndpointUrl(format!("https://example.com/wp-json/wp/v2/{}", path)),
+            header_map: WpNetworkHeaderMap::new(header_map).into(),
+            body: body.map(|body| Arc::new(WpNetworkRequestBody::new(body))),
+            follow_redirects: true,
+        })
+    }
+}
@@ -7,6 +7,7 @@ use url::Url;
 
 use crate::{
     api_error::{ParsedRequestError, RequestExecutionError, WpError},
+    authentication::AuthenticationProvider,
     WpApiError, WpAuthentication,
 };
 
@@ -16,76 +17,247 @@ pub mod endpoint;
 
 const CONTENT_TYPE_JSON: &str = "application/json";
 const LINK_HEADER_KEY: &str = "Link";
+const NONCE_HEADER_KEY: &str = "X-WP-Nonce";
 
 #[derive(Debug)]
 pub struct InnerRequestBuilder {
-    authentication: WpAuthentication,
+    authentication_provider: Arc<dyn AuthenticationProvider>,
 }
 
 impl InnerRequestBuilder {
-    pub fn new(authentication: WpAuthentication) -> Self {
-        Self { authentication }
+    pub fn new(authentication_provider: Arc<dyn AuthenticationProvider>) -> Self {
+        Self {
+            authentication_provider,
+        }
     }
 
-    pub fn get(&self, url: ApiEndpointUrl) -> WpNetworkRequest {
-        WpNetworkRequest {
+    pub fn get(&self, url: ApiEndpointUrl) -> Result<WpNetworkRequest, WpApiError> {
+        Ok(WpNetworkRequest {
             method: RequestMethod::GET,
             url: url.into(),
-            header_map: self.header_map().into(),
+            header_map: self.header_map()?.into(),
             body: None,
-        }
+            follow_redirects: true,
+        })
     }
 
-    pub fn post<T>(&self, url: ApiEndpointUrl, json_body: &T) -> WpNetworkRequest
+    pub fn post<T>(
+        &self,
+        url: ApiEndpointUrl,
+        json_body: &T,
+    ) -> Result<WpNetworkRequest, WpApiError>
     where
         T: ?Sized + Serialize,
     {
-        WpNetworkRequest {
+        Ok(WpNetworkRequest {
             method: RequestMethod::POST,
             url: url.into(),
-            header_map: self.header_map_for_post_request().into(),
+            header_map: self.header_map_for_post_request()?.into(),
             body: serde_json::to_vec(json_body)
                 .ok()
                 .map(|b| Arc::new(WpNetworkRequestBody::new(b))),
-        }
+            follow_redirects: true,
+        })
     }
 
-    pub fn delete(&self, url: ApiEndpointUrl) -> WpNetworkRequest {
-        WpNetworkRequest {
+    // Unlike `post`, the body is sent as is, so the `content_type` has to describe it.
+    pub fn post_with_body(
+        &self,
+        url: ApiEndpointUrl,
+        content_type: HeaderValue,
+        body: Vec<u8>,
+    ) -> Result<WpNetworkRequest, WpApiError> {
+        let mut header_map = self.header_map()?;
+        header_map
+            .inner
+            .insert(http::header::CONTENT_TYPE, content_type);
+        Ok(WpNetworkRequest {
+            method: RequestMethod::POST,
+            url: url.into(),
+            header_map: header_map.into(),
+            body: Some(Arc::new(WpNetworkRequestBody::new(body))),
+            follow_redirects: true,
+        })
+    }
+
+    pub fn post_multipart(
+        &self,
+        url: ApiEndpointUrl,
+        form: MultipartForm,
+    ) -> Result<WpNetworkRequest, WpApiError> {
+        let content_type = form.content_type();
+        self.post_with_body(url, content_type, form.into_body())
+    }
+
+    pub fn delete(&self, url: ApiEndpointUrl) -> Result<WpNetworkRequest, WpApiError> {
+        Ok(WpNetworkRequest {
             method: RequestMethod::DELETE,
             url: url.into(),
-            header_map: self.header_map().into(),
+            header_map: self.header_map()?.into(),
             body: None,
-        }
+            follow_redirects: true,
+        })
+    }
+
+    pub fn options(&self, url: ApiEndpointUrl) -> Result<WpNetworkRequest, WpApiError> {
+        Ok(WpNetworkRequest {
+            method: RequestMethod::OPTIONS,
+            url: url.into(),
+            header_map: self.header_map()?.into(),
+            body: None,
+            follow_redirects: true,
+        })
     }
 
-    fn header_map(&self) -> WpNetworkHeaderMap {
+    fn header_map(&self) -> Result<WpNetworkHeaderMap, WpApiError> {
         let mut header_map = HeaderMap::new();
         header_map.insert(
             http::header::ACCEPT,
             HeaderValue::from_static(CONTENT_TYPE_JSON),
         );
-        match self.authentication {
-            WpAuthentication::None => (),
-            WpAuthentication::AuthorizationHeader { ref token } => {
-                let hv = HeaderValue::from_str(&format!("Basic {}", token));
-                let hv = hv.expect("It shouldn't be possible to build WpAuthentication::AuthorizationHeader with an invalid token");
-                header_map.insert(http::header::AUTHORIZATION, hv);
-            }
-        };
-        header_map.into()
+        insert_authentication_headers(
+            &mut header_map,
+            &self.authentication_provider.authentication(),
+        )?;
+        Ok(header_map.into())
     }
 
-    fn header_map_for_post_request(&self) -> WpNetworkHeaderMap {
-        let mut header_map = self.header_map();
+    fn header_map_for_post_request(&self) -> Result<WpNetworkHeaderMap, WpApiError> {
+        let mut header_map = self.header_map()?;
         header_map.inner.insert(
             http::header::CONTENT_TYPE,
             HeaderValue::from_static(CONTENT_TYPE_JSON),
         );
-        header_map
+        Ok(header_map)
     }
 }
 
+// Replaces any authentication headers in the `header_map` with the ones for `authentication`.
+pub(crate) fn insert_authentication_headers(
+    header_map: &mut HeaderMap,
+    authentication: &WpAuthentication,
+) -> Result<(), RequestExecutionError> {
+    header_map.remove(http::header::AUTHORIZATION);
+    header_map.remove(http::header::COOKIE);
+    header_map.remove(NONCE_HEADER_KEY);
+    match authentication {
+        WpAuthentication::None => (),
+        WpAuthentication::AuthorizationHeader { token } => {
+            header_map.insert(
+                http::header::AUTHORIZATION,
+                authentication_header_value(&format!("Basic {}", token))?,
+            );
+        }
+        WpAuthentication::BearerToken { token } => {
+            header_map.insert(
+                http::header::AUTHORIZATION,
+                authentication_header_value(&format!("Bearer {}", token))?,
+            );
+        }
+        WpAuthentication::Cookie { cookie, nonce } => {
+            header_map.insert(http::header::COOKIE, authentication_header_value(cookie)?);
+            header_map.insert(NONCE_HEADER_KEY, authentication_header_value(nonce)?);
+        }
+    };
+    Ok(())
+}
+
+pub(crate) fn authentication_header_value(
+    value: &str,
+) -> Result<HeaderValue, RequestExecutionError> {
+    HeaderValue::from_str(value).map_err(|e| RequestExecutionError::InvalidAuthentication {
+        reason: e.to_string(),
+    })
+}
+
+// Builds a `multipart/form-data` body as described in RFC 7578.
+#[derive(Debug)]
+pub struct MultipartForm {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl Default for MultipartForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultipartForm {
+    pub fn new() -> Self {
+        Self::with_boundary(format!("wp-api-boundary-{}", uuid::Uuid::new_v4().simple()))
+    }
+
+    fn with_boundary(boundary: String) -> Self {
+        Self {
+            boundary,
+            body: Vec::new(),
+        }
+    }
+
+    pub fn text(mut self, name: &str, value: &str) -> Self {
+        self.append_part_headers(name, None, None);
+        self.body.extend_from_slice(value.as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    pub fn file(mut self, name: &str, file_name: &str, mime_type: &str, contents: &[u8]) -> Self {
+        self.append_part_headers(name, Some(file_name), Some(mime_type));
+        self.body.extend_from_slice(contents);
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    fn append_part_headers(
+        &mut self,
+        name: &str,
+        file_name: Option<&str>,
+        mime_type: Option<&str>,
+    ) {
+        let mut headers = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            self.boundary,
+            escape_quoted_string(name)
+        );
+        if let Some(file_name) = file_name {
+            headers.push_str(&format!(
+                "; filename=\"{}\"",
+                escape_quoted_string(file_name)
+            ));
+        }
+        headers.push_str("\r\n");
+        if let Some(mime_type) = mime_type {
+            headers.push_str(&format!(
+                "Content-Type: {}\r\n",
+                mime_type.replace(['\r', '\n'], "")
+            ));
+        }
+        headers.push_str("\r\n");
+        self.body.extend_from_slice(headers.as_bytes());
+    }
+
+    fn content_type(&self) -> HeaderValue {
+        HeaderValue::from_str(&format!("multipart/form-data; boundary={}", self.boundary))
+            .expect("The boundary only contains valid header characters")
+    }
+
+    fn into_body(mut self) -> Vec<u8> {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body
+    }
+}
+
+// Percent encodes the characters that would otherwise terminate a quoted parameter value, the
+// same way browsers do for `multipart/form-data` submissions.
+fn escape_quoted_string(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
 #[uniffi::export(with_foreign)]
 #[async_trait::async_trait]
 pub trait RequestExecutor: Send + Sync + Debug {
@@ -101,7 +273,7 @@ pub struct WpNetworkRequestBody {
 }
 
 impl WpNetworkRequestBody {
-    fn new(body: Vec<u8>) -> Self {
+    pub(crate) fn new(body: Vec<u8>) -> Self {
         Self { inner: body }
     }
 }
@@ -120,6 +292,10 @@ pub struct WpNetworkRequest {
     pub(crate) url: WpEndpointUrl,
     pub(crate) header_map: Arc<WpNetworkHeaderMap>,
     pub(crate) body: Option<Arc<WpNetworkRequestBody>>,
+    // Request executors should return redirect responses as they are, instead of following them,
+    // when this is `false`. For example, cookie authentication reads the `Set-Cookie` headers of
+    // the redirect response of `wp-login.php`.
+    pub(crate) follow_redirects: bool,
 }
 
 #[uniffi::export]
@@ -140,11 +316,41 @@ impl WpNetworkRequest {
         self.body.clone()
     }
 
+    pub fn follow_redirects(&self) -> bool {
+        self.follow_redirects
+    }
+
     pub fn body_as_string(&self) -> Option<String> {
         self.body
             .as_ref()
             .map(|b| request_or_response_body_as_string(&b.inner))
     }
+
+    // Returns a copy of the request with the given headers instead of its own.
+    pub fn with_header_map(&self, header_map: Arc<WpNetworkHeaderMap>) -> WpNetworkRequest {
+        WpNetworkRequest {
+            method: self.method.clone(),
+            url: self.url.clone(),
+            header_map,
+            body: self.body.clone(),
+            follow_redirects: self.follow_redirects,
+        }
+    }
+
+    // Returns a copy of the request with the given header, replacing any existing values.
+    pub fn with_header(
+        &self,
+        header_name: String,
+        header_value: String,
+    ) -> Result<WpNetworkRequest, WpNetworkHeaderMapError> {
+        let name = HeaderName::from_bytes(header_name.as_bytes())
+            .map_err(|_| WpNetworkHeaderMapError::InvalidHeaderName { header_name })?;
+        let value = HeaderValue::from_str(&header_value)
+            .map_err(|_| WpNetworkHeaderMapError::InvalidHeaderValue { header_value })?;
+        let mut header_map = self.header_map.as_header_map();
+        header_map.insert(name, value);
+        Ok(self.with_header_map(WpNetworkHeaderMap::new(header_map).into()))
+    }
 }
 
 impl Debug for WpNetworkRequest {
@@ -169,13 +375,38 @@ impl Debug for WpNetworkRequest {
 }
 
 // Has custom `Debug` trait implementation
-#[derive(uniffi::Record)]
+#[derive(Clone, uniffi::Record)]
 pub struct WpNetworkResponse {
     pub body: Vec<u8>,
     pub status_code: u16,
     pub header_map: Arc<WpNetworkHeaderMap>,
 }
 
+// A parsed response along with its status code & headers, such as `X-WP-Total` or `ETag`.
+#[derive(Debug)]
+pub struct WpResponse<T> {
+    pub data: T,
+    pub status_code: u16,
+    pub header_map: Arc<WpNetworkHeaderMap>,
+}
+
+impl<T> WpResponse<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    pub fn from_response<E>(response: WpNetworkResponse) -> Result<Self, E>
+    where
+        E: ParsedRequestError,
+    {
+        let data = response.parse()?;
+        Ok(Self {
+            data,
+            status_code: response.status_code,
+            header_map: response.header_map,
+        })
+    }
+}
+
 #[derive(Debug, Default, Clone, uniffi::Object)]
 pub struct WpNetworkHeaderMap {
     inner: HeaderMap,
@@ -297,6 +528,13 @@ impl WpNetworkResponse {
         .collect()
     }
 
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        self.header_map
+            .inner
+            .get(name)
+            .and_then(|header_value| header_value.to_str().ok())
+    }
+
     pub fn body_as_string(&self) -> String {
         request_or_response_body_as_string(&self.body)
     }
@@ -365,13 +603,27 @@ impl Debug for WpNetworkResponse {
     }
 }
 
-#[derive(Debug, Clone, uniffi::Enum)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
 pub enum RequestMethod {
     GET,
     POST,
     PUT,
     DELETE,
     HEAD,
+    OPTIONS,
+}
+
+impl RequestMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::GET => "GET",
+            Self::POST => "POST",
+            Self::PUT => "PUT",
+            Self::DELETE => "DELETE",
+            Self::HEAD => "HEAD",
+            Self::OPTIONS => "OPTIONS",
+        }
+    }
 }
 
 pub fn request_or_response_body_as_string(body: &[u8]) -> String {
@@ -483,6 +735,117 @@ mod tests {
         assert_header_map_values(&header_map, "User-Agent", vec![]);
     }
 
+    #[test]
+    fn test_wp_response_from_response() {
+        let response = WpNetworkResponse {
+            body: b"[1,2]".to_vec(),
+            status_code: 200,
+            header_map: Arc::new(
+                WpNetworkHeaderMap::from_map([("X-WP-Total".to_string(), "2".to_string())].into())
+                    .unwrap(),
+            ),
+        };
+        let wp_response = WpResponse::<Vec<u32>>::from_response::<WpApiError>(response).unwrap();
+        assert_eq!(wp_response.data, vec![1, 2]);
+        assert_eq!(wp_response.status_code, 200);
+        assert_header_map_values(&wp_response.header_map, "X-WP-Total", vec!["2"]);
+    }
+
+    #[test]
+    fn test_wp_response_from_error_response() {
+        let response = WpNetworkResponse {
+            body: br#"{"code":"rest_post_invalid_id","message":"Invalid post ID.","data":{"status":404}}"#.to_vec(),
+            status_code: 404,
+            header_map: Arc::new(WpNetworkHeaderMap::default()),
+        };
+        assert!(matches!(
+            WpResponse::<Vec<u32>>::from_response::<WpApiError>(response),
+            Err(WpApiError::WpError {
+                status_code: 404,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_bearer_token_authentication_header() {
+        let header_map = InnerRequestBuilder::new(
+            WpAuthentication::BearerToken {
+                token: "foo.bar.baz".to_string(),
+            }
+            .into(),
+        )
+        .header_map()
+        .unwrap();
+        assert_header_map_values(&header_map, "Authorization", vec!["Bearer foo.bar.baz"]);
+    }
+
+    #[test]
+    fn test_cookie_authentication_headers() {
+        let header_map = InnerRequestBuilder::new(
+            WpAuthentication::Cookie {
+                cookie: "wordpress_logged_in_abc=foo; wordpress_sec_abc=bar".to_string(),
+                nonce: "1a2b3c4d5e".to_string(),
+            }
+            .into(),
+        )
+        .header_map()
+        .unwrap();
+        assert_header_map_values(
+            &header_map,
+            "Cookie",
+            vec!["wordpress_logged_in_abc=foo; wordpress_sec_abc=bar"],
+        );
+        assert_header_map_values(&header_map, NONCE_HEADER_KEY, vec!["1a2b3c4d5e"]);
+        assert_header_map_values(&header_map, "Authorization", vec![]);
+    }
+
+    #[test]
+    fn test_multipart_form_body() {
+        let form = MultipartForm::with_boundary("foo".to_string())
+            .text("title", "Hello")
+            .file("file", "a\"b.txt", "text/plain", b"contents");
+        assert_eq!(
+            form.content_type(),
+            HeaderValue::from_static("multipart/form-data; boundary=foo")
+        );
+        assert_eq!(
+            request_or_response_body_as_string(&form.into_body()),
+            [
+                "--foo",
+                "Content-Disposition: form-data; name=\"title\"",
+                "",
+                "Hello",
+                "--foo",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"a%22b.txt\"",
+                "Content-Type: text/plain",
+                "",
+                "contents",
+                "--foo--",
+                ""
+            ]
+            .join("\r\n")
+        );
+    }
+
+    #[rstest]
+    #[case(WpAuthentication::AuthorizationHeader { token: "foo\nbar".to_string() })]
+    #[case(WpAuthentication::BearerToken { token: "foo\nbar".to_string() })]
+    #[case(WpAuthentication::Cookie { cookie: "foo\nbar".to_string(), nonce: "1a2b3c4d5e".to_string() })]
+    #[case(WpAuthentication::Cookie { cookie: "foo=bar".to_string(), nonce: "foo\nbar".to_string() })]
+    fn invalid_authentication_headers_are_an_error(#[case] authentication: WpAuthentication) {
+        assert!(matches!(
+            InnerRequestBuilder::new(authentication.into()).get(
+                Url::parse("https://example.com/wp-json/wp/v2/posts")
+                    .unwrap()
+                    .into()
+            ),
+            Err(WpApiError::RequestExecutionFailed {
+                error: RequestExecutionError::InvalidAuthentication { .. }
+            })
+        ));
+    }
+
     fn assert_header_map_values(header_map: &WpNetworkHeaderMap, key: &str, values: Vec<&str>) {
         assert_eq!(
             header_map
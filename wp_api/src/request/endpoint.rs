@@ -3,10 +3,21 @@ use url::Url;
 use crate::SparseField;
 
 pub(crate) mod application_passwords_endpoint;
+pub(crate) mod batch_endpoint;
+pub(crate) mod categories_endpoint;
+pub(crate) mod comments_endpoint;
+pub(crate) mod custom_posts_endpoint;
+pub(crate) mod links_endpoint;
+pub(crate) mod media_endpoint;
+pub(crate) mod pages_endpoint;
 pub(crate) mod plugins_endpoint;
+pub(crate) mod post_revisions_endpoint;
 pub(crate) mod post_types_endpoint;
 pub(crate) mod posts_endpoint;
+pub(crate) mod schema_endpoint;
 pub(crate) mod site_settings_endpoint;
+pub(crate) mod tags_endpoint;
+pub(crate) mod taxonomies_endpoint;
 pub(crate) mod users_endpoint;
 pub(crate) mod wp_site_health_tests_endpoint;
 
@@ -177,6 +188,7 @@ pub trait AsNamespace {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum WpNamespace {
+    BatchV1,
     WpSiteHealthV1,
     WpV2,
 }
@@ -184,6 +196,7 @@ enum WpNamespace {
 impl AsNamespace for WpNamespace {
     fn as_str(&self) -> &str {
         match self {
+            Self::BatchV1 => "/batch/v1",
             Self::WpSiteHealthV1 => "/wp-site-health/v1",
             Self::WpV2 => "/wp/v2",
         }
@@ -0,0 +1,268 @@
+use std::sync::Arc;
+
+use crate::{
+    authentication::AuthenticationProvider,
+    batch::{BatchRequestBody, BatchResponse, BatchValidation, MAX_BATCH_REQUESTS},
+    request::{InnerRequestBuilder, RequestExecutor, WpNetworkRequest},
+    WpApiError,
+};
+
+use super::{ApiBaseUrl, ApiEndpointUrl, AsNamespace, WpNamespace};
+
+// The batch request body is built from other requests, so unlike most endpoints, it can't be
+// derived with `WpDerivedRequest`.
+#[derive(Debug)]
+pub struct BatchRequestEndpoint {
+    api_base_url: Arc<ApiBaseUrl>,
+}
+
+impl BatchRequestEndpoint {
+    pub fn new(api_base_url: Arc<ApiBaseUrl>) -> Self {
+        Self { api_base_url }
+    }
+
+    pub fn batch(&self) -> ApiEndpointUrl {
+        self.api_base_url
+            .by_extending_and_splitting_by_forward_slash([WpNamespace::BatchV1.as_str()])
+            .into()
+    }
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct BatchRequestBuilder {
+    endpoint: BatchRequestEndpoint,
+    inner: InnerRequestBuilder,
+}
+
+impl BatchRequestBuilder {
+    pub fn new(
+        api_base_url: Arc<ApiBaseUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+    ) -> Self {
+        Self {
+            endpoint: BatchRequestEndpoint::new(api_base_url),
+            inner: InnerRequestBuilder::new(authentication_provider),
+        }
+    }
+}
+
+#[uniffi::export]
+impl BatchRequestBuilder {
+    // Bundles the given requests, which are typically created by the other request builders, into
+    // a single request. WordPress only supports batching `POST`, `PUT`, `PATCH` & `DELETE` requests
+    // with JSON bodies, and up to `MAX_BATCH_REQUESTS` of them. Any other request is rejected with
+    // `WpApiError::InvalidBatchRequest`, before anything is sent.
+    //
+    // Larger batches are rejected, instead of being split, because the requests of a
+    // `RequireAllValidate` batch must be validated together.
+    pub fn batch(
+        &self,
+        requests: Vec<Arc<WpNetworkRequest>>,
+        validation: BatchValidation,
+    ) -> Result<WpNetworkRequest, WpApiError> {
+        if requests.len() > MAX_BATCH_REQUESTS {
+            return Err(WpApiError::TooManyBatchRequests {
+                request_count: requests.len() as u64,
+                max_requests: MAX_BATCH_REQUESTS as u64,
+            });
+        }
+        let body =
+            BatchRequestBody::new(self.endpoint.api_base_url.as_str(), &requests, validation)?;
+        self.inner.post(self.endpoint.batch(), &body)
+    }
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct BatchRequestExecutor {
+    request_builder: BatchRequestBuilder,
+    request_executor: Arc<dyn RequestExecutor>,
+}
+
+impl BatchRequestExecutor {
+    pub fn new(
+        api_base_url: Arc<ApiBaseUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+        request_executor: Arc<dyn RequestExecutor>,
+    ) -> Self {
+        Self {
+            request_builder: BatchRequestBuilder::new(api_base_url, authentication_provider),
+            request_executor,
+        }
+    }
+}
+
+#[uniffi::export]
+impl BatchRequestExecutor {
+    pub async fn batch(
+        &self,
+        requests: Vec<Arc<WpNetworkRequest>>,
+        validation: BatchValidation,
+    ) -> Result<BatchResponse, WpApiError> {
+        let request = self.request_builder.batch(requests, validation)?;
+        self.request_executor
+            .execute(Arc::new(request))
+            .await?
+            .parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api_error::InvalidBatchRequestReason,
+        posts::{PostCreateParams, PostId},
+        request::{
+            endpoint::{
+                posts_endpoint::PostsRequestBuilder, tests::fixture_api_base_url, WpEndpointUrl,
+            },
+            RequestMethod, WpNetworkHeaderMap, WpNetworkRequestBody,
+        },
+        WpAuthentication,
+    };
+    use rstest::*;
+
+    #[rstest]
+    fn batch_endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) {
+        assert_eq!(
+            BatchRequestEndpoint::new(fixture_api_base_url)
+                .batch()
+                .as_str(),
+            "https://example.com/wp-json/batch/v1"
+        );
+    }
+
+    #[rstest]
+    #[case(BatchValidation::Normal, "normal")]
+    #[case(BatchValidation::RequireAllValidate, "require-all-validate")]
+    fn batch_request(
+        fixture_api_base_url: Arc<ApiBaseUrl>,
+        #[case] validation: BatchValidation,
+        #[case] expected_validation: &str,
+    ) {
+        let posts_request_builder =
+            PostsRequestBuilder::new(fixture_api_base_url.clone(), WpAuthentication::None.into());
+        let requests = vec![
+            Arc::new(
+                posts_request_builder
+                    .create(&PostCreateParams {
+                        title: Some("foo".to_string()),
+                        ..Default::default()
+                    })
+                    .unwrap(),
+            ),
+            Arc::new(posts_request_builder.delete(&PostId(3)).unwrap()),
+        ];
+        let request = BatchRequestBuilder::new(fixture_api_base_url, WpAuthentication::None.into())
+            .batch(requests, validation)
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&request.body_as_string().unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "validation": expected_validation,
+                "requests": [
+                    {
+                        "method": "POST",
+                        "path": "/wp/v2/posts",
+                        "body": { "title": "foo", "meta": null }
+                    },
+                    {
+                        "method": "DELETE",
+                        "path": "/wp/v2/posts/3?force=true"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[rstest]
+    #[case(MAX_BATCH_REQUESTS, true)]
+    #[case(MAX_BATCH_REQUESTS + 1, false)]
+    fn batch_request_size_limit(
+        fixture_api_base_url: Arc<ApiBaseUrl>,
+        #[case] request_count: usize,
+        #[case] is_valid: bool,
+    ) {
+        let posts_request_builder =
+            PostsRequestBuilder::new(fixture_api_base_url.clone(), WpAuthentication::None.into());
+        let requests = (1..=request_count)
+            .map(|id| Arc::new(posts_request_builder.delete(&PostId(id as i32)).unwrap()))
+            .collect();
+        let result = BatchRequestBuilder::new(fixture_api_base_url, WpAuthentication::None.into())
+            .batch(requests, BatchValidation::Normal);
+        if is_valid {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(
+                result,
+                Err(WpApiError::TooManyBatchRequests {
+                    request_count: 26,
+                    max_requests: 25
+                })
+            ));
+        }
+    }
+
+    #[rstest]
+    #[case(
+        RequestMethod::GET,
+        "https://example.com/wp-json/wp/v2/posts",
+        None,
+        InvalidBatchRequestReason::UnsupportedMethod
+    )]
+    #[case(
+        RequestMethod::POST,
+        "https://example.com/wp-json/wp/v2/media",
+        Some("--boundary\r\n"),
+        InvalidBatchRequestReason::NonJsonBody
+    )]
+    #[case(
+        RequestMethod::DELETE,
+        "https://example.org/wp-json/wp/v2/posts/1",
+        None,
+        InvalidBatchRequestReason::NotUnderApiRoot
+    )]
+    #[case(
+        RequestMethod::DELETE,
+        "https://example.com/wp-json-other/wp/v2/posts/1",
+        None,
+        InvalidBatchRequestReason::NotUnderApiRoot
+    )]
+    #[case(
+        RequestMethod::DELETE,
+        "https://example.com/?rest_route=/wp/v2/posts/1",
+        None,
+        InvalidBatchRequestReason::RestRouteUrl
+    )]
+    fn invalid_batch_request(
+        fixture_api_base_url: Arc<ApiBaseUrl>,
+        #[case] method: RequestMethod,
+        #[case] url: &str,
+        #[case] body: Option<&str>,
+        #[case] expected_reason: InvalidBatchRequestReason,
+    ) {
+        let posts_request_builder =
+            PostsRequestBuilder::new(fixture_api_base_url.clone(), WpAuthentication::None.into());
+        let requests = vec![
+            Arc::new(posts_request_builder.delete(&PostId(3)).unwrap()),
+            Arc::new(WpNetworkRequest {
+                method,
+                url: WpEndpointUrl(url.to_string()),
+                header_map: WpNetworkHeaderMap::default().into(),
+                body: body.map(|body| Arc::new(WpNetworkRequestBody::new(body.into()))),
+                follow_redirects: true,
+            }),
+        ];
+        assert_eq!(
+            BatchRequestBuilder::new(fixture_api_base_url, WpAuthentication::None.into())
+                .batch(requests, BatchValidation::Normal)
+                .map(|_| ()),
+            Err(WpApiError::InvalidBatchRequest {
+                index: 1,
+                reason: expected_reason
+            })
+        );
+    }
+}
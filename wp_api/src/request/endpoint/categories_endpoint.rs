@@ -0,0 +1,160 @@
+use crate::{
+    categories::{
+        CategoryListParams, CategoryUpdateParams, CategoryWithEditContext,
+        SparseCategoryFieldWithEditContext, SparseCategoryFieldWithEmbedContext,
+        SparseCategoryFieldWithViewContext,
+    },
+    posts::CategoryId,
+    SparseField,
+};
+use wp_derive_request_builder::WpDerivedRequest;
+
+use super::{AsNamespace, DerivedRequest, WpNamespace};
+
+#[derive(WpDerivedRequest)]
+enum CategoriesRequest {
+    #[contextual_get(url = "/categories", params = &CategoryListParams, output = Vec<crate::categories::SparseCategory>, filter_by = crate::categories::SparseCategoryField)]
+    List,
+    #[contextual_get(url = "/categories/<category_id>", output = crate::categories::SparseCategory, filter_by = crate::categories::SparseCategoryField)]
+    Retrieve,
+    #[post(url = "/categories", params = &crate::categories::CategoryCreateParams, output = CategoryWithEditContext)]
+    Create,
+    #[delete(url = "/categories/<category_id>", output = crate::categories::CategoryDeleteResponse)]
+    Delete,
+    #[post(url = "/categories/<category_id>", params = &CategoryUpdateParams, output = CategoryWithEditContext)]
+    Update,
+}
+
+impl DerivedRequest for CategoriesRequest {
+    fn additional_query_pairs(&self) -> Vec<(&str, String)> {
+        match self {
+            // Terms don't support trashing, so `force=true` is required to delete them
+            CategoriesRequest::Delete => vec![("force", true.to_string())],
+            _ => vec![],
+        }
+    }
+
+    fn namespace() -> impl AsNamespace {
+        WpNamespace::WpV2
+    }
+}
+
+crate::generate_paginated_list_impl!(
+    Categories,
+    CategoryListParams;
+    edit => crate::categories::CategoryWithEditContext,
+    embed => crate::categories::CategoryWithEmbedContext,
+    view => crate::categories::CategoryWithViewContext
+);
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseCategoryFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseCategoryFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseCategoryFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::WpApiParamCategoriesOrderBy,
+        generate,
+        posts::PostId,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        WpApiParamOrder,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn create_category(endpoint: CategoriesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/categories");
+    }
+
+    #[rstest]
+    fn delete_category(endpoint: CategoriesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(&CategoryId(54)),
+            "/categories/54?force=true",
+        );
+    }
+
+    #[rstest]
+    #[case(CategoryListParams::default(), "")]
+    #[case(generate!(CategoryListParams, (page, Some(2))), "page=2")]
+    #[case(generate!(CategoryListParams, (search, Some("foo".to_string()))), "search=foo")]
+    #[case(generate!(CategoryListParams, (exclude, vec![CategoryId(1), CategoryId(2)])), "exclude=1%2C2")]
+    #[case(generate!(CategoryListParams, (order, Some(WpApiParamOrder::Desc))), "order=desc")]
+    #[case(generate!(CategoryListParams, (orderby, Some(WpApiParamCategoriesOrderBy::Count))), "orderby=count")]
+    #[case(generate!(CategoryListParams, (hide_empty, Some(false))), "hide_empty=false")]
+    #[case(generate!(CategoryListParams, (parent, Some(CategoryId(1)))), "parent=1")]
+    #[case(generate!(CategoryListParams, (post, Some(PostId(1)))), "post=1")]
+    #[case(generate!(CategoryListParams, (slug, vec!["foo".to_string()])), "slug=foo")]
+    fn list_categories(
+        endpoint: CategoriesRequestEndpoint,
+        #[case] params: CategoryListParams,
+        #[case] expected_additional_params: &str,
+    ) {
+        let expected_path = |context: &str| {
+            if expected_additional_params.is_empty() {
+                format!("/categories?context={}", context)
+            } else {
+                format!(
+                    "/categories?context={}&{}",
+                    context, expected_additional_params
+                )
+            }
+        };
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&params),
+            &expected_path("edit"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_embed_context(&params),
+            &expected_path("embed"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_view_context(&params),
+            &expected_path("view"),
+        );
+    }
+
+    #[rstest]
+    #[case(&[], "/categories?context=edit&_fields=")]
+    #[case(&[SparseCategoryFieldWithEditContext::Name, SparseCategoryFieldWithEditContext::Parent], "/categories?context=edit&_fields=name%2Cparent")]
+    fn filter_list_categories_with_edit_context(
+        endpoint: CategoriesRequestEndpoint,
+        #[case] fields: &[SparseCategoryFieldWithEditContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_edit_context(&CategoryListParams::default(), fields),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn retrieve_category(endpoint: CategoriesRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_embed_context(&CategoryId(54)),
+            "/categories/54?context=embed",
+        );
+    }
+
+    #[rstest]
+    fn update_category(endpoint: CategoriesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&CategoryId(54)), "/categories/54");
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> CategoriesRequestEndpoint {
+        CategoriesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
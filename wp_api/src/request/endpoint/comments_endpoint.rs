@@ -0,0 +1,253 @@
+use crate::{
+    comments::{
+        CommentId, CommentListParams, CommentStatus, CommentUpdateParams, CommentWithEditContext,
+        SparseCommentFieldWithEditContext, SparseCommentFieldWithEmbedContext,
+        SparseCommentFieldWithViewContext,
+    },
+    request::WpNetworkRequest,
+    SparseField, WpApiError,
+};
+use wp_derive_request_builder::WpDerivedRequest;
+
+use super::{AsNamespace, DerivedRequest, WpNamespace};
+
+#[derive(WpDerivedRequest)]
+enum CommentsRequest {
+    #[contextual_get(url = "/comments", params = &CommentListParams, output = Vec<crate::comments::SparseComment>, filter_by = crate::comments::SparseCommentField)]
+    List,
+    #[contextual_get(url = "/comments/<comment_id>", params = &crate::comments::CommentRetrieveParams, output = crate::comments::SparseComment, filter_by = crate::comments::SparseCommentField)]
+    Retrieve,
+    #[post(url = "/comments", params = &crate::comments::CommentCreateParams, output = CommentWithEditContext)]
+    Create,
+    #[delete(url = "/comments/<comment_id>", output = crate::comments::CommentDeleteResponse)]
+    Delete,
+    #[delete(url = "/comments/<comment_id>", output = CommentWithEditContext)]
+    Trash,
+    #[post(url = "/comments/<comment_id>", params = &CommentUpdateParams, output = CommentWithEditContext)]
+    Update,
+}
+
+impl DerivedRequest for CommentsRequest {
+    fn additional_query_pairs(&self) -> Vec<(&str, String)> {
+        match self {
+            CommentsRequest::Delete => vec![("force", true.to_string())],
+            CommentsRequest::Trash => vec![("force", false.to_string())],
+            _ => vec![],
+        }
+    }
+
+    fn namespace() -> impl AsNamespace {
+        WpNamespace::WpV2
+    }
+}
+
+crate::generate_paginated_list_impl!(
+    Comments,
+    CommentListParams;
+    edit => crate::comments::CommentWithEditContext,
+    embed => crate::comments::CommentWithEmbedContext,
+    view => crate::comments::CommentWithViewContext
+);
+
+// Moderation helpers which are shorthands for updating only the status of a comment.
+#[uniffi::export]
+impl CommentsRequestBuilder {
+    pub fn approve(&self, comment_id: &CommentId) -> Result<WpNetworkRequest, WpApiError> {
+        self.update(
+            comment_id,
+            &CommentUpdateParams::status(CommentStatus::Approved),
+        )
+    }
+
+    pub fn hold(&self, comment_id: &CommentId) -> Result<WpNetworkRequest, WpApiError> {
+        self.update(
+            comment_id,
+            &CommentUpdateParams::status(CommentStatus::Hold),
+        )
+    }
+
+    pub fn spam(&self, comment_id: &CommentId) -> Result<WpNetworkRequest, WpApiError> {
+        self.update(
+            comment_id,
+            &CommentUpdateParams::status(CommentStatus::Spam),
+        )
+    }
+}
+
+#[uniffi::export]
+impl CommentsRequestExecutor {
+    pub async fn approve(
+        &self,
+        comment_id: &CommentId,
+    ) -> Result<CommentWithEditContext, WpApiError> {
+        self.update(
+            comment_id,
+            &CommentUpdateParams::status(CommentStatus::Approved),
+        )
+        .await
+    }
+
+    pub async fn hold(&self, comment_id: &CommentId) -> Result<CommentWithEditContext, WpApiError> {
+        self.update(
+            comment_id,
+            &CommentUpdateParams::status(CommentStatus::Hold),
+        )
+        .await
+    }
+
+    pub async fn spam(&self, comment_id: &CommentId) -> Result<CommentWithEditContext, WpApiError> {
+        self.update(
+            comment_id,
+            &CommentUpdateParams::status(CommentStatus::Spam),
+        )
+        .await
+    }
+}
+
+impl CommentUpdateParams {
+    fn status(status: CommentStatus) -> Self {
+        Self {
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseCommentFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseCommentFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseCommentFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        comments::{CommentRetrieveParams, CommentType},
+        generate,
+        posts::PostId,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        WpAuthentication,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn create_comment(endpoint: CommentsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/comments");
+    }
+
+    #[rstest]
+    fn delete_comment(endpoint: CommentsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.delete(&CommentId(54)), "/comments/54?force=true");
+    }
+
+    #[rstest]
+    #[case(CommentListParams::default(), "")]
+    #[case(generate!(CommentListParams, (page, Some(2))), "page=2")]
+    #[case(generate!(CommentListParams, (post, vec![PostId(1)])), "post=1")]
+    #[case(generate!(CommentListParams, (parent, vec![CommentId(1), CommentId(2)])), "parent=1%2C2")]
+    #[case(generate!(CommentListParams, (status, Some(CommentStatus::Spam))), "status=spam")]
+    #[case(generate!(CommentListParams, (author_email, Some("a@b.c".to_string()))), "author_email=a%40b.c")]
+    #[case(generate!(CommentListParams, (comment_type, Some(CommentType::Comment))), "type=comment")]
+    fn list_comments(
+        endpoint: CommentsRequestEndpoint,
+        #[case] params: CommentListParams,
+        #[case] expected_additional_params: &str,
+    ) {
+        let expected_path = |context: &str| {
+            if expected_additional_params.is_empty() {
+                format!("/comments?context={}", context)
+            } else {
+                format!(
+                    "/comments?context={}&{}",
+                    context, expected_additional_params
+                )
+            }
+        };
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&params),
+            &expected_path("edit"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_embed_context(&params),
+            &expected_path("embed"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_view_context(&params),
+            &expected_path("view"),
+        );
+    }
+
+    #[rstest]
+    #[case(&[], "/comments?context=edit&_fields=")]
+    #[case(&[SparseCommentFieldWithEditContext::AuthorEmail, SparseCommentFieldWithEditContext::CommentType], "/comments?context=edit&_fields=author_email%2Ctype")]
+    fn filter_list_comments_with_edit_context(
+        endpoint: CommentsRequestEndpoint,
+        #[case] fields: &[SparseCommentFieldWithEditContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_edit_context(&CommentListParams::default(), fields),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    #[case(None, "/comments/54?context=view")]
+    #[case(Some("foo"), "/comments/54?context=view&password=foo")]
+    fn retrieve_comment(
+        endpoint: CommentsRequestEndpoint,
+        #[case] password: Option<&str>,
+        #[case] expected_path: &str,
+    ) {
+        let params = CommentRetrieveParams {
+            password: password.map(|p| p.to_string()),
+        };
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&CommentId(54), &params),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn trash_comment(endpoint: CommentsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.trash(&CommentId(54)), "/comments/54?force=false");
+    }
+
+    #[rstest]
+    fn update_comment(endpoint: CommentsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&CommentId(54)), "/comments/54");
+    }
+
+    #[rstest]
+    #[case(CommentsRequestBuilder::approve, r#"{"status":"approved"}"#)]
+    #[case(CommentsRequestBuilder::hold, r#"{"status":"hold"}"#)]
+    #[case(CommentsRequestBuilder::spam, r#"{"status":"spam"}"#)]
+    fn moderate_comment(
+        fixture_api_base_url: Arc<ApiBaseUrl>,
+        #[case] moderate: fn(
+            &CommentsRequestBuilder,
+            &CommentId,
+        ) -> Result<WpNetworkRequest, WpApiError>,
+        #[case] expected_body: &str,
+    ) {
+        let request_builder =
+            CommentsRequestBuilder::new(fixture_api_base_url, WpAuthentication::None.into());
+        let request = moderate(&request_builder, &CommentId(54)).unwrap();
+        assert!(request.url().0.ends_with("/wp-json/wp/v2/comments/54"));
+        assert_eq!(request.body_as_string().as_deref(), Some(expected_body));
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> CommentsRequestEndpoint {
+        CommentsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
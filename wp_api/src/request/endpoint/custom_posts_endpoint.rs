@@ -0,0 +1,186 @@
+use crate::{
+    custom_posts::{
+        CustomPost, CustomPostCreateParams, CustomPostDeleteResponse, CustomPostType,
+        CustomPostUpdateParams,
+    },
+    posts::{PostId, PostListParams, PostRetrieveParams},
+};
+use wp_derive_request_builder::WpDerivedRequest;
+
+use super::{AsNamespace, DerivedRequest};
+
+#[derive(WpDerivedRequest)]
+enum CustomPostsRequest {
+    #[contextual_get(url = "/<custom_post_type>", params = &PostListParams, output = Vec<CustomPost>)]
+    List,
+    #[contextual_get(url = "/<custom_post_type>/<post_id>", params = &PostRetrieveParams, output = CustomPost)]
+    Retrieve,
+    #[post(url = "/<custom_post_type>", params = &CustomPostCreateParams, output = CustomPost)]
+    Create,
+    #[delete(url = "/<custom_post_type>/<post_id>", output = CustomPostDeleteResponse)]
+    Delete,
+    #[delete(url = "/<custom_post_type>/<post_id>", output = CustomPost)]
+    Trash,
+    #[post(url = "/<custom_post_type>/<post_id>", params = &CustomPostUpdateParams, output = CustomPost)]
+    Update,
+}
+
+impl DerivedRequest for CustomPostsRequest {
+    fn additional_query_pairs(&self) -> Vec<(&str, String)> {
+        match self {
+            CustomPostsRequest::Delete => vec![("force", true.to_string())],
+            CustomPostsRequest::Trash => vec![("force", false.to_string())],
+            _ => vec![],
+        }
+    }
+
+    fn namespace() -> impl AsNamespace {
+        CustomPostTypeNamespace
+    }
+}
+
+// Custom post types can be registered under any namespace, so the namespace is part of the
+// `CustomPostType` url part instead.
+struct CustomPostTypeNamespace;
+
+impl AsNamespace for CustomPostTypeNamespace {
+    fn as_str(&self) -> &str {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        posts::{PostCreateParams, PostStatus},
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        WpAuthentication,
+    };
+    use rstest::*;
+    use std::{collections::HashMap, sync::Arc};
+
+    #[rstest]
+    fn create_custom_post(endpoint: CustomPostsRequestEndpoint, product: CustomPostType) {
+        validate_wp_v2_endpoint(endpoint.create(&product), "/product");
+    }
+
+    #[rstest]
+    fn create_custom_post_request(fixture_api_base_url: Arc<ApiBaseUrl>, product: CustomPostType) {
+        let request_builder =
+            CustomPostsRequestBuilder::new(fixture_api_base_url, WpAuthentication::None.into());
+        let request = request_builder
+            .create(
+                &product,
+                &CustomPostCreateParams {
+                    post: PostCreateParams {
+                        title: Some("foo".to_string()),
+                        ..Default::default()
+                    },
+                    extra_fields: HashMap::from([("price".to_string(), "10".to_string())]),
+                },
+            )
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_str(
+            &request
+                .body_as_string()
+                .expect("Create request should have a body"),
+        )
+        .expect("Create request body should be JSON");
+        assert_eq!(body, serde_json::json!({ "title": "foo", "price": 10 }));
+    }
+
+    #[rstest]
+    fn delete_custom_post(endpoint: CustomPostsRequestEndpoint, product: CustomPostType) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(&product, &PostId(54)),
+            "/product/54?force=true",
+        );
+    }
+
+    #[rstest]
+    #[case(PostListParams::default(), "")]
+    #[case(generate!(PostListParams, (page, Some(2))), "page=2")]
+    #[case(generate!(PostListParams, (status, vec![PostStatus::Draft])), "status=draft")]
+    fn list_custom_posts(
+        endpoint: CustomPostsRequestEndpoint,
+        product: CustomPostType,
+        #[case] params: PostListParams,
+        #[case] expected_additional_params: &str,
+    ) {
+        let expected_path = |context: &str| {
+            if expected_additional_params.is_empty() {
+                format!("/product?context={}", context)
+            } else {
+                format!(
+                    "/product?context={}&{}",
+                    context, expected_additional_params
+                )
+            }
+        };
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&product, &params),
+            &expected_path("edit"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_embed_context(&product, &params),
+            &expected_path("embed"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_view_context(&product, &params),
+            &expected_path("view"),
+        );
+    }
+
+    #[rstest]
+    fn list_custom_posts_in_custom_namespace(endpoint: CustomPostsRequestEndpoint) {
+        assert_eq!(
+            endpoint
+                .list_with_edit_context(
+                    &CustomPostType::new("wc/v3", "products"),
+                    &PostListParams::default(),
+                )
+                .as_str(),
+            "https://example.com/wp-json/wc/v3/products?context=edit"
+        );
+    }
+
+    #[rstest]
+    fn retrieve_custom_post(endpoint: CustomPostsRequestEndpoint, product: CustomPostType) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(
+                &product,
+                &PostId(54),
+                &PostRetrieveParams::default(),
+            ),
+            "/product/54?context=edit",
+        );
+    }
+
+    #[rstest]
+    fn trash_custom_post(endpoint: CustomPostsRequestEndpoint, product: CustomPostType) {
+        validate_wp_v2_endpoint(
+            endpoint.trash(&product, &PostId(54)),
+            "/product/54?force=false",
+        );
+    }
+
+    #[rstest]
+    fn update_custom_post(endpoint: CustomPostsRequestEndpoint, product: CustomPostType) {
+        validate_wp_v2_endpoint(endpoint.update(&product, &PostId(54)), "/product/54");
+    }
+
+    #[fixture]
+    fn product() -> CustomPostType {
+        CustomPostType::new("wp/v2", "product")
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> CustomPostsRequestEndpoint {
+        CustomPostsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
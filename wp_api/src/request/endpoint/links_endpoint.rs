@@ -0,0 +1,286 @@
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::{
+    api_error::UnfollowableLinkReason,
+    authentication::AuthenticationProvider,
+    links::{WpLink, WpLinkRelation, WpLinks},
+    request::{InnerRequestBuilder, RequestExecutor, WpNetworkRequest, WpNetworkResponse},
+    WpApiError, WpContext,
+};
+
+use super::ApiBaseUrl;
+
+// Links are absolute urls, so unlike most endpoints, they can't be derived with `WpDerivedRequest`.
+#[derive(Debug)]
+pub struct LinksRequestEndpoint {
+    api_base_url: Arc<ApiBaseUrl>,
+}
+
+impl LinksRequestEndpoint {
+    pub fn new(api_base_url: Arc<ApiBaseUrl>) -> Self {
+        Self { api_base_url }
+    }
+
+    pub fn follow(&self, link: &WpLink, context: Option<WpContext>) -> Result<Url, WpApiError> {
+        let unfollowable_link = |reason| WpApiError::UnfollowableLink {
+            href: link.href.clone(),
+            reason,
+        };
+        if link.is_templated() {
+            return Err(unfollowable_link(UnfollowableLinkReason::Templated));
+        }
+        let mut url = Url::parse(&link.href)
+            .map_err(|_| unfollowable_link(UnfollowableLinkReason::InvalidUrl))?;
+        if url.origin() != self.api_base_url.url.origin() {
+            return Err(unfollowable_link(UnfollowableLinkReason::DifferentOrigin));
+        }
+        if let Some(context) = context {
+            url.query_pairs_mut()
+                .append_pair("context", context.as_str());
+        }
+        Ok(url)
+    }
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct LinksRequestBuilder {
+    endpoint: LinksRequestEndpoint,
+    inner: InnerRequestBuilder,
+}
+
+impl LinksRequestBuilder {
+    pub fn new(
+        api_base_url: Arc<ApiBaseUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+    ) -> Self {
+        Self {
+            endpoint: LinksRequestEndpoint::new(api_base_url),
+            inner: InnerRequestBuilder::new(authentication_provider),
+        }
+    }
+}
+
+#[uniffi::export]
+impl LinksRequestBuilder {
+    // Builds a `GET` request for the first link of the relation, such as the `author` of a post.
+    //
+    // Returns `None` if the resource doesn't have a link for the relation.
+    pub fn follow_link(
+        &self,
+        links: &WpLinks,
+        relation: WpLinkRelation,
+        context: Option<WpContext>,
+    ) -> Result<Option<Arc<WpNetworkRequest>>, WpApiError> {
+        links
+            .first(&relation)
+            .map(|link| self.follow(link, context))
+            .transpose()
+    }
+
+    // Builds a `GET` request for the given link, such as one of the `wp:term` links of a post.
+    pub fn follow(
+        &self,
+        link: &WpLink,
+        context: Option<WpContext>,
+    ) -> Result<Arc<WpNetworkRequest>, WpApiError> {
+        let url = self.endpoint.follow(link, context)?;
+        self.inner.get(url.into()).map(Arc::new)
+    }
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct LinksRequestExecutor {
+    request_builder: LinksRequestBuilder,
+    request_executor: Arc<dyn RequestExecutor>,
+}
+
+impl LinksRequestExecutor {
+    pub fn new(
+        api_base_url: Arc<ApiBaseUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+        request_executor: Arc<dyn RequestExecutor>,
+    ) -> Self {
+        Self {
+            request_builder: LinksRequestBuilder::new(api_base_url, authentication_provider),
+            request_executor,
+        }
+    }
+
+    // The type of the linked resource depends on the relation, for example
+    // `client.links().follow_link_as::<UserWithViewContext>(&post.links, WpLinkRelation::Author, Some(WpContext::View))`.
+    //
+    // `uniffi` doesn't support generic types, so this is only available in Rust. Other platforms
+    // can parse the response of `follow_link`.
+    pub async fn follow_link_as<T: DeserializeOwned>(
+        &self,
+        links: &WpLinks,
+        relation: WpLinkRelation,
+        context: Option<WpContext>,
+    ) -> Result<Option<T>, WpApiError> {
+        self.follow_link(links, relation, context)
+            .await?
+            .map(|response| response.parse())
+            .transpose()
+    }
+
+    pub async fn follow_as<T: DeserializeOwned>(
+        &self,
+        link: &WpLink,
+        context: Option<WpContext>,
+    ) -> Result<T, WpApiError> {
+        self.follow(link, context).await?.parse()
+    }
+}
+
+#[uniffi::export]
+impl LinksRequestExecutor {
+    // Returns `None` if the resource doesn't have a link for the relation.
+    pub async fn follow_link(
+        &self,
+        links: &WpLinks,
+        relation: WpLinkRelation,
+        context: Option<WpContext>,
+    ) -> Result<Option<WpNetworkResponse>, WpApiError> {
+        match links.first(&relation) {
+            Some(link) => self.follow(link, context).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn follow(
+        &self,
+        link: &WpLink,
+        context: Option<WpContext>,
+    ) -> Result<WpNetworkResponse, WpApiError> {
+        let request = self.request_builder.follow(link, context)?;
+        Ok(self.request_executor.execute(request).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock::MockRequestExecutor,
+        request::{endpoint::tests::fixture_api_base_url, RequestMethod},
+        WpAuthentication,
+    };
+    use rstest::*;
+
+    #[rstest]
+    #[case(
+        "https://example.com/wp-json/wp/v2/users/1",
+        None,
+        "https://example.com/wp-json/wp/v2/users/1"
+    )]
+    #[case(
+        "https://example.com/wp-json/wp/v2/comments?post=1",
+        Some(WpContext::Edit),
+        "https://example.com/wp-json/wp/v2/comments?post=1&context=edit"
+    )]
+    #[case(
+        "https://example.com/index.php?rest_route=/wp/v2/users/1",
+        None,
+        "https://example.com/index.php?rest_route=/wp/v2/users/1"
+    )]
+    fn follow_link(
+        fixture_api_base_url: Arc<ApiBaseUrl>,
+        #[case] href: &str,
+        #[case] context: Option<WpContext>,
+        #[case] expected_url: &str,
+    ) {
+        assert_eq!(
+            LinksRequestEndpoint::new(fixture_api_base_url)
+                .follow(&link(href, None), context)
+                .unwrap()
+                .as_str(),
+            expected_url
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "https://example.org/wp-json/wp/v2/users/1",
+        None,
+        UnfollowableLinkReason::DifferentOrigin
+    )]
+    #[case(
+        "http://example.com/wp-json/wp/v2/users/1",
+        None,
+        UnfollowableLinkReason::DifferentOrigin
+    )]
+    #[case("not a url", None, UnfollowableLinkReason::InvalidUrl)]
+    #[case(
+        "https://example.com/wp-json/{rel}",
+        Some(true),
+        UnfollowableLinkReason::Templated
+    )]
+    fn unfollowable_link(
+        fixture_api_base_url: Arc<ApiBaseUrl>,
+        #[case] href: &str,
+        #[case] templated: Option<bool>,
+        #[case] expected_reason: UnfollowableLinkReason,
+    ) {
+        assert_eq!(
+            LinksRequestEndpoint::new(fixture_api_base_url).follow(&link(href, templated), None),
+            Err(WpApiError::UnfollowableLink {
+                href: href.to_string(),
+                reason: expected_reason
+            })
+        );
+    }
+
+    #[rstest]
+    fn follow_link_with_executor(fixture_api_base_url: Arc<ApiBaseUrl>) {
+        let executor = Arc::new(MockRequestExecutor::new());
+        executor.respond_with_json(
+            RequestMethod::GET,
+            "/wp/v2/users/<user_id>".to_string(),
+            200,
+            r#"{"id":1,"name":"foo"}"#.to_string(),
+        );
+        let links = WpLinks {
+            author: vec![link("https://example.com/wp-json/wp/v2/users/1", None)],
+            ..Default::default()
+        };
+        let links_executor = LinksRequestExecutor::new(
+            fixture_api_base_url,
+            WpAuthentication::None.into(),
+            executor.clone(),
+        );
+        let author =
+            futures::executor::block_on(links_executor.follow_link_as::<serde_json::Value>(
+                &links,
+                WpLinkRelation::Author,
+                Some(WpContext::View),
+            ))
+            .unwrap();
+        assert_eq!(author, Some(serde_json::json!({ "id": 1, "name": "foo" })));
+        assert_eq!(
+            executor.requests()[0].url().0,
+            "https://example.com/wp-json/wp/v2/users/1?context=view"
+        );
+        let replies = futures::executor::block_on(links_executor.follow_link(
+            &links,
+            WpLinkRelation::Replies,
+            None,
+        ))
+        .unwrap();
+        assert!(replies.is_none());
+    }
+
+    fn link(href: &str, templated: Option<bool>) -> WpLink {
+        WpLink {
+            href: href.to_string(),
+            embeddable: None,
+            templated,
+            name: None,
+            taxonomy: None,
+            count: None,
+            id: None,
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use std::sync::Arc;
+
+use crate::{
+    media::{
+        MediaCreateParams, MediaListParams, MediaUpdateParams, MediaUploadFile,
+        MediaWithEditContext, SparseMediaFieldWithEditContext, SparseMediaFieldWithEmbedContext,
+        SparseMediaFieldWithViewContext,
+    },
+    posts::MediaId,
+    request::{MultipartForm, WpNetworkRequest},
+    SparseField, WpApiError,
+};
+use wp_derive_request_builder::WpDerivedRequest;
+
+use super::{ApiEndpointUrl, AsNamespace, DerivedRequest, WpNamespace};
+
+#[derive(WpDerivedRequest)]
+enum MediaRequest {
+    #[contextual_get(url = "/media", params = &MediaListParams, output = Vec<crate::media::SparseMedia>, filter_by = crate::media::SparseMediaField)]
+    List,
+    #[contextual_get(url = "/media/<media_id>", output = crate::media::SparseMedia, filter_by = crate::media::SparseMediaField)]
+    Retrieve,
+    #[delete(url = "/media/<media_id>", output = crate::media::MediaDeleteResponse)]
+    Delete,
+    #[post(url = "/media/<media_id>", params = &MediaUpdateParams, output = MediaWithEditContext)]
+    Update,
+}
+
+impl DerivedRequest for MediaRequest {
+    fn additional_query_pairs(&self) -> Vec<(&str, String)> {
+        match self {
+            // Media items don't support trashing, so `force=true` is required to delete them
+            MediaRequest::Delete => vec![("force", true.to_string())],
+            _ => vec![],
+        }
+    }
+
+    fn namespace() -> impl AsNamespace {
+        WpNamespace::WpV2
+    }
+}
+
+crate::generate_paginated_list_impl!(
+    Media,
+    MediaListParams;
+    edit => crate::media::MediaWithEditContext,
+    embed => crate::media::MediaWithEmbedContext,
+    view => crate::media::MediaWithViewContext
+);
+
+// Uploads are sent as `multipart/form-data` instead of JSON, so they can't be derived and are
+// implemented manually for each of the generated types.
+impl MediaRequestEndpoint {
+    pub fn upload(&self) -> ApiEndpointUrl {
+        self.api_base_url
+            .by_extending_and_splitting_by_forward_slash([
+                MediaRequest::namespace().as_str(),
+                "/media",
+            ])
+            .into()
+    }
+}
+
+#[uniffi::export]
+impl MediaRequestBuilder {
+    pub fn upload(
+        &self,
+        params: &MediaCreateParams,
+        file: MediaUploadFile,
+    ) -> Result<WpNetworkRequest, WpApiError> {
+        let form = media_create_params_as_form_fields(params)
+            .into_iter()
+            .fold(MultipartForm::new(), |form, (name, value)| {
+                form.text(&name, &value)
+            })
+            .file("file", &file.file_name, &file.mime_type, &file.contents);
+        self.inner.post_multipart(self.endpoint.upload(), form)
+    }
+}
+
+#[uniffi::export]
+impl MediaRequestExecutor {
+    pub async fn upload(
+        &self,
+        params: &MediaCreateParams,
+        file: MediaUploadFile,
+    ) -> Result<MediaWithEditContext, WpApiError> {
+        let request = self.request_builder.upload(params, file)?;
+        self.request_executor
+            .execute(Arc::new(request))
+            .await?
+            .parse()
+    }
+}
+
+fn media_create_params_as_form_fields(params: &MediaCreateParams) -> Vec<(String, String)> {
+    match serde_json::to_value(params) {
+        Ok(serde_json::Value::Object(map)) => map
+            .into_iter()
+            .filter_map(|(name, value)| match value {
+                serde_json::Value::Null => None,
+                serde_json::Value::String(s) => Some((name, s)),
+                other => Some((name, other.to_string())),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMediaFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMediaFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseMediaFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        media::{WpApiParamMediaOrderBy, WpApiParamMediaType},
+        posts::{PostId, PostStatus},
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        WpApiParamOrder, WpAuthentication,
+    };
+    use rstest::*;
+
+    #[rstest]
+    fn delete_media(endpoint: MediaRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.delete(&MediaId(54)), "/media/54?force=true");
+    }
+
+    #[rstest]
+    #[case(MediaListParams::default(), "")]
+    #[case(generate!(MediaListParams, (page, Some(2))), "page=2")]
+    #[case(generate!(MediaListParams, (include, vec![MediaId(1), MediaId(2)])), "include=1%2C2")]
+    #[case(generate!(MediaListParams, (order, Some(WpApiParamOrder::Asc))), "order=asc")]
+    #[case(generate!(MediaListParams, (orderby, Some(WpApiParamMediaOrderBy::Title))), "orderby=title")]
+    #[case(generate!(MediaListParams, (parent, vec![PostId(3)])), "parent=3")]
+    #[case(generate!(MediaListParams, (status, vec![PostStatus::Custom("inherit".to_string())])), "status=inherit")]
+    #[case(generate!(MediaListParams, (media_type, Some(WpApiParamMediaType::Audio))), "media_type=audio")]
+    #[case(generate!(MediaListParams, (mime_type, Some("image/png".to_string()))), "mime_type=image%2Fpng")]
+    fn list_media(
+        endpoint: MediaRequestEndpoint,
+        #[case] params: MediaListParams,
+        #[case] expected_additional_params: &str,
+    ) {
+        let expected_path = |context: &str| {
+            if expected_additional_params.is_empty() {
+                format!("/media?context={}", context)
+            } else {
+                format!("/media?context={}&{}", context, expected_additional_params)
+            }
+        };
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&params),
+            &expected_path("edit"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_embed_context(&params),
+            &expected_path("embed"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_view_context(&params),
+            &expected_path("view"),
+        );
+    }
+
+    #[rstest]
+    #[case(&[], "/media?context=edit&_fields=")]
+    #[case(&[SparseMediaFieldWithEditContext::SourceUrl, SparseMediaFieldWithEditContext::MediaDetails], "/media?context=edit&_fields=source_url%2Cmedia_details")]
+    #[case(&[SparseMediaFieldWithEditContext::PostType, SparseMediaFieldWithEditContext::MimeType], "/media?context=edit&_fields=type%2Cmime_type")]
+    fn filter_list_media_with_edit_context(
+        endpoint: MediaRequestEndpoint,
+        #[case] fields: &[SparseMediaFieldWithEditContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_edit_context(&MediaListParams::default(), fields),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn retrieve_media(endpoint: MediaRequestEndpoint) {
+        let media_id = MediaId(54);
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&media_id),
+            "/media/54?context=edit",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_embed_context(&media_id),
+            "/media/54?context=embed",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&media_id),
+            "/media/54?context=view",
+        );
+    }
+
+    #[rstest]
+    #[case(&[SparseMediaFieldWithViewContext::AltText, SparseMediaFieldWithViewContext::Post], "/media/54?context=view&_fields=alt_text%2Cpost")]
+    fn filter_retrieve_media_with_view_context(
+        endpoint: MediaRequestEndpoint,
+        #[case] fields: &[SparseMediaFieldWithViewContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_view_context(&MediaId(54), fields),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn update_media(endpoint: MediaRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&MediaId(54)), "/media/54");
+    }
+
+    #[rstest]
+    fn upload_media(endpoint: MediaRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.upload(), "/media");
+    }
+
+    #[rstest]
+    fn upload_media_request(fixture_api_base_url: Arc<ApiBaseUrl>) {
+        let request_builder =
+            MediaRequestBuilder::new(fixture_api_base_url, WpAuthentication::None.into());
+        let request = request_builder
+            .upload(
+                &MediaCreateParams {
+                    title: Some("foo".to_string()),
+                    post: Some(PostId(3)),
+                    ..Default::default()
+                },
+                MediaUploadFile {
+                    file_name: "foo.txt".to_string(),
+                    mime_type: "text/plain".to_string(),
+                    contents: b"bar".to_vec(),
+                },
+            )
+            .unwrap();
+        let content_type = request
+            .header_map()
+            .as_header_map()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|h| h.to_str().ok())
+            .map(|h| h.to_string())
+            .expect("Upload request should have a content type");
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .expect("Upload request should be a multipart/form-data request");
+        let body = request
+            .body_as_string()
+            .expect("Upload request should have a body");
+        assert!(body.contains("Content-Disposition: form-data; name=\"title\"\r\n\r\nfoo\r\n"));
+        assert!(body.contains("Content-Disposition: form-data; name=\"post\"\r\n\r\n3\r\n"));
+        assert!(body.contains(
+            "Content-Disposition: form-data; name=\"file\"; filename=\"foo.txt\"\r\nContent-Type: text/plain\r\n\r\nbar\r\n"
+        ));
+        assert!(body.ends_with(&format!("--{}--\r\n", boundary)));
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> MediaRequestEndpoint {
+        MediaRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    pages::{
+        PageId, PageListParams, PageUpdateParams, PageWithEditContext,
+        SparsePageFieldWithEditContext, SparsePageFieldWithEmbedContext,
+        SparsePageFieldWithViewContext,
+    },
+    SparseField,
+};
+use wp_derive_request_builder::WpDerivedRequest;
+
+use super::{AsNamespace, DerivedRequest, WpNamespace};
+
+#[derive(WpDerivedRequest)]
+enum PagesRequest {
+    #[contextual_get(url = "/pages", params = &PageListParams, output = Vec<crate::pages::SparsePage>, filter_by = crate::pages::SparsePageField, embed_by = crate::embed::WpEmbedRelation)]
+    List,
+    #[contextual_get(url = "/pages/<page_id>", params = &crate::pages::PageRetrieveParams, output = crate::pages::SparsePage, filter_by = crate::pages::SparsePageField, embed_by = crate::embed::WpEmbedRelation)]
+    Retrieve,
+    #[post(url = "/pages", params = &crate::pages::PageCreateParams, output = crate::pages::PageWithEditContext)]
+    Create,
+    #[delete(url = "/pages/<page_id>", output = crate::pages::PageDeleteResponse)]
+    Delete,
+    #[delete(url = "/pages/<page_id>", output = crate::pages::PageWithEditContext)]
+    Trash,
+    #[post(url = "/pages/<page_id>", params = &PageUpdateParams, output = PageWithEditContext)]
+    Update,
+}
+
+impl DerivedRequest for PagesRequest {
+    fn additional_query_pairs(&self) -> Vec<(&str, String)> {
+        match self {
+            PagesRequest::Delete => vec![("force", true.to_string())],
+            PagesRequest::Trash => vec![("force", false.to_string())],
+            _ => vec![],
+        }
+    }
+
+    fn namespace() -> impl AsNamespace {
+        WpNamespace::WpV2
+    }
+}
+
+crate::generate_paginated_list_impl!(
+    Pages,
+    PageListParams;
+    edit => crate::pages::PageWithEditContext,
+    embed => crate::pages::PageWithEmbedContext,
+    view => crate::pages::PageWithViewContext
+);
+
+super::macros::default_sparse_field_implementation_from_field_name!(SparsePageFieldWithEditContext);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparsePageFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(SparsePageFieldWithViewContext);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        pages::{PageRetrieveParams, WpApiParamPagesOrderBy},
+        posts::PostStatus,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        UserId, WpApiParamOrder,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn create_page(endpoint: PagesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/pages");
+    }
+
+    #[rstest]
+    fn delete_page(endpoint: PagesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.delete(&PageId(54)), "/pages/54?force=true");
+    }
+
+    #[rstest]
+    #[case(PageListParams::default(), "")]
+    #[case(generate!(PageListParams, (page, Some(2))), "page=2")]
+    #[case(generate!(PageListParams, (per_page, Some(2))), "per_page=2")]
+    #[case(generate!(PageListParams, (search, Some("foo".to_string()))), "search=foo")]
+    #[case(generate!(PageListParams, (author, vec![UserId(1), UserId(2)])), "author=1%2C2")]
+    #[case(generate!(PageListParams, (exclude, vec![PageId(1), PageId(2)])), "exclude=1%2C2")]
+    #[case(generate!(PageListParams, (include, vec![PageId(1), PageId(2)])), "include=1%2C2")]
+    #[case(generate!(PageListParams, (menu_order, Some(2))), "menu_order=2")]
+    #[case(generate!(PageListParams, (order, Some(WpApiParamOrder::Desc))), "order=desc")]
+    #[case(generate!(PageListParams, (orderby, Some(WpApiParamPagesOrderBy::MenuOrder))), "orderby=menu_order")]
+    #[case(generate!(PageListParams, (parent, vec![PageId(1), PageId(2)])), "parent=1%2C2")]
+    #[case(generate!(PageListParams, (parent_exclude, vec![PageId(1), PageId(2)])), "parent_exclude=1%2C2")]
+    #[case(generate!(PageListParams, (status, vec![PostStatus::Draft, PostStatus::Publish])), "status=draft%2Cpublish")]
+    fn list_pages(
+        endpoint: PagesRequestEndpoint,
+        #[case] params: PageListParams,
+        #[case] expected_additional_params: &str,
+    ) {
+        let expected_path = |context: &str| {
+            if expected_additional_params.is_empty() {
+                format!("/pages?context={}", context)
+            } else {
+                format!("/pages?context={}&{}", context, expected_additional_params)
+            }
+        };
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&params),
+            &expected_path("edit"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_embed_context(&params),
+            &expected_path("embed"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_view_context(&params),
+            &expected_path("view"),
+        );
+    }
+
+    #[rstest]
+    #[case(PageListParams::default(), &[], "/pages?context=edit&_fields=")]
+    #[case(generate!(PageListParams, (parent, vec![PageId(2)])), &[SparsePageFieldWithEditContext::Parent, SparsePageFieldWithEditContext::MenuOrder], "/pages?context=edit&parent=2&_fields=parent%2Cmenu_order")]
+    #[case(PageListParams::default(), &[SparsePageFieldWithEditContext::PostType, SparsePageFieldWithEditContext::Template], "/pages?context=edit&_fields=type%2Ctemplate")]
+    fn filter_list_pages_with_edit_context(
+        endpoint: PagesRequestEndpoint,
+        #[case] params: PageListParams,
+        #[case] fields: &[SparsePageFieldWithEditContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_edit_context(&params, fields),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    #[case(None, "")]
+    #[case(Some("foo"), "password=foo")]
+    fn retrieve_page(
+        endpoint: PagesRequestEndpoint,
+        #[case] password: Option<&str>,
+        #[case] expected_additional_params: &str,
+    ) {
+        let page_id = PageId(54);
+        let expected_path = |context: &str| {
+            if expected_additional_params.is_empty() {
+                format!("/pages/54?context={}", context)
+            } else {
+                format!(
+                    "/pages/54?context={}&{}",
+                    context, expected_additional_params
+                )
+            }
+        };
+        let params = PageRetrieveParams {
+            password: password.map(|p| p.to_string()),
+        };
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&page_id, &params),
+            &expected_path("edit"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_embed_context(&page_id, &params),
+            &expected_path("embed"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&page_id, &params),
+            &expected_path("view"),
+        );
+    }
+
+    #[rstest]
+    #[case(&[], "/pages/54?context=view&_fields=")]
+    #[case(&[SparsePageFieldWithViewContext::Parent, SparsePageFieldWithViewContext::MenuOrder, SparsePageFieldWithViewContext::Template], "/pages/54?context=view&_fields=parent%2Cmenu_order%2Ctemplate")]
+    fn filter_retrieve_page_with_view_context(
+        endpoint: PagesRequestEndpoint,
+        #[case] fields: &[SparsePageFieldWithViewContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_view_context(
+                &PageId(54),
+                &PageRetrieveParams::default(),
+                fields,
+            ),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn trash_page(endpoint: PagesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.trash(&PageId(54)), "/pages/54?force=false");
+    }
+
+    #[rstest]
+    fn update_page(endpoint: PagesRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&PageId(54)), "/pages/54");
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> PagesRequestEndpoint {
+        PagesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    post_revisions::{
+        PostAutosaveCreateParams, PostRevisionListParams, PostRevisionWithEditContext,
+        SparsePostRevisionFieldWithEditContext, SparsePostRevisionFieldWithEmbedContext,
+        SparsePostRevisionFieldWithViewContext,
+    },
+    posts::{PostId, RevisionId},
+    SparseField,
+};
+use wp_derive_request_builder::WpDerivedRequest;
+
+use super::{AsNamespace, DerivedRequest, WpNamespace};
+
+#[derive(WpDerivedRequest)]
+enum PostRevisionsRequest {
+    #[contextual_get(url = "/posts/<post_id>/revisions", params = &PostRevisionListParams, output = Vec<crate::post_revisions::SparsePostRevision>, filter_by = crate::post_revisions::SparsePostRevisionField)]
+    List,
+    #[contextual_get(url = "/posts/<post_id>/revisions/<revision_id>", output = crate::post_revisions::SparsePostRevision, filter_by = crate::post_revisions::SparsePostRevisionField)]
+    Retrieve,
+    #[delete(url = "/posts/<post_id>/revisions/<revision_id>", output = crate::post_revisions::PostRevisionDeleteResponse)]
+    Delete,
+    #[contextual_get(url = "/posts/<post_id>/autosaves", output = Vec<crate::post_revisions::SparsePostRevision>, filter_by = crate::post_revisions::SparsePostRevisionField)]
+    ListAutosaves,
+    #[contextual_get(url = "/posts/<post_id>/autosaves/<revision_id>", output = crate::post_revisions::SparsePostRevision, filter_by = crate::post_revisions::SparsePostRevisionField)]
+    RetrieveAutosave,
+    #[post(url = "/posts/<post_id>/autosaves", params = &PostAutosaveCreateParams, output = PostRevisionWithEditContext)]
+    CreateAutosave,
+}
+
+impl DerivedRequest for PostRevisionsRequest {
+    fn additional_query_pairs(&self) -> Vec<(&str, String)> {
+        match self {
+            // Revisions don't support trashing, so `force=true` is required to delete them
+            PostRevisionsRequest::Delete => vec![("force", true.to_string())],
+            _ => vec![],
+        }
+    }
+
+    fn namespace() -> impl AsNamespace {
+        WpNamespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparsePostRevisionFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparsePostRevisionFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparsePostRevisionFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        post_revisions::WpApiParamPostRevisionsOrderBy,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        WpApiParamOrder,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn create_autosave(endpoint: PostRevisionsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create_autosave(&PostId(1)), "/posts/1/autosaves");
+    }
+
+    #[rstest]
+    fn delete_post_revision(endpoint: PostRevisionsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.delete(&PostId(1), &RevisionId(54)),
+            "/posts/1/revisions/54?force=true",
+        );
+    }
+
+    #[rstest]
+    fn list_autosaves(endpoint: PostRevisionsRequestEndpoint) {
+        let post_id = PostId(1);
+        validate_wp_v2_endpoint(
+            endpoint.list_autosaves_with_edit_context(&post_id),
+            "/posts/1/autosaves?context=edit",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_autosaves_with_embed_context(&post_id),
+            "/posts/1/autosaves?context=embed",
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_autosaves_with_view_context(&post_id),
+            "/posts/1/autosaves?context=view",
+        );
+    }
+
+    #[rstest]
+    #[case(PostRevisionListParams::default(), "")]
+    #[case(generate!(PostRevisionListParams, (page, Some(2))), "page=2")]
+    #[case(generate!(PostRevisionListParams, (include, vec![RevisionId(3), RevisionId(4)])), "include=3%2C4")]
+    #[case(generate!(PostRevisionListParams, (order, Some(WpApiParamOrder::Asc))), "order=asc")]
+    #[case(generate!(PostRevisionListParams, (orderby, Some(WpApiParamPostRevisionsOrderBy::IncludeSlugs))), "orderby=include_slugs")]
+    fn list_post_revisions(
+        endpoint: PostRevisionsRequestEndpoint,
+        #[case] params: PostRevisionListParams,
+        #[case] expected_additional_params: &str,
+    ) {
+        let expected_path = |context: &str| {
+            if expected_additional_params.is_empty() {
+                format!("/posts/1/revisions?context={}", context)
+            } else {
+                format!(
+                    "/posts/1/revisions?context={}&{}",
+                    context, expected_additional_params
+                )
+            }
+        };
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&PostId(1), &params),
+            &expected_path("edit"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_embed_context(&PostId(1), &params),
+            &expected_path("embed"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_view_context(&PostId(1), &params),
+            &expected_path("view"),
+        );
+    }
+
+    #[rstest]
+    #[case(&[SparsePostRevisionFieldWithEditContext::Id, SparsePostRevisionFieldWithEditContext::PreviewLink], "/posts/1/autosaves/54?context=edit&_fields=id%2Cpreview_link")]
+    fn filter_retrieve_autosave_with_edit_context(
+        endpoint: PostRevisionsRequestEndpoint,
+        #[case] fields: &[SparsePostRevisionFieldWithEditContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_autosave_with_edit_context(
+                &PostId(1),
+                &RevisionId(54),
+                fields,
+            ),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn retrieve_post_revision(endpoint: PostRevisionsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&PostId(1), &RevisionId(54)),
+            "/posts/1/revisions/54?context=view",
+        );
+    }
+
+    #[rstest]
+    #[case(&[SparsePostRevisionFieldWithEmbedContext::Parent, SparsePostRevisionFieldWithEmbedContext::Title], "/posts/1/revisions?context=embed&_fields=parent%2Ctitle")]
+    #[case(&[SparsePostRevisionFieldWithEmbedContext::Id, SparsePostRevisionFieldWithEmbedContext::Links], "/posts/1/revisions?context=embed&_fields=id%2C_links")]
+    fn filter_list_post_revisions_with_embed_context(
+        endpoint: PostRevisionsRequestEndpoint,
+        #[case] fields: &[SparsePostRevisionFieldWithEmbedContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_embed_context(
+                &PostId(1),
+                &PostRevisionListParams::default(),
+                fields,
+            ),
+            expected_path,
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> PostRevisionsRequestEndpoint {
+        PostRevisionsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
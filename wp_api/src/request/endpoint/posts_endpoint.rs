@@ -12,9 +12,9 @@ use super::{AsNamespace, DerivedRequest, WpNamespace};
 
 #[derive(WpDerivedRequest)]
 enum PostsRequest {
-    #[contextual_get(url = "/posts", params = &PostListParams, output = Vec<crate::posts::SparsePost>, filter_by = crate::posts::SparsePostField)]
+    #[contextual_get(url = "/posts", params = &PostListParams, output = Vec<crate::posts::SparsePost>, filter_by = crate::posts::SparsePostField, embed_by = crate::embed::WpEmbedRelation)]
     List,
-    #[contextual_get(url = "/posts/<post_id>", params = &crate::posts::PostRetrieveParams, output = crate::posts::SparsePost, filter_by = crate::posts::SparsePostField)]
+    #[contextual_get(url = "/posts/<post_id>", params = &crate::posts::PostRetrieveParams, output = crate::posts::SparsePost, filter_by = crate::posts::SparsePostField, embed_by = crate::embed::WpEmbedRelation)]
     Retrieve,
     #[post(url = "/posts", params = &crate::posts::PostCreateParams, output = crate::posts::PostWithEditContext)]
     Create,
@@ -40,37 +40,25 @@ impl DerivedRequest for PostsRequest {
     }
 }
 
-impl SparseField for SparsePostFieldWithEditContext {
-    fn as_str(&self) -> &str {
-        match self {
-            Self::PostType => "type",
-            _ => self.as_field_name(),
-        }
-    }
-}
-
-impl SparseField for SparsePostFieldWithEmbedContext {
-    fn as_str(&self) -> &str {
-        match self {
-            Self::PostType => "type",
-            _ => self.as_field_name(),
-        }
-    }
-}
+crate::generate_paginated_list_impl!(
+    Posts,
+    PostListParams;
+    edit => crate::posts::PostWithEditContext,
+    embed => crate::posts::PostWithEmbedContext,
+    view => crate::posts::PostWithViewContext
+);
 
-impl SparseField for SparsePostFieldWithViewContext {
-    fn as_str(&self) -> &str {
-        match self {
-            Self::PostType => "type",
-            _ => self.as_field_name(),
-        }
-    }
-}
+super::macros::default_sparse_field_implementation_from_field_name!(SparsePostFieldWithEditContext);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparsePostFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(SparsePostFieldWithViewContext);
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
+        embed::WpEmbedRelation,
         generate,
         posts::{
             CategoryId, PostRetrieveParams, PostStatus, TagId, WpApiParamPostsOrderBy,
@@ -173,6 +161,7 @@ mod tests {
     #[rstest]
     #[case(PostListParams::default(), &[], "/posts?context=edit&_fields=")]
     #[case(generate!(PostListParams, (orderby, Some(WpApiParamPostsOrderBy::Author))), &[SparsePostFieldWithEditContext::Author], "/posts?context=edit&orderby=author&_fields=author")]
+    #[case(PostListParams::default(), &[SparsePostFieldWithEditContext::PostType, SparsePostFieldWithEditContext::Links, SparsePostFieldWithEditContext::Embedded], "/posts?context=edit&_fields=type%2C_links%2C_embedded")]
     #[case(post_list_params_with_all_fields(), ALL_SPARSE_POST_FIELDS_WITH_EDIT_CONTEXT, &format!("/posts?context=edit&{}&{}", EXPECTED_QUERY_PAIRS_FOR_POST_LIST_PARAMS_WITH_ALL_FIELDS, EXPECTED_QUERY_PAIRS_FOR_ALL_SPARSE_POST_FIELDS_WITH_EDIT_CONTEXT))]
     fn filter_list_post_with_edit_context(
         endpoint: PostsRequestEndpoint,
@@ -260,6 +249,35 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(&[], "/posts?context=view&_embed=1")]
+    #[case(&[WpEmbedRelation::Author], "/posts?context=view&_embed=author")]
+    #[case(&[WpEmbedRelation::Author, WpEmbedRelation::FeaturedMedia, WpEmbedRelation::Term], "/posts?context=view&_embed=author%2Cwp%3Afeaturedmedia%2Cwp%3Aterm")]
+    fn embed_list_post_with_view_context(
+        endpoint: PostsRequestEndpoint,
+        #[case] embed: &[WpEmbedRelation],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.embed_list_with_view_context(&PostListParams::default(), embed),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn embed_retrieve_post_with_edit_context(endpoint: PostsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.embed_retrieve_with_edit_context(
+                &PostId(54),
+                &PostRetrieveParams {
+                    password: Some("foo".to_string()),
+                },
+                &[WpEmbedRelation::Term],
+            ),
+            "/posts/54?context=edit&password=foo&_embed=wp%3Aterm",
+        );
+    }
+
     #[rstest]
     fn trash_post(endpoint: PostsRequestEndpoint) {
         validate_wp_v2_endpoint(endpoint.trash(&PostId(54)), "/posts/54?force=false");
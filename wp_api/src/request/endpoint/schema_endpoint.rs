@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use crate::{
+    authentication::AuthenticationProvider,
+    request::{InnerRequestBuilder, RequestExecutor, WpNetworkRequest},
+    schema::{WpNamespaceIndex, WpRoute},
+    WpApiError,
+};
+
+use super::{ApiBaseUrl, ApiEndpointUrl};
+
+// Routes are given as strings, such as `/wp/v2/posts` or `/my-plugin/v1/items`, so unlike most
+// endpoints, they can't be derived with `WpDerivedRequest`.
+#[derive(Debug)]
+pub struct SchemaRequestEndpoint {
+    api_base_url: Arc<ApiBaseUrl>,
+}
+
+impl SchemaRequestEndpoint {
+    pub fn new(api_base_url: Arc<ApiBaseUrl>) -> Self {
+        Self { api_base_url }
+    }
+
+    pub fn route(&self, route: &str) -> ApiEndpointUrl {
+        self.api_base_url
+            .by_extending_and_splitting_by_forward_slash([route])
+            .into()
+    }
+
+    // The `help` context includes the schema of each route
+    pub fn namespace_index(&self, namespace: &str) -> ApiEndpointUrl {
+        let mut url = self
+            .api_base_url
+            .by_extending_and_splitting_by_forward_slash([namespace]);
+        url.query_pairs_mut().append_pair("context", "help");
+        url.into()
+    }
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct SchemaRequestBuilder {
+    endpoint: SchemaRequestEndpoint,
+    inner: InnerRequestBuilder,
+}
+
+impl SchemaRequestBuilder {
+    pub fn new(
+        api_base_url: Arc<ApiBaseUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+    ) -> Self {
+        Self {
+            endpoint: SchemaRequestEndpoint::new(api_base_url),
+            inner: InnerRequestBuilder::new(authentication_provider),
+        }
+    }
+}
+
+#[uniffi::export]
+impl SchemaRequestBuilder {
+    // An `OPTIONS` request for a route, such as `/wp/v2/posts` or `/wp/v2/posts/1`
+    pub fn route(&self, route: String) -> Result<WpNetworkRequest, WpApiError> {
+        self.inner.options(self.endpoint.route(&route))
+    }
+
+    // The routes of a namespace, such as `wp/v2`, including their schemas
+    pub fn namespace_index(&self, namespace: String) -> Result<WpNetworkRequest, WpApiError> {
+        self.inner.get(self.endpoint.namespace_index(&namespace))
+    }
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct SchemaRequestExecutor {
+    request_builder: SchemaRequestBuilder,
+    request_executor: Arc<dyn RequestExecutor>,
+}
+
+impl SchemaRequestExecutor {
+    pub fn new(
+        api_base_url: Arc<ApiBaseUrl>,
+        authentication_provider: Arc<dyn AuthenticationProvider>,
+        request_executor: Arc<dyn RequestExecutor>,
+    ) -> Self {
+        Self {
+            request_builder: SchemaRequestBuilder::new(api_base_url, authentication_provider),
+            request_executor,
+        }
+    }
+}
+
+#[uniffi::export]
+impl SchemaRequestExecutor {
+    pub async fn route(&self, route: String) -> Result<WpRoute, WpApiError> {
+        let request = self.request_builder.route(route)?;
+        self.request_executor
+            .execute(Arc::new(request))
+            .await?
+            .parse()
+    }
+
+    pub async fn namespace_index(&self, namespace: String) -> Result<WpNamespaceIndex, WpApiError> {
+        let request = self.request_builder.namespace_index(namespace)?;
+        self.request_executor
+            .execute(Arc::new(request))
+            .await?
+            .parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        request::{endpoint::tests::fixture_api_base_url, RequestMethod},
+        WpAuthentication,
+    };
+    use rstest::*;
+
+    #[rstest]
+    #[case("/wp/v2/posts", "https://example.com/wp-json/wp/v2/posts")]
+    #[case("wp/v2/posts/1", "https://example.com/wp-json/wp/v2/posts/1")]
+    #[case(
+        "/my-plugin/v1/items/",
+        "https://example.com/wp-json/my-plugin/v1/items"
+    )]
+    fn route_options(
+        fixture_api_base_url: Arc<ApiBaseUrl>,
+        #[case] route: &str,
+        #[case] expected_url: &str,
+    ) {
+        let request =
+            SchemaRequestBuilder::new(fixture_api_base_url, WpAuthentication::None.into())
+                .route(route.to_string())
+                .unwrap();
+        assert_eq!(request.method(), RequestMethod::OPTIONS);
+        assert_eq!(request.url().0, expected_url);
+    }
+
+    #[rstest]
+    fn namespace_index(fixture_api_base_url: Arc<ApiBaseUrl>) {
+        assert_eq!(
+            SchemaRequestEndpoint::new(fixture_api_base_url)
+                .namespace_index("wp/v2")
+                .as_str(),
+            "https://example.com/wp-json/wp/v2?context=help"
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    posts::TagId,
+    tags::{
+        SparseTagFieldWithEditContext, SparseTagFieldWithEmbedContext,
+        SparseTagFieldWithViewContext, TagListParams, TagUpdateParams, TagWithEditContext,
+    },
+    SparseField,
+};
+use wp_derive_request_builder::WpDerivedRequest;
+
+use super::{AsNamespace, DerivedRequest, WpNamespace};
+
+#[derive(WpDerivedRequest)]
+enum TagsRequest {
+    #[contextual_get(url = "/tags", params = &TagListParams, output = Vec<crate::tags::SparseTag>, filter_by = crate::tags::SparseTagField)]
+    List,
+    #[contextual_get(url = "/tags/<tag_id>", output = crate::tags::SparseTag, filter_by = crate::tags::SparseTagField)]
+    Retrieve,
+    #[post(url = "/tags", params = &crate::tags::TagCreateParams, output = TagWithEditContext)]
+    Create,
+    #[delete(url = "/tags/<tag_id>", output = crate::tags::TagDeleteResponse)]
+    Delete,
+    #[post(url = "/tags/<tag_id>", params = &TagUpdateParams, output = TagWithEditContext)]
+    Update,
+}
+
+impl DerivedRequest for TagsRequest {
+    fn additional_query_pairs(&self) -> Vec<(&str, String)> {
+        match self {
+            // Terms don't support trashing, so `force=true` is required to delete them
+            TagsRequest::Delete => vec![("force", true.to_string())],
+            _ => vec![],
+        }
+    }
+
+    fn namespace() -> impl AsNamespace {
+        WpNamespace::WpV2
+    }
+}
+
+crate::generate_paginated_list_impl!(
+    Tags,
+    TagListParams;
+    edit => crate::tags::TagWithEditContext,
+    embed => crate::tags::TagWithEmbedContext,
+    view => crate::tags::TagWithViewContext
+);
+
+super::macros::default_sparse_field_implementation_from_field_name!(SparseTagFieldWithEditContext);
+super::macros::default_sparse_field_implementation_from_field_name!(SparseTagFieldWithEmbedContext);
+super::macros::default_sparse_field_implementation_from_field_name!(SparseTagFieldWithViewContext);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generate,
+        posts::PostId,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+        tags::WpApiParamTagsOrderBy,
+        WpApiParamOrder,
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    fn create_tag(endpoint: TagsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.create(), "/tags");
+    }
+
+    #[rstest]
+    fn delete_tag(endpoint: TagsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.delete(&TagId(54)), "/tags/54?force=true");
+    }
+
+    #[rstest]
+    #[case(TagListParams::default(), "")]
+    #[case(generate!(TagListParams, (page, Some(2))), "page=2")]
+    #[case(generate!(TagListParams, (search, Some("foo".to_string()))), "search=foo")]
+    #[case(generate!(TagListParams, (exclude, vec![TagId(1), TagId(2)])), "exclude=1%2C2")]
+    #[case(generate!(TagListParams, (order, Some(WpApiParamOrder::Desc))), "order=desc")]
+    #[case(generate!(TagListParams, (orderby, Some(WpApiParamTagsOrderBy::Count))), "orderby=count")]
+    #[case(generate!(TagListParams, (hide_empty, Some(false))), "hide_empty=false")]
+    #[case(generate!(TagListParams, (offset, Some(2))), "offset=2")]
+    #[case(generate!(TagListParams, (post, Some(PostId(1)))), "post=1")]
+    #[case(generate!(TagListParams, (slug, vec!["foo".to_string()])), "slug=foo")]
+    fn list_tags(
+        endpoint: TagsRequestEndpoint,
+        #[case] params: TagListParams,
+        #[case] expected_additional_params: &str,
+    ) {
+        let expected_path = |context: &str| {
+            if expected_additional_params.is_empty() {
+                format!("/tags?context={}", context)
+            } else {
+                format!("/tags?context={}&{}", context, expected_additional_params)
+            }
+        };
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&params),
+            &expected_path("edit"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_embed_context(&params),
+            &expected_path("embed"),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_view_context(&params),
+            &expected_path("view"),
+        );
+    }
+
+    #[rstest]
+    #[case(&[], "/tags?context=edit&_fields=")]
+    #[case(&[SparseTagFieldWithEditContext::Name, SparseTagFieldWithEditContext::Count], "/tags?context=edit&_fields=name%2Ccount")]
+    fn filter_list_tags_with_edit_context(
+        endpoint: TagsRequestEndpoint,
+        #[case] fields: &[SparseTagFieldWithEditContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_list_with_edit_context(&TagListParams::default(), fields),
+            expected_path,
+        );
+    }
+
+    #[rstest]
+    fn retrieve_tag(endpoint: TagsRequestEndpoint) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_embed_context(&TagId(54)),
+            "/tags/54?context=embed",
+        );
+    }
+
+    #[rstest]
+    fn update_tag(endpoint: TagsRequestEndpoint) {
+        validate_wp_v2_endpoint(endpoint.update(&TagId(54)), "/tags/54");
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> TagsRequestEndpoint {
+        TagsRequestEndpoint::new(fixture_api_base_url)
+    }
+}
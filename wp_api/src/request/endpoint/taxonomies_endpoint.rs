@@ -0,0 +1,125 @@
+use super::{AsNamespace, DerivedRequest, WpNamespace};
+use crate::taxonomies::{
+    SparseTaxonomyDetailsFieldWithEditContext, SparseTaxonomyDetailsFieldWithEmbedContext,
+    SparseTaxonomyDetailsFieldWithViewContext, TaxonomyListParams, TaxonomyType,
+};
+use crate::SparseField;
+use wp_derive_request_builder::WpDerivedRequest;
+
+#[derive(WpDerivedRequest)]
+enum TaxonomiesRequest {
+    #[contextual_get(url = "/taxonomies", params = &TaxonomyListParams, output = crate::taxonomies::SparseTaxonomiesResponse)]
+    List,
+    #[contextual_get(url = "/taxonomies/<taxonomy_type>", output = crate::taxonomies::SparseTaxonomyDetails, filter_by = crate::taxonomies::SparseTaxonomyDetailsField)]
+    Retrieve,
+}
+
+impl DerivedRequest for TaxonomiesRequest {
+    fn namespace() -> impl AsNamespace {
+        WpNamespace::WpV2
+    }
+}
+
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseTaxonomyDetailsFieldWithEditContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseTaxonomyDetailsFieldWithEmbedContext
+);
+super::macros::default_sparse_field_implementation_from_field_name!(
+    SparseTaxonomyDetailsFieldWithViewContext
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        post_types::PostType,
+        request::endpoint::{
+            tests::{fixture_api_base_url, validate_wp_v2_endpoint},
+            ApiBaseUrl,
+        },
+    };
+    use rstest::*;
+    use std::sync::Arc;
+
+    #[rstest]
+    #[case(TaxonomyListParams::default(), "")]
+    #[case(TaxonomyListParams { post_type: Some(PostType::Post) }, "&type=post")]
+    #[case(TaxonomyListParams { post_type: Some(PostType::Custom("foo".to_string())) }, "&type=foo")]
+    fn list_taxonomies(
+        endpoint: TaxonomiesRequestEndpoint,
+        #[case] params: TaxonomyListParams,
+        #[case] expected_additional_params: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.list_with_edit_context(&params),
+            format!("/taxonomies?context=edit{}", expected_additional_params).as_str(),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_embed_context(&params),
+            format!("/taxonomies?context=embed{}", expected_additional_params).as_str(),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.list_with_view_context(&params),
+            format!("/taxonomies?context=view{}", expected_additional_params).as_str(),
+        );
+    }
+
+    #[rstest]
+    #[case(TaxonomyType::Category, "/taxonomies/category")]
+    #[case(TaxonomyType::PostTag, "/taxonomies/post_tag")]
+    #[case(TaxonomyType::NavMenu, "/taxonomies/nav_menu")]
+    #[case(TaxonomyType::LinkCategory, "/taxonomies/link_category")]
+    #[case(TaxonomyType::PostFormat, "/taxonomies/post_format")]
+    #[case(TaxonomyType::WpTheme, "/taxonomies/wp_theme")]
+    #[case(TaxonomyType::WpTemplatePartArea, "/taxonomies/wp_template_part_area")]
+    #[case(TaxonomyType::WpPatternCategory, "/taxonomies/wp_pattern_category")]
+    #[case(TaxonomyType::Custom("foo".to_string()), "/taxonomies/foo")]
+    fn retrieve_taxonomy(
+        endpoint: TaxonomiesRequestEndpoint,
+        #[case] taxonomy_type: TaxonomyType,
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_edit_context(&taxonomy_type),
+            format!("{}?context=edit", expected_path).as_str(),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_embed_context(&taxonomy_type),
+            format!("{}?context=embed", expected_path).as_str(),
+        );
+        validate_wp_v2_endpoint(
+            endpoint.retrieve_with_view_context(&taxonomy_type),
+            format!("{}?context=view", expected_path).as_str(),
+        );
+    }
+
+    #[rstest]
+    #[case(TaxonomyType::Category, &[SparseTaxonomyDetailsFieldWithViewContext::Name], "/taxonomies/category?context=view&_fields=name")]
+    #[case(
+        TaxonomyType::PostTag,
+        &[
+            SparseTaxonomyDetailsFieldWithViewContext::Hierarchical,
+            SparseTaxonomyDetailsFieldWithViewContext::RestBase,
+            SparseTaxonomyDetailsFieldWithViewContext::Types,
+        ],
+        "/taxonomies/post_tag?context=view&_fields=hierarchical%2Crest_base%2Ctypes"
+    )]
+    fn filter_retrieve_taxonomy_with_view_context(
+        endpoint: TaxonomiesRequestEndpoint,
+        #[case] taxonomy_type: TaxonomyType,
+        #[case] fields: &[SparseTaxonomyDetailsFieldWithViewContext],
+        #[case] expected_path: &str,
+    ) {
+        validate_wp_v2_endpoint(
+            endpoint.filter_retrieve_with_view_context(&taxonomy_type, fields),
+            expected_path,
+        );
+    }
+
+    #[fixture]
+    fn endpoint(fixture_api_base_url: Arc<ApiBaseUrl>) -> TaxonomiesRequestEndpoint {
+        TaxonomiesRequestEndpoint::new(fixture_api_base_url)
+    }
+}
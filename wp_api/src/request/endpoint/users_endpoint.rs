@@ -33,6 +33,14 @@ impl DerivedRequest for UsersRequest {
     }
 }
 
+crate::generate_paginated_list_impl!(
+    Users,
+    UserListParams;
+    edit => crate::UserWithEditContext,
+    embed => crate::UserWithEmbedContext,
+    view => crate::UserWithViewContext
+);
+
 super::macros::default_sparse_field_implementation_from_field_name!(SparseUserFieldWithEditContext);
 super::macros::default_sparse_field_implementation_from_field_name!(
     SparseUserFieldWithEmbedContext
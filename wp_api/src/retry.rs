@@ -0,0 +1,325 @@
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    api_error::RequestExecutionError,
+    request::{RequestExecutor, RequestMethod, WpNetworkRequest, WpNetworkResponse},
+};
+
+const RETRY_AFTER_HEADER_KEY: &str = "Retry-After";
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct RetryPolicy {
+    // The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    // The delay before the first retry, which is multiplied by `backoff_multiplier` for each
+    // following retry.
+    pub initial_backoff_ms: u64,
+    pub backoff_multiplier: f64,
+    // The maximum delay between attempts. If a `Retry-After` header asks for a longer delay, the
+    // response is returned without retrying.
+    pub max_backoff_ms: u64,
+    // Randomizes each delay between half and all of it, so clients don't retry in lockstep.
+    pub jitter: bool,
+    // By default, only `GET`, `HEAD`, `PUT` & `DELETE` requests are retried, because retrying a
+    // `POST` request, such as creating a post, may apply it more than once.
+    pub retry_non_idempotent_requests: bool,
+    pub retryable_status_codes: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff_ms: 500,
+            backoff_multiplier: 2.0,
+            max_backoff_ms: 30_000,
+            jitter: true,
+            retry_non_idempotent_requests: false,
+            retryable_status_codes: vec![429, 502, 503, 504],
+        }
+    }
+}
+
+impl RetryPolicy {
+    fn should_retry_request(&self, request: &WpNetworkRequest) -> bool {
+        self.retry_non_idempotent_requests
+            || matches!(
+                request.method,
+                RequestMethod::GET
+                    | RequestMethod::HEAD
+                    | RequestMethod::OPTIONS
+                    | RequestMethod::PUT
+                    | RequestMethod::DELETE
+            )
+    }
+
+    // Returns the delay before the given retry, starting from `1`, or `None` if the outcome of the
+    // previous attempt shouldn't be retried.
+    fn retry_delay(
+        &self,
+        retry: u32,
+        result: &Result<WpNetworkResponse, RequestExecutionError>,
+    ) -> Option<Duration> {
+        let max_backoff = Duration::from_millis(self.max_backoff_ms);
+        let retry_after = match result {
+            Ok(response) if self.retryable_status_codes.contains(&response.status_code) => {
+                retry_after(response)
+            }
+            Ok(_) => return None,
+            // Retrying won't help if the request was cancelled or if the host is misconfigured
+            Err(RequestExecutionError::RequestCancelled { .. })
+            | Err(RequestExecutionError::HostNotFound { .. })
+            | Err(RequestExecutionError::InvalidSslCertificate { .. })
+            | Err(RequestExecutionError::TooManyRedirects { .. }) => return None,
+            Err(_) => None,
+        };
+        match retry_after {
+            Some(retry_after) => (retry_after <= max_backoff).then_some(retry_after),
+            None => Some(self.backoff(retry).min(max_backoff)),
+        }
+    }
+
+    fn backoff(&self, retry: u32) -> Duration {
+        let backoff_ms = self.initial_backoff_ms as f64
+            * self.backoff_multiplier.powi(retry.saturating_sub(1) as i32);
+        let backoff_ms = backoff_ms.min(self.max_backoff_ms as f64);
+        let backoff_ms = if self.jitter {
+            backoff_ms * (0.5 + fastrand::f64() * 0.5)
+        } else {
+            backoff_ms
+        };
+        Duration::from_millis(backoff_ms as u64)
+    }
+}
+
+#[uniffi::export]
+fn default_retry_policy() -> RetryPolicy {
+    RetryPolicy::default()
+}
+
+// `Retry-After` is either a number of seconds or an HTTP date.
+fn retry_after(response: &WpNetworkResponse) -> Option<Duration> {
+    let value = response.get_header(RETRY_AFTER_HEADER_KEY)?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    httpdate::parse_http_date(value).ok().map(|date| {
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO)
+    })
+}
+
+// Wraps a `RequestExecutor` to retry requests that fail with a transient error, such as a
+// `503 Service Unavailable` response or a `RequestExecutionError`, as described by the
+// `RetryPolicy`.
+#[derive(Debug, uniffi::Object)]
+pub struct RetryingRequestExecutor {
+    inner: Arc<dyn RequestExecutor>,
+    retry_policy: RetryPolicy,
+}
+
+#[uniffi::export]
+impl RetryingRequestExecutor {
+    #[uniffi::constructor]
+    pub fn new(inner: Arc<dyn RequestExecutor>, retry_policy: RetryPolicy) -> Self {
+        Self {
+            inner,
+            retry_policy,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestExecutor for RetryingRequestExecutor {
+    async fn execute(
+        &self,
+        request: Arc<WpNetworkRequest>,
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        let should_retry_request = self.retry_policy.should_retry_request(&request);
+        let mut attempt = 1;
+        loop {
+            let result = self.inner.execute(request.clone()).await;
+            if !should_retry_request || attempt >= self.retry_policy.max_attempts {
+                return result;
+            }
+            match self.retry_policy.retry_delay(attempt, &result) {
+                Some(delay) => {
+                    if !delay.is_zero() {
+                        futures_timer::Delay::new(delay).await;
+                    }
+                    attempt += 1;
+                }
+                None => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::{endpoint::WpEndpointUrl, WpNetworkHeaderMap};
+    use http::{HeaderMap, HeaderValue};
+    use rstest::*;
+    use std::sync::Mutex;
+
+    // Returns the given results in order, then `200` responses
+    #[derive(Debug)]
+    struct TestRequestExecutor {
+        results: Mutex<Vec<Result<WpNetworkResponse, RequestExecutionError>>>,
+        attempts: Mutex<u32>,
+    }
+
+    impl TestRequestExecutor {
+        fn new(mut results: Vec<Result<WpNetworkResponse, RequestExecutionError>>) -> Self {
+            results.reverse();
+            Self {
+                results: Mutex::new(results),
+                attempts: Mutex::new(0),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl RequestExecutor for TestRequestExecutor {
+        async fn execute(
+            &self,
+            _request: Arc<WpNetworkRequest>,
+        ) -> Result<WpNetworkResponse, RequestExecutionError> {
+            *self.attempts.lock().unwrap() += 1;
+            self.results
+                .lock()
+                .unwrap()
+                .pop()
+                .unwrap_or_else(|| Ok(response(200, &[])))
+        }
+    }
+
+    #[rstest]
+    #[case(RequestMethod::GET, vec![Ok(response(503, &[])), Ok(response(429, &[]))], 200, 3)]
+    #[case(RequestMethod::GET, vec![Err(execution_error())], 200, 2)]
+    #[case(RequestMethod::GET, vec![Err(RequestExecutionError::RequestTimedOut { reason: "".to_string() })], 200, 2)]
+    #[case(RequestMethod::GET, (0..3).map(|_| Ok(response(503, &[]))).collect(), 503, 3)]
+    #[case(RequestMethod::GET, vec![Ok(response(404, &[]))], 404, 1)]
+    #[case(RequestMethod::POST, vec![Ok(response(503, &[]))], 503, 1)]
+    #[case(RequestMethod::GET, vec![Ok(response(503, &[("Retry-After", "120")]))], 503, 1)]
+    fn retry_requests(
+        #[case] method: RequestMethod,
+        #[case] results: Vec<Result<WpNetworkResponse, RequestExecutionError>>,
+        #[case] expected_status_code: u16,
+        #[case] expected_attempts: u32,
+    ) {
+        let inner = Arc::new(TestRequestExecutor::new(results));
+        let executor = RetryingRequestExecutor::new(inner.clone(), test_retry_policy());
+        let response = futures::executor::block_on(executor.execute(Arc::new(request(method))));
+        assert_eq!(response.unwrap().status_code, expected_status_code);
+        assert_eq!(*inner.attempts.lock().unwrap(), expected_attempts);
+    }
+
+    #[rstest]
+    #[case(RequestExecutionError::RequestCancelled { reason: "".to_string() })]
+    #[case(RequestExecutionError::InvalidSslCertificate { reason: "".to_string() })]
+    fn dont_retry_permanent_errors(#[case] error: RequestExecutionError) {
+        let inner = Arc::new(TestRequestExecutor::new(vec![Err(error.clone())]));
+        let executor = RetryingRequestExecutor::new(inner.clone(), test_retry_policy());
+        let response =
+            futures::executor::block_on(executor.execute(Arc::new(request(RequestMethod::GET))));
+        assert_eq!(response.err(), Some(error));
+        assert_eq!(*inner.attempts.lock().unwrap(), 1);
+    }
+
+    #[rstest]
+    fn retry_non_idempotent_requests() {
+        let inner = Arc::new(TestRequestExecutor::new(vec![Ok(response(503, &[]))]));
+        let executor = RetryingRequestExecutor::new(
+            inner.clone(),
+            RetryPolicy {
+                retry_non_idempotent_requests: true,
+                ..test_retry_policy()
+            },
+        );
+        let response =
+            futures::executor::block_on(executor.execute(Arc::new(request(RequestMethod::POST))));
+        assert_eq!(response.unwrap().status_code, 200);
+        assert_eq!(*inner.attempts.lock().unwrap(), 2);
+    }
+
+    #[rstest]
+    #[case(1, 100)]
+    #[case(2, 200)]
+    #[case(3, 400)]
+    #[case(4, 500)]
+    fn exponential_backoff(#[case] retry: u32, #[case] expected_backoff_ms: u64) {
+        let retry_policy = RetryPolicy {
+            initial_backoff_ms: 100,
+            max_backoff_ms: 500,
+            jitter: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            retry_policy.retry_delay(retry, &Ok(response(503, &[]))),
+            Some(Duration::from_millis(expected_backoff_ms))
+        );
+    }
+
+    #[rstest]
+    fn backoff_with_jitter() {
+        let retry_policy = RetryPolicy {
+            initial_backoff_ms: 1000,
+            ..Default::default()
+        };
+        let backoff = retry_policy.backoff(1);
+        assert!(backoff >= Duration::from_millis(500) && backoff <= Duration::from_millis(1000));
+    }
+
+    #[rstest]
+    #[case("7", Some(Duration::from_secs(7)))]
+    #[case("Wed, 21 Oct 2015 07:28:00 GMT", Some(Duration::ZERO))]
+    #[case("soon", None)]
+    fn parse_retry_after(#[case] value: &'static str, #[case] expected: Option<Duration>) {
+        assert_eq!(
+            retry_after(&response(503, &[(RETRY_AFTER_HEADER_KEY, value)])),
+            expected
+        );
+    }
+
+    fn test_retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            initial_backoff_ms: 0,
+            max_backoff_ms: 1000,
+            ..Default::default()
+        }
+    }
+
+    fn execution_error() -> RequestExecutionError {
+        RequestExecutionError::RequestExecutionFailed {
+            status_code: None,
+            reason: "Connection reset".to_string(),
+        }
+    }
+
+    fn request(method: RequestMethod) -> WpNetworkRequest {
+        WpNetworkRequest {
+            method,
+            url: WpEndpointUrl("https://example.com/wp-json/wp/v2/posts".to_string()),
+            header_map: WpNetworkHeaderMap::default().into(),
+            body: None,
+            follow_redirects: true,
+        }
+    }
+
+    fn response(status_code: u16, headers: &[(&'static str, &'static str)]) -> WpNetworkResponse {
+        let mut header_map = HeaderMap::new();
+        headers.iter().for_each(|(name, value)| {
+            header_map.insert(*name, HeaderValue::from_static(value));
+        });
+        WpNetworkResponse {
+            body: Vec::new(),
+            status_code,
+            header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
+        }
+    }
+}
@@ -0,0 +1,757 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use regex::Regex;
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{links::WpLinks, request::RequestMethod};
+
+// The description of a REST route, as returned by an `OPTIONS` request to the route, or as part of
+// a route index such as `/wp-json/wp/v2`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct WpRoute {
+    pub namespace: String,
+    // The allowed methods, such as `GET`, `POST`, `PUT`, `PATCH` & `DELETE`
+    #[serde(default)]
+    pub methods: Vec<String>,
+    #[serde(default)]
+    pub endpoints: Vec<WpRouteEndpoint>,
+    // The schema of the route's resources. Route indexes only include it for `context=help`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<WpRouteSchema>,
+    // Only the routes without path parameters have links, such as a `self` link to `/wp/v2/posts`
+    #[serde(
+        rename = "_links",
+        default,
+        deserialize_with = "deserialize_route_links",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub links: Option<WpLinks>,
+}
+
+impl WpRoute {
+    pub fn allows_method(&self, method: &RequestMethod) -> bool {
+        self.methods
+            .iter()
+            .any(|m| m.eq_ignore_ascii_case(method.as_str()))
+    }
+
+    // The arguments of the endpoint handling the given method
+    pub fn args(&self, method: &RequestMethod) -> Option<&HashMap<String, WpRouteArg>> {
+        self.endpoints
+            .iter()
+            .find(|endpoint| endpoint.allows_method(method))
+            .map(|endpoint| &endpoint.args)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct WpRouteEndpoint {
+    #[serde(default)]
+    pub methods: Vec<String>,
+    #[serde(default, deserialize_with = "deserialize_map_or_empty_array")]
+    pub args: HashMap<String, WpRouteArg>,
+}
+
+impl WpRouteEndpoint {
+    pub fn allows_method(&self, method: &RequestMethod) -> bool {
+        self.methods
+            .iter()
+            .any(|m| m.eq_ignore_ascii_case(method.as_str()))
+    }
+
+    pub fn required_args(&self) -> Vec<&str> {
+        let mut required_args = self
+            .args
+            .iter()
+            .filter(|(_, arg)| arg.required)
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        required_args.sort();
+        required_args
+    }
+}
+
+// An argument of an endpoint, such as the `status` of `POST /wp/v2/posts`.
+//
+// `default` & `enum_values` can have any JSON type, so string values are kept as they are and other
+// values are JSON encoded: `"publish"` is `publish`, but `[1, 2]` is `[1,2]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct WpRouteArg {
+    #[serde(default)]
+    pub description: Option<String>,
+    // A list, because an argument can accept several types, such as `["string", "null"]`
+    #[serde(
+        rename = "type",
+        default,
+        deserialize_with = "deserialize_json_schema_types"
+    )]
+    pub types: Vec<String>,
+    #[serde(default)]
+    pub format: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_json_value_as_string"
+    )]
+    pub default: Option<String>,
+    #[serde(
+        rename = "enum",
+        default,
+        deserialize_with = "deserialize_json_values_as_strings"
+    )]
+    pub enum_values: Vec<String>,
+    // The types of the items of `array` arguments
+    #[serde(
+        rename = "items",
+        default,
+        deserialize_with = "deserialize_item_types",
+        serialize_with = "serialize_item_types",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub item_types: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct WpRouteSchema {
+    #[serde(rename = "$schema", default)]
+    pub schema_url: Option<String>,
+    // The name of the resource, such as `post`
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(
+        rename = "type",
+        default,
+        deserialize_with = "deserialize_json_schema_types"
+    )]
+    pub types: Vec<String>,
+    // The fields of the resource, including the ones registered by plugins
+    #[serde(default, deserialize_with = "deserialize_map_or_empty_array")]
+    pub properties: HashMap<String, WpSchemaProperty>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct WpSchemaProperty {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(
+        rename = "type",
+        default,
+        deserialize_with = "deserialize_json_schema_types"
+    )]
+    pub types: Vec<String>,
+    #[serde(default)]
+    pub format: Option<String>,
+    // The contexts the field is included in, such as `view`, `edit` & `embed`
+    #[serde(default)]
+    pub context: Vec<String>,
+    #[serde(default)]
+    pub readonly: bool,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_json_value_as_string"
+    )]
+    pub default: Option<String>,
+    #[serde(
+        rename = "enum",
+        default,
+        deserialize_with = "deserialize_json_values_as_strings"
+    )]
+    pub enum_values: Vec<String>,
+    #[serde(
+        rename = "items",
+        default,
+        deserialize_with = "deserialize_item_types",
+        serialize_with = "serialize_item_types",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub item_types: Vec<String>,
+}
+
+// A route index, such as `/wp-json/wp/v2`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct WpNamespaceIndex {
+    pub namespace: String,
+    #[serde(default)]
+    pub routes: WpRoutes,
+}
+
+impl WpNamespaceIndex {
+    pub fn find_route(&self, route: &str) -> Option<&WpRoute> {
+        self.routes.find(route)
+    }
+
+    pub fn supports_route(&self, route: &str, method: &RequestMethod) -> bool {
+        self.find_route(route)
+            .is_some_and(|route| route.allows_method(method))
+    }
+}
+
+// The routes of a route index, keyed by their pattern, such as `/wp/v2/posts/(?P<id>[\d]+)`.
+//
+// WordPress matches a path against the patterns in the order the routes are registered, so that
+// order is kept along with the patterns, which are compiled the first time they are matched against.
+// Foreign languages get the routes as a list of
+// `WpRouteEntry`s in that order.
+#[derive(Debug, Clone, Default)]
+pub struct WpRoutes {
+    routes: HashMap<String, WpRoute>,
+    // Patterns that aren't supported by the `regex` crate, such as look-arounds, don't have a regex
+    // and never match a path
+    patterns: Vec<(String, OnceLock<Option<Regex>>)>,
+}
+
+impl WpRoutes {
+    fn from_ordered_routes(routes: impl IntoIterator<Item = (String, WpRoute)>) -> Self {
+        let mut wp_routes = Self::default();
+        for (pattern, route) in routes {
+            if wp_routes.routes.insert(pattern.clone(), route).is_none() {
+                wp_routes.patterns.push((pattern, OnceLock::new()));
+            }
+        }
+        wp_routes
+    }
+
+    pub fn get(&self, pattern: &str) -> Option<&WpRoute> {
+        self.routes.get(pattern)
+    }
+
+    pub fn contains_key(&self, pattern: &str) -> bool {
+        self.routes.contains_key(pattern)
+    }
+
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    // The patterns & their routes, in the order they are registered
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &WpRoute)> {
+        self.patterns
+            .iter()
+            .filter_map(|(pattern, _)| self.routes.get_key_value(pattern))
+    }
+
+    // Finds the route for the given path.
+    //
+    // Both a pattern, such as `/wp/v2/posts/(?P<id>[\d]+)`, and a matching path, such as
+    // `/wp/v2/posts/1`, can be used. Like WordPress, the paths are matched case-insensitively and
+    // the first registered route that matches is used.
+    pub fn find(&self, route: &str) -> Option<&WpRoute> {
+        let route = format!("/{}", route.trim_matches('/'));
+        if let Some(r) = self.routes.get(&route) {
+            return Some(r);
+        }
+        self.patterns
+            .iter()
+            .find(|(pattern, regex)| {
+                regex
+                    .get_or_init(|| Regex::new(&format!("(?i)^{}$", pattern)).ok())
+                    .as_ref()
+                    .is_some_and(|regex| regex.is_match(&route))
+            })
+            .and_then(|(pattern, _)| self.routes.get(pattern))
+    }
+}
+
+impl PartialEq for WpRoutes {
+    fn eq(&self, other: &Self) -> bool {
+        self.routes == other.routes
+    }
+}
+
+impl std::ops::Index<&str> for WpRoutes {
+    type Output = WpRoute;
+
+    fn index(&self, pattern: &str) -> &Self::Output {
+        &self.routes[pattern]
+    }
+}
+
+impl Serialize for WpRoutes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
+
+// Routes can be registered by any plugin, so a route that can't be parsed is skipped instead of
+// failing the parsing of the whole route index. Like other maps, an empty route index is encoded as
+// `[]` by PHP.
+impl<'de> Deserialize<'de> for WpRoutes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct WpRoutesVisitor;
+
+        impl<'de> Visitor<'de> for WpRoutesVisitor {
+            type Value = WpRoutes;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map of routes or an empty array")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                // The entries are visited one by one, since `serde_json::Map` doesn't keep their
+                // order
+                let mut routes = Vec::new();
+                while let Some((pattern, value)) = map.next_entry::<String, serde_json::Value>()? {
+                    if let Ok(route) = serde_json::from_value(value) {
+                        routes.push((pattern, route));
+                    }
+                }
+                Ok(WpRoutes::from_ordered_routes(routes))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                match seq.next_element::<serde::de::IgnoredAny>()? {
+                    None => Ok(WpRoutes::default()),
+                    Some(_) => Err(serde::de::Error::custom("expected a map or an empty array")),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(WpRoutesVisitor)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct WpRouteEntry {
+    pub pattern: String,
+    pub route: WpRoute,
+}
+
+// `uniffi` maps don't keep the order of their entries, so the routes are passed as a list instead.
+uniffi::custom_type!(WpRoutes, Vec<WpRouteEntry>);
+
+impl crate::UniffiCustomTypeConverter for WpRoutes {
+    type Builtin = Vec<WpRouteEntry>;
+
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Ok(Self::from_ordered_routes(
+            val.into_iter().map(|entry| (entry.pattern, entry.route)),
+        ))
+    }
+
+    fn from_custom(mut obj: Self) -> Self::Builtin {
+        obj.patterns
+            .into_iter()
+            .filter_map(|(pattern, _)| {
+                let route = obj.routes.remove(&pattern)?;
+                Some(WpRouteEntry { pattern, route })
+            })
+            .collect()
+    }
+}
+
+// Older WordPress versions use a single link instead of a list of links, such as
+// `"self": "https://example.com/wp-json/wp/v2/posts"`, so these are converted to lists first. Links
+// that still can't be parsed are ignored.
+fn deserialize_route_links<'de, D>(deserializer: D) -> Result<Option<WpLinks>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(serde_json::Value::Object(links)) =
+        Option::<serde_json::Value>::deserialize(deserializer)?
+    else {
+        return Ok(None);
+    };
+    let links = links
+        .into_iter()
+        .map(|(relation, value)| {
+            let value = match value {
+                serde_json::Value::String(href) => serde_json::json!([{ "href": href }]),
+                serde_json::Value::Object(_) => serde_json::Value::Array(vec![value]),
+                value => value,
+            };
+            (relation, value)
+        })
+        .collect();
+    Ok(serde_json::from_value(serde_json::Value::Object(links)).ok())
+}
+
+// PHP encodes empty associative arrays as `[]`, such as the `args` of an endpoint without any
+pub(crate) fn deserialize_map_or_empty_array<'de, D, T>(
+    deserializer: D,
+) -> Result<HashMap<String, T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum MapOrArray<T> {
+        Map(HashMap<String, T>),
+        Array(Vec<serde::de::IgnoredAny>),
+    }
+    match MapOrArray::deserialize(deserializer)? {
+        MapOrArray::Map(map) => Ok(map),
+        MapOrArray::Array(array) if array.is_empty() => Ok(HashMap::new()),
+        MapOrArray::Array(_) => Err(serde::de::Error::custom("expected a map or an empty array")),
+    }
+}
+
+// JSON Schema allows both `"type": "string"` & `"type": ["string", "null"]`
+fn deserialize_json_schema_types<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        Some(OneOrMany::One(t)) => vec![t],
+        Some(OneOrMany::Many(types)) => types,
+        None => Vec::new(),
+    })
+}
+
+fn deserialize_item_types<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Items {
+        #[serde(
+            rename = "type",
+            default,
+            deserialize_with = "deserialize_json_schema_types"
+        )]
+        types: Vec<String>,
+    }
+    Ok(Option::<Items>::deserialize(deserializer)?
+        .map(|items| items.types)
+        .unwrap_or_default())
+}
+
+fn serialize_item_types<S>(item_types: &[String], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct Items<'a> {
+        #[serde(rename = "type")]
+        types: &'a [String],
+    }
+    Items { types: item_types }.serialize(serializer)
+}
+
+fn deserialize_optional_json_value_as_string<'de, D>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<serde_json::Value>::deserialize(deserializer)?.map(json_value_as_string))
+}
+
+fn deserialize_json_values_as_strings<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Vec<serde_json::Value>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(json_value_as_string)
+        .collect())
+}
+
+fn json_value_as_string(value: serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s,
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::links::WpLinkRelation;
+    use rstest::*;
+
+    #[rstest]
+    fn parse_route_options() {
+        let route: WpRoute = serde_json::from_str(POSTS_ROUTE_JSON).unwrap();
+        assert_eq!(route.namespace, "wp/v2");
+        assert!(route.allows_method(&RequestMethod::GET));
+        assert!(route.allows_method(&RequestMethod::POST));
+        assert!(!route.allows_method(&RequestMethod::DELETE));
+
+        let list_args = route.args(&RequestMethod::GET).unwrap();
+        let context = &list_args["context"];
+        assert_eq!(context.types, vec!["string"]);
+        assert_eq!(context.default.as_deref(), Some("view"));
+        assert_eq!(context.enum_values, vec!["view", "embed", "edit"]);
+        assert_eq!(list_args["per_page"].default.as_deref(), Some("10"));
+        assert_eq!(list_args["author"].item_types, vec!["integer"]);
+
+        assert_eq!(route.endpoints[1].required_args(), vec!["title"]);
+
+        let schema = route.schema.unwrap();
+        assert_eq!(schema.title.as_deref(), Some("post"));
+        let date = &schema.properties["date"];
+        assert_eq!(date.types, vec!["string", "null"]);
+        assert_eq!(date.format.as_deref(), Some("date-time"));
+        assert_eq!(date.context, vec!["view", "edit", "embed"]);
+        assert!(schema.properties["id"].readonly);
+        // Fields registered by plugins are included
+        assert_eq!(
+            schema.properties["plugin_field"].description.as_deref(),
+            Some("A field added by a plugin.")
+        );
+    }
+
+    #[rstest]
+    fn parse_endpoint_without_args() {
+        let endpoint: WpRouteEndpoint =
+            serde_json::from_str(r#"{"methods": ["GET"], "args": []}"#).unwrap();
+        assert!(endpoint.args.is_empty());
+        assert!(
+            serde_json::from_str::<WpRouteEndpoint>(r#"{"methods": ["GET"], "args": [1]}"#)
+                .is_err()
+        );
+    }
+
+    #[rstest]
+    #[case("/wp/v2/posts", Some("/wp/v2/posts"))]
+    #[case("wp/v2/posts/", Some("/wp/v2/posts"))]
+    #[case("/wp/v2/posts/1", Some("/wp/v2/posts/(?P<id>[\\d]+)"))]
+    #[case("/WP/V2/POSTS/1", Some("/wp/v2/posts/(?P<id>[\\d]+)"))]
+    #[case("/wp/v2/posts/(?P<id>[\\d]+)", Some("/wp/v2/posts/(?P<id>[\\d]+)"))]
+    #[case("/wp/v2/posts/foo", None)]
+    #[case("/wp/v2/posts/1/revisions", None)]
+    #[case("/wp/v2/pages", None)]
+    fn find_route_in_index(#[case] route: &str, #[case] expected_pattern: Option<&str>) {
+        let index: WpNamespaceIndex = serde_json::from_str(NAMESPACE_INDEX_JSON).unwrap();
+        let expected_route = expected_pattern.map(|pattern| &index.routes[pattern]);
+        assert_eq!(index.find_route(route), expected_route);
+    }
+
+    #[rstest]
+    #[case(&["(?P<id>[0-9]+)", "(?P<slug>[a-z0-9-]+)"], "/my-plugin/v1/items/(?P<id>[0-9]+)")]
+    #[case(&["(?P<slug>[a-z0-9-]+)", "(?P<id>[0-9]+)"], "/my-plugin/v1/items/(?P<slug>[a-z0-9-]+)")]
+    fn find_first_registered_route_for_overlapping_patterns(
+        #[case] parameters: &[&str],
+        #[case] expected_pattern: &str,
+    ) {
+        let routes = parameters
+            .iter()
+            .map(|parameter| {
+                format!(
+                    r#""/my-plugin/v1/items/{}": {{ "namespace": "my-plugin/v1", "methods": ["GET"], "endpoints": [] }}"#,
+                    parameter
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let index: WpNamespaceIndex = serde_json::from_str(&format!(
+            r#"{{ "namespace": "my-plugin/v1", "routes": {{ {} }} }}"#,
+            routes
+        ))
+        .unwrap();
+        assert_eq!(
+            index.find_route("/my-plugin/v1/items/1"),
+            Some(&index.routes[expected_pattern])
+        );
+        // The routes are kept in the order they are registered
+        assert_eq!(
+            index
+                .routes
+                .iter()
+                .map(|(pattern, _)| pattern.as_str())
+                .collect::<Vec<_>>(),
+            parameters
+                .iter()
+                .map(|parameter| format!("/my-plugin/v1/items/{}", parameter))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    fn supports_route() {
+        let index: WpNamespaceIndex = serde_json::from_str(NAMESPACE_INDEX_JSON).unwrap();
+        assert!(index.supports_route("/wp/v2/posts", &RequestMethod::POST));
+        assert!(index.supports_route("/wp/v2/posts/1", &RequestMethod::DELETE));
+        assert!(!index.supports_route("/wp/v2/posts", &RequestMethod::DELETE));
+        assert!(!index.supports_route("/wp/v2/pages", &RequestMethod::GET));
+    }
+
+    #[rstest]
+    fn parse_route_index_links() {
+        let index: WpNamespaceIndex = serde_json::from_str(NAMESPACE_INDEX_JSON).unwrap();
+        assert_eq!(
+            index.routes["/wp/v2/posts"]
+                .links
+                .as_ref()
+                .and_then(|links| links.first(&WpLinkRelation::SelfLink))
+                .map(|link| link.href.as_str()),
+            Some("https://example.com/wp-json/wp/v2/posts")
+        );
+        assert!(index.routes["/wp/v2/posts/(?P<id>[\\d]+)"].links.is_none());
+        // Older WordPress versions use a single link instead of a list
+        assert_eq!(
+            index.routes["/wp/v2/categories"]
+                .links
+                .as_ref()
+                .and_then(|links| links.first(&WpLinkRelation::SelfLink))
+                .map(|link| link.href.as_str()),
+            Some("https://example.com/wp-json/wp/v2/categories")
+        );
+    }
+
+    #[rstest]
+    fn routes_that_cant_be_parsed_are_skipped() {
+        let index: WpNamespaceIndex = serde_json::from_str(NAMESPACE_INDEX_JSON).unwrap();
+        assert!(!index.routes.contains_key("/my-plugin/v1/broken"));
+        assert_eq!(index.routes.len(), 3);
+    }
+
+    #[rstest]
+    fn routes_keep_their_order_across_ffi() {
+        use crate::UniffiCustomTypeConverter;
+        let index: WpNamespaceIndex = serde_json::from_str(NAMESPACE_INDEX_JSON).unwrap();
+        let entries = WpRoutes::from_custom(index.routes.clone());
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| entry.pattern.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "/wp/v2/posts",
+                "/wp/v2/posts/(?P<id>[\\d]+)",
+                "/wp/v2/categories"
+            ]
+        );
+        let routes = WpRoutes::into_custom(entries).unwrap();
+        assert_eq!(routes, index.routes);
+        assert!(routes
+            .iter()
+            .map(|(pattern, _)| pattern)
+            .eq(index.routes.iter().map(|(pattern, _)| pattern)));
+    }
+
+    #[rstest]
+    fn serialize_route() {
+        let route: WpRoute = serde_json::from_str(POSTS_ROUTE_JSON).unwrap();
+        let serialized = serde_json::to_string(&route).unwrap();
+        assert_eq!(serde_json::from_str::<WpRoute>(&serialized).unwrap(), route);
+    }
+
+    const NAMESPACE_INDEX_JSON: &str = r#"{
+        "namespace": "wp/v2",
+        "routes": {
+            "/wp/v2/posts": {
+                "namespace": "wp/v2",
+                "methods": ["GET", "POST"],
+                "endpoints": [
+                    { "methods": ["GET"], "args": [] },
+                    { "methods": ["POST"], "args": [] }
+                ],
+                "_links": { "self": [{ "href": "https://example.com/wp-json/wp/v2/posts" }] }
+            },
+            "/wp/v2/posts/(?P<id>[\\d]+)": {
+                "namespace": "wp/v2",
+                "methods": ["GET", "POST", "PUT", "PATCH", "DELETE"],
+                "endpoints": [
+                    { "methods": ["GET"], "args": {} },
+                    { "methods": ["POST", "PUT", "PATCH"], "args": {} },
+                    { "methods": ["DELETE"], "args": {} }
+                ]
+            },
+            "/wp/v2/categories": {
+                "namespace": "wp/v2",
+                "methods": ["GET"],
+                "endpoints": [{ "methods": ["GET"], "args": {} }],
+                "_links": { "self": "https://example.com/wp-json/wp/v2/categories" }
+            },
+            "/my-plugin/v1/broken": {
+                "namespace": "my-plugin/v1",
+                "methods": "GET"
+            }
+        }
+    }"#;
+
+    const POSTS_ROUTE_JSON: &str = r#"{
+        "namespace": "wp/v2",
+        "methods": ["GET", "POST"],
+        "endpoints": [
+            {
+                "methods": ["GET"],
+                "allow_batch": { "v1": true },
+                "args": {
+                    "context": {
+                        "description": "Scope under which the request is made; determines fields present in response.",
+                        "type": "string",
+                        "enum": ["view", "embed", "edit"],
+                        "default": "view",
+                        "required": false
+                    },
+                    "per_page": {
+                        "type": "integer",
+                        "default": 10,
+                        "minimum": 1,
+                        "maximum": 100,
+                        "required": false
+                    },
+                    "author": {
+                        "type": "array",
+                        "items": { "type": "integer" },
+                        "default": [],
+                        "required": false
+                    }
+                }
+            },
+            {
+                "methods": ["POST"],
+                "allow_batch": { "v1": true },
+                "args": {
+                    "title": { "type": "object", "required": true },
+                    "status": {
+                        "type": "string",
+                        "enum": ["publish", "future", "draft", "pending", "private"],
+                        "required": false
+                    }
+                }
+            }
+        ],
+        "schema": {
+            "$schema": "http://json-schema.org/draft-04/schema#",
+            "title": "post",
+            "type": "object",
+            "properties": {
+                "date": {
+                    "description": "The date the post was published, in the site's timezone.",
+                    "type": ["string", "null"],
+                    "format": "date-time",
+                    "context": ["view", "edit", "embed"]
+                },
+                "id": {
+                    "description": "Unique identifier for the post.",
+                    "type": "integer",
+                    "context": ["view", "edit", "embed"],
+                    "readonly": true
+                },
+                "plugin_field": {
+                    "description": "A field added by a plugin.",
+                    "type": "string",
+                    "context": ["view", "edit"]
+                }
+            }
+        }
+    }"#;
+}
@@ -0,0 +1,421 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    login::{UrlDiscoveryError, WpApiDetails, WpLoginClient},
+    request::{RequestExecutor, RequestMethod},
+    ParsedUrl,
+};
+
+const NAMESPACE_BLOCK_EDITOR: &str = "wp-block-editor/v1";
+const NAMESPACE_SITE_HEALTH: &str = "wp-site-health/v1";
+const NAMESPACE_WOOCOMMERCE: &str = "wc/v3";
+
+// The routes WordPress added in each version, ordered by version, along with a method each route
+// accepts. Sites don't expose their version through the REST API, so it's estimated from which of
+// these routes are in the route index.
+const VERSION_ROUTES: &[(WpVersion, &str, RequestMethod)] = &[
+    (WpVersion::new(4, 7), "/wp/v2/posts", RequestMethod::GET),
+    (WpVersion::new(5, 0), "/wp/v2/blocks", RequestMethod::GET),
+    // `post-process` & `batch` only accept `POST` requests
+    (
+        WpVersion::new(5, 3),
+        "/wp/v2/media/1/post-process",
+        RequestMethod::POST,
+    ),
+    (WpVersion::new(5, 5), "/wp/v2/plugins", RequestMethod::GET),
+    (WpVersion::new(5, 6), "/batch/v1", RequestMethod::POST),
+    (WpVersion::new(5, 8), "/wp/v2/widgets", RequestMethod::GET),
+    (
+        WpVersion::new(5, 9),
+        "/wp/v2/navigation",
+        RequestMethod::GET,
+    ),
+    (
+        WpVersion::new(6, 3),
+        "/wp/v2/global-styles/1/revisions",
+        RequestMethod::GET,
+    ),
+    (
+        WpVersion::new(6, 5),
+        "/wp/v2/font-families",
+        RequestMethod::GET,
+    ),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, uniffi::Record)]
+pub struct WpVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl WpVersion {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl Display for WpVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+// The estimated WordPress version of a site: at least `min` and below `max_exclusive`.
+//
+// Either bound is `None` if it can't be estimated, for example `max_exclusive` for sites running
+// the latest version. Plugins such as Gutenberg can register the routes of upcoming versions early,
+// so the estimate can be higher than the actual version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Record)]
+pub struct WpVersionRange {
+    pub min: Option<WpVersion>,
+    pub max_exclusive: Option<WpVersion>,
+}
+
+impl WpVersionRange {
+    fn from_api_details(api_details: &WpApiDetails) -> Self {
+        if api_details.routes.is_empty() {
+            return Self {
+                min: None,
+                max_exclusive: None,
+            };
+        }
+        let supports_route = |route: &str, method: &RequestMethod| {
+            api_details.supports_route(route.to_string(), method.clone())
+        };
+        let min = VERSION_ROUTES
+            .iter()
+            .filter(|(_, route, method)| supports_route(route, method))
+            .map(|(version, _, _)| *version)
+            .max();
+        let max_exclusive = VERSION_ROUTES
+            .iter()
+            // `Option::is_none_or` isn't available in Rust 1.80
+            .filter(|(version, _, _)| match min {
+                Some(min) => *version > min,
+                None => true,
+            })
+            .find(|(_, route, method)| !supports_route(route, method))
+            .map(|(version, _, _)| *version);
+        Self { min, max_exclusive }
+    }
+}
+
+// What a site supports, based on its API root, such as the namespaces of its plugins.
+#[derive(Debug, uniffi::Object)]
+pub struct SiteCapabilities {
+    api_details: Arc<WpApiDetails>,
+    wordpress_version: WpVersionRange,
+}
+
+#[uniffi::export]
+impl SiteCapabilities {
+    #[uniffi::constructor]
+    pub fn new(api_details: Arc<WpApiDetails>) -> Self {
+        Self {
+            wordpress_version: WpVersionRange::from_api_details(&api_details),
+            api_details,
+        }
+    }
+
+    pub fn api_details(&self) -> Arc<WpApiDetails> {
+        self.api_details.clone()
+    }
+
+    pub fn wordpress_version(&self) -> WpVersionRange {
+        self.wordpress_version
+    }
+
+    // Whether the site is known to run at least the given version, such as `5.6` for the error
+    // codes of application passwords
+    pub fn is_at_least(&self, version: WpVersion) -> bool {
+        self.wordpress_version.min.is_some_and(|min| min >= version)
+    }
+
+    pub fn namespaces(&self) -> Vec<String> {
+        self.api_details.namespaces.clone()
+    }
+
+    pub fn has_namespace(&self, namespace: String) -> bool {
+        self.api_details.namespaces.contains(&namespace)
+    }
+
+    pub fn has_block_editor(&self) -> bool {
+        self.has_namespace(NAMESPACE_BLOCK_EDITOR.to_string())
+    }
+
+    pub fn has_site_health(&self) -> bool {
+        self.has_namespace(NAMESPACE_SITE_HEALTH.to_string())
+    }
+
+    pub fn has_woocommerce(&self) -> bool {
+        self.has_namespace(NAMESPACE_WOOCOMMERCE.to_string())
+    }
+
+    // Application passwords can be disabled by a plugin or the `wp_is_application_passwords_available`
+    // filter, and they require HTTPS unless the site is in a local environment.
+    pub fn application_passwords_enabled(&self) -> bool {
+        self.api_details
+            .find_application_passwords_authentication_url()
+            .is_some()
+    }
+
+    pub fn supports_route(&self, route: String, method: RequestMethod) -> bool {
+        self.api_details.supports_route(route, method)
+    }
+}
+
+// Discovers the capabilities of sites using `WpLoginClient::api_discovery`, caching them for each
+// site url, so they are only fetched once.
+//
+// The capabilities of a site change when its plugins or its WordPress version change, so they
+// can be fetched again after `invalidate`.
+#[derive(Debug, uniffi::Object)]
+pub struct SiteCapabilitiesService {
+    login_client: WpLoginClient,
+    cache: Mutex<HashMap<ParsedUrl, Arc<SiteCapabilities>>>,
+}
+
+impl SiteCapabilitiesService {
+    pub fn with_login_client(login_client: WpLoginClient) -> Self {
+        Self {
+            login_client,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[uniffi::export]
+impl SiteCapabilitiesService {
+    #[uniffi::constructor]
+    pub fn new(request_executor: Arc<dyn RequestExecutor>) -> Self {
+        Self::with_login_client(WpLoginClient::new(request_executor))
+    }
+
+    pub async fn capabilities(
+        &self,
+        site_url: Arc<ParsedUrl>,
+    ) -> Result<Arc<SiteCapabilities>, UrlDiscoveryError> {
+        if let Some(capabilities) = self.cached_capabilities(site_url.clone()) {
+            return Ok(capabilities);
+        }
+        let url_discovery = self.login_client.api_discovery(site_url.url()).await?;
+        let capabilities = Arc::new(SiteCapabilities::new(url_discovery.api_details));
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(site_url.as_ref().clone(), capabilities.clone());
+        }
+        Ok(capabilities)
+    }
+
+    pub fn cached_capabilities(&self, site_url: Arc<ParsedUrl>) -> Option<Arc<SiteCapabilities>> {
+        self.cache
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(site_url.as_ref()).cloned())
+    }
+
+    pub fn invalidate(&self, site_url: Arc<ParsedUrl>) {
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.remove(site_url.as_ref());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api_error::RequestExecutionError,
+        request::{WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse},
+    };
+    use http::{HeaderMap, HeaderValue};
+    use rstest::*;
+
+    const GET: &[&str] = &["GET"];
+    const POST: &[&str] = &["POST"];
+    const GET_POST: &[&str] = &["GET", "POST"];
+
+    // The routes & their methods, as registered by WordPress 6.4
+    const WP_6_4_ROUTES: &[(&str, &[&str])] = &[
+        ("/", GET),
+        ("/batch/v1", POST),
+        ("/wp/v2/posts", GET_POST),
+        (
+            "/wp/v2/posts/(?P<id>[\\d]+)",
+            &["GET", "POST", "PUT", "PATCH", "DELETE"],
+        ),
+        ("/wp/v2/blocks", GET_POST),
+        ("/wp/v2/media/(?P<id>[\\d]+)/post-process", POST),
+        ("/wp/v2/plugins", GET_POST),
+        ("/wp/v2/widgets", GET_POST),
+        ("/wp/v2/navigation", GET_POST),
+        ("/wp/v2/global-styles/(?P<parent>[\\d]+)/revisions", GET),
+        ("/wp-site-health/v1/tests/background-updates", GET),
+    ];
+
+    // Serves the API root of `https://example.com`
+    #[derive(Debug)]
+    struct TestRequestExecutor {
+        api_details_json: String,
+        requests: Mutex<Vec<Arc<WpNetworkRequest>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl RequestExecutor for TestRequestExecutor {
+        async fn execute(
+            &self,
+            request: Arc<WpNetworkRequest>,
+        ) -> Result<WpNetworkResponse, RequestExecutionError> {
+            self.requests.lock().unwrap().push(request.clone());
+            let mut header_map = HeaderMap::new();
+            let body = match request.method {
+                RequestMethod::HEAD => {
+                    header_map.insert(
+                        "Link",
+                        HeaderValue::from_static(
+                            "<https://example.com/wp-json/>; rel=\"https://api.w.org/\"",
+                        ),
+                    );
+                    Vec::new()
+                }
+                _ => self.api_details_json.clone().into_bytes(),
+            };
+            Ok(WpNetworkResponse {
+                body,
+                status_code: 200,
+                header_map: WpNetworkHeaderMap::new(header_map).into(),
+            })
+        }
+    }
+
+    #[rstest]
+    #[case(WP_6_4_ROUTES, Some(WpVersion::new(6, 3)), Some(WpVersion::new(6, 5)))]
+    #[case(&[("/wp/v2/posts", GET_POST), ("/wp/v2/blocks", GET_POST)], Some(WpVersion::new(5, 0)), Some(WpVersion::new(5, 3)))]
+    #[case(&[("/wp/v2/posts", GET_POST), ("/batch/v1", POST)], Some(WpVersion::new(5, 6)), Some(WpVersion::new(5, 8)))]
+    #[case(&[("/wp/v2/posts", GET_POST), ("/batch/v1", GET)], Some(WpVersion::new(4, 7)), Some(WpVersion::new(5, 0)))]
+    #[case(&[("/wp/v2/posts", GET_POST), ("/wp/v2/font-families", GET_POST)], Some(WpVersion::new(6, 5)), None)]
+    #[case(&[("/oembed/1.0/embed", GET)], None, Some(WpVersion::new(4, 7)))]
+    #[case(&[], None, None)]
+    fn test_wordpress_version(
+        #[case] routes: &[(&str, &[&str])],
+        #[case] expected_min: Option<WpVersion>,
+        #[case] expected_max_exclusive: Option<WpVersion>,
+    ) {
+        assert_eq!(
+            capabilities(routes, &[], "[]").wordpress_version(),
+            WpVersionRange {
+                min: expected_min,
+                max_exclusive: expected_max_exclusive,
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_is_at_least() {
+        let capabilities = capabilities(WP_6_4_ROUTES, &[], "[]");
+        assert!(capabilities.is_at_least(WpVersion::new(5, 6)));
+        assert!(capabilities.is_at_least(WpVersion::new(6, 3)));
+        assert!(!capabilities.is_at_least(WpVersion::new(6, 5)));
+        assert!(!self::capabilities(&[], &[], "[]").is_at_least(WpVersion::new(4, 7)));
+    }
+
+    #[rstest]
+    fn test_namespaces() {
+        let capabilities = capabilities(
+            &[],
+            &["oembed/1.0", "wp/v2", "wp-site-health/v1", "wc/v3"],
+            "[]",
+        );
+        assert!(capabilities.has_site_health());
+        assert!(capabilities.has_woocommerce());
+        assert!(!capabilities.has_block_editor());
+        assert!(capabilities.has_namespace("oembed/1.0".to_string()));
+        assert!(!capabilities.has_namespace("oembed".to_string()));
+    }
+
+    #[rstest]
+    #[case(
+        r#"{"application-passwords": {"endpoints": {"authorization": "https://example.com/wp-admin/authorize-application.php"}}}"#,
+        true
+    )]
+    #[case("[]", false)]
+    #[case("{}", false)]
+    fn test_application_passwords_enabled(#[case] authentication: &str, #[case] expected: bool) {
+        assert_eq!(
+            capabilities(&[], &[], authentication).application_passwords_enabled(),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn capabilities_are_cached_per_site_url() {
+        let executor = Arc::new(TestRequestExecutor {
+            api_details_json: api_details_json(WP_6_4_ROUTES, &["wp/v2"], "[]"),
+            requests: Mutex::new(Vec::new()),
+        });
+        let service = SiteCapabilitiesService::new(executor.clone());
+        let site_url = Arc::new(ParsedUrl::parse("https://example.com").unwrap());
+        let fetch = || futures::executor::block_on(service.capabilities(site_url.clone()));
+        assert!(service.cached_capabilities(site_url.clone()).is_none());
+
+        let capabilities = fetch().unwrap();
+        assert_eq!(
+            capabilities.wordpress_version().min,
+            Some(WpVersion::new(6, 3))
+        );
+        assert_eq!(executor.requests.lock().unwrap().len(), 2);
+        assert!(Arc::ptr_eq(&fetch().unwrap(), &capabilities));
+        assert_eq!(executor.requests.lock().unwrap().len(), 2);
+
+        service.invalidate(site_url.clone());
+        assert!(service.cached_capabilities(site_url.clone()).is_none());
+        assert!(!Arc::ptr_eq(&fetch().unwrap(), &capabilities));
+        assert_eq!(executor.requests.lock().unwrap().len(), 4);
+    }
+
+    fn capabilities(
+        routes: &[(&str, &[&str])],
+        namespaces: &[&str],
+        authentication: &str,
+    ) -> SiteCapabilities {
+        SiteCapabilities::new(Arc::new(
+            serde_json::from_str(&api_details_json(routes, namespaces, authentication)).unwrap(),
+        ))
+    }
+
+    fn api_details_json(
+        routes: &[(&str, &[&str])],
+        namespaces: &[&str],
+        authentication: &str,
+    ) -> String {
+        let routes = routes
+            .iter()
+            .map(|(route, methods)| {
+                (
+                    route.to_string(),
+                    serde_json::json!({
+                        "namespace": route.split('/').nth(1).unwrap_or_default(),
+                        "methods": methods,
+                        "endpoints": [{ "methods": methods, "args": [] }]
+                    }),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+        serde_json::json!({
+            "name": "example",
+            "description": "",
+            "url": "https://example.com",
+            "home": "https://example.com",
+            "gmt_offset": 0,
+            "timezone_string": "",
+            "namespaces": namespaces,
+            "authentication": serde_json::from_str::<serde_json::Value>(authentication).unwrap(),
+            "site_icon_url": null,
+            "routes": routes,
+        })
+        .to_string()
+    }
+}
@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+
+use crate::{
+    impl_as_query_value_from_as_str, impl_pagination_params,
+    posts::{PostId, TagId},
+    url_query::{AppendUrlQueryPairs, AsQueryValue, QueryPairs, QueryPairsExtension},
+    WpApiParamOrder,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum WpApiParamTagsOrderBy {
+    Count,
+    Description,
+    Id,
+    Include,
+    IncludeSlugs,
+    #[default]
+    Name,
+    Slug,
+    TermGroup,
+}
+
+impl_as_query_value_from_as_str!(WpApiParamTagsOrderBy);
+
+impl WpApiParamTagsOrderBy {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Count => "count",
+            Self::Description => "description",
+            Self::Id => "id",
+            Self::Include => "include",
+            Self::IncludeSlugs => "include_slugs",
+            Self::Name => "name",
+            Self::Slug => "slug",
+            Self::TermGroup => "term_group",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, uniffi::Record)]
+pub struct TagListParams {
+    /// Current page of the collection.
+    /// Default: `1`
+    #[uniffi(default = None)]
+    pub page: Option<u32>,
+    /// Maximum number of items to be returned in result set.
+    /// Default: `10`
+    #[uniffi(default = None)]
+    pub per_page: Option<u32>,
+    /// Limit results to those matching a string.
+    #[uniffi(default = None)]
+    pub search: Option<String>,
+    /// Ensure result set excludes specific IDs.
+    #[uniffi(default = [])]
+    pub exclude: Vec<TagId>,
+    /// Limit result set to specific IDs.
+    #[uniffi(default = [])]
+    pub include: Vec<TagId>,
+    /// Offset the result set by a specific number of items.
+    #[uniffi(default = None)]
+    pub offset: Option<u32>,
+    /// Order sort attribute ascending or descending.
+    /// Default: asc
+    /// One of: asc, desc
+    #[uniffi(default = None)]
+    pub order: Option<WpApiParamOrder>,
+    /// Sort collection by term attribute.
+    /// Default: name
+    /// One of: id, include, name, slug, include_slugs, term_group, description, count
+    #[uniffi(default = None)]
+    pub orderby: Option<WpApiParamTagsOrderBy>,
+    /// Whether to hide terms not assigned to any posts.
+    #[uniffi(default = None)]
+    pub hide_empty: Option<bool>,
+    /// Limit result set to terms assigned to a specific post.
+    #[uniffi(default = None)]
+    pub post: Option<PostId>,
+    /// Limit result set to terms with one or more specific slugs.
+    #[uniffi(default = [])]
+    pub slug: Vec<String>,
+}
+
+impl AppendUrlQueryPairs for TagListParams {
+    fn append_query_pairs(&self, query_pairs_mut: &mut QueryPairs) {
+        query_pairs_mut
+            .append_option_query_value_pair("page", self.page.as_ref())
+            .append_option_query_value_pair("per_page", self.per_page.as_ref())
+            .append_option_query_value_pair("search", self.search.as_ref())
+            .append_vec_query_value_pair("exclude", &self.exclude)
+            .append_vec_query_value_pair("include", &self.include)
+            .append_option_query_value_pair("offset", self.offset.as_ref())
+            .append_option_query_value_pair("order", self.order.as_ref())
+            .append_option_query_value_pair("orderby", self.orderby.as_ref())
+            .append_option_query_value_pair("hide_empty", self.hide_empty.as_ref())
+            .append_option_query_value_pair("post", self.post.as_ref())
+            .append_vec_query_value_pair("slug", &self.slug);
+    }
+}
+
+impl_pagination_params!(TagListParams);
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record)]
+pub struct TagDeleteResponse {
+    pub deleted: bool,
+    pub previous: TagWithEditContext,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct TagCreateParams {
+    // HTML title for the term.
+    pub name: String,
+    // HTML description of the term.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    // An alphanumeric identifier for the term unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, uniffi::Record)]
+pub struct TagUpdateParams {
+    // HTML title for the term.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    // HTML description of the term.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    // An alphanumeric identifier for the term unique to its type.
+    #[uniffi(default = None)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseTag {
+    #[WpContext(edit, embed, view)]
+    pub id: Option<TagId>,
+    #[WpContext(edit, view)]
+    pub count: Option<u32>,
+    #[WpContext(edit, view)]
+    pub description: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub link: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub name: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub taxonomy: Option<String>,
+    // meta field is omitted for now: https://github.com/Automattic/wordpress-rs/issues/57
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, unit_test_common::assert_expected_query_pairs};
+    use rstest::*;
+
+    #[rstest]
+    #[case(TagListParams::default(), "")]
+    #[case(generate!(TagListParams, (hide_empty, Some(true))), "hide_empty=true")]
+    #[case(generate!(TagListParams, (offset, Some(2))), "offset=2")]
+    #[case(generate!(TagListParams, (post, Some(PostId(2)))), "post=2")]
+    #[case(generate!(TagListParams, (slug, vec!["foo".to_string(), "bar".to_string()])), "slug=foo%2Cbar")]
+    #[case(generate!(TagListParams, (orderby, Some(WpApiParamTagsOrderBy::TermGroup))), "orderby=term_group")]
+    #[trace]
+    fn test_tag_list_params(#[case] params: TagListParams, #[case] expected_query: &str) {
+        assert_expected_query_pairs(params, expected_query);
+    }
+}
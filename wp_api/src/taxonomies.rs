@@ -0,0 +1,155 @@
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use wp_contextual::WpContextual;
+
+use crate::{
+    post_types::PostType,
+    url_query::{AppendUrlQueryPairs, QueryPairs, QueryPairsExtension},
+};
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, uniffi::Enum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxonomyType {
+    Category,
+    PostTag,
+    NavMenu,
+    LinkCategory,
+    PostFormat,
+    WpTheme,
+    WpTemplatePartArea,
+    WpPatternCategory,
+    #[serde(untagged)]
+    Custom(String),
+}
+
+impl Display for TaxonomyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Category => "category",
+            Self::PostTag => "post_tag",
+            Self::NavMenu => "nav_menu",
+            Self::LinkCategory => "link_category",
+            Self::PostFormat => "post_format",
+            Self::WpTheme => "wp_theme",
+            Self::WpTemplatePartArea => "wp_template_part_area",
+            Self::WpPatternCategory => "wp_pattern_category",
+            Self::Custom(name) => name.as_str(),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Default, uniffi::Record)]
+pub struct TaxonomyListParams {
+    /// Limit results to taxonomies associated with a specific post type.
+    #[uniffi(default = None)]
+    pub post_type: Option<PostType>,
+}
+
+impl AppendUrlQueryPairs for TaxonomyListParams {
+    fn append_query_pairs(&self, query_pairs_mut: &mut QueryPairs) {
+        query_pairs_mut.append_option_query_value_pair("type", self.post_type.as_ref());
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, uniffi::Record, WpContextual)]
+#[serde(transparent)]
+pub struct SparseTaxonomiesResponse {
+    #[serde(flatten)]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualField]
+    pub taxonomies: Option<HashMap<TaxonomyType, SparseTaxonomyDetails>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record, WpContextual)]
+pub struct SparseTaxonomyDetails {
+    #[WpContext(edit)]
+    pub capabilities: Option<HashMap<TaxonomyCapabilities, String>>,
+    #[WpContext(edit, view)]
+    pub description: Option<String>,
+    #[WpContext(edit, view)]
+    pub hierarchical: Option<bool>,
+    #[WpContext(edit)]
+    pub labels: Option<TaxonomyLabels>,
+    #[WpContext(edit, embed, view)]
+    pub name: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub slug: Option<String>,
+    #[WpContext(edit)]
+    pub show_cloud: Option<bool>,
+    #[WpContext(edit, view)]
+    pub types: Option<Vec<PostType>>,
+    #[WpContext(edit, embed, view)]
+    pub rest_base: Option<String>,
+    #[WpContext(edit, embed, view)]
+    pub rest_namespace: Option<String>,
+    #[WpContext(edit)]
+    pub visibility: Option<TaxonomyVisibility>,
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
+}
+
+// Labels that are only used by hierarchical or non-hierarchical taxonomies will be `null` for
+// the other kind, and the `*_field_description` labels are only available since WordPress 6.6.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct TaxonomyLabels {
+    pub name: String,
+    pub singular_name: String,
+    pub search_items: String,
+    pub popular_items: Option<String>,
+    pub all_items: String,
+    pub parent_item: Option<String>,
+    pub parent_item_colon: Option<String>,
+    pub name_field_description: Option<String>,
+    pub slug_field_description: Option<String>,
+    pub parent_field_description: Option<String>,
+    pub desc_field_description: Option<String>,
+    pub edit_item: String,
+    pub view_item: String,
+    pub update_item: String,
+    pub add_new_item: String,
+    pub new_item_name: String,
+    pub separate_items_with_commas: Option<String>,
+    pub add_or_remove_items: Option<String>,
+    pub choose_from_most_used: Option<String>,
+    pub not_found: String,
+    pub no_terms: String,
+    pub filter_by_item: Option<String>,
+    pub items_list_navigation: String,
+    pub items_list: String,
+    pub most_used: String,
+    pub back_to_items: String,
+    pub item_link: String,
+    pub item_link_description: String,
+    pub menu_name: String,
+    pub name_admin_bar: String,
+    pub archives: String,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, uniffi::Enum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxonomyCapabilities {
+    AssignTerms,
+    DeleteTerms,
+    EditTerms,
+    ManageTerms,
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct TaxonomyVisibility {
+    pub public: bool,
+    pub publicly_queryable: bool,
+    pub show_admin_column: bool,
+    pub show_in_nav_menus: bool,
+    pub show_in_quick_edit: bool,
+    pub show_ui: bool,
+}
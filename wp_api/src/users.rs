@@ -5,7 +5,7 @@ use wp_contextual::WpContextual;
 
 use crate::{
     impl_as_query_value_for_new_type, impl_as_query_value_from_as_str,
-    impl_as_query_value_from_to_string,
+    impl_as_query_value_from_to_string, impl_pagination_params,
     url_query::{AppendUrlQueryPairs, AsQueryValue, QueryPairs, QueryPairsExtension},
     WpApiParamOrder,
 };
@@ -80,7 +80,7 @@ impl Display for WpApiParamUsersHasPublishedPosts {
     }
 }
 
-#[derive(Debug, Default, uniffi::Record)]
+#[derive(Debug, Default, Clone, uniffi::Record)]
 pub struct UserListParams {
     /// Current page of the collection.
     /// Default: `1`
@@ -155,6 +155,8 @@ impl AppendUrlQueryPairs for UserListParams {
     }
 }
 
+impl_pagination_params!(UserListParams);
+
 #[derive(Debug, Serialize, uniffi::Record)]
 pub struct UserCreateParams {
     /// Login name for the user.
@@ -360,6 +362,10 @@ pub struct SparseUser {
     #[WpContextualOption]
     pub avatar_urls: Option<HashMap<String, String>>,
     // meta field is omitted for now: https://github.com/Automattic/wordpress-rs/issues/57
+    #[serde(rename = "_links")]
+    #[WpContext(edit, embed, view)]
+    #[WpContextualOption]
+    pub links: Option<crate::links::WpLinks>,
 }
 
 #[cfg(test)]
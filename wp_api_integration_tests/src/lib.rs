@@ -1,10 +1,11 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 use wp_api::{
+    comments::CommentId,
+    pages::PageId,
     posts::{CategoryId, MediaId, PostId, TagId},
-    request::{
-        RequestExecutor, RequestMethod, WpNetworkHeaderMap, WpNetworkRequest, WpNetworkResponse,
-    },
+    request::{RequestExecutor, WpNetworkRequest, WpNetworkResponse},
+    reqwest_request_executor::ReqwestRequestExecutor,
     users::UserId,
     ParsedUrl, RequestExecutionError, WpApiClient, WpApiError, WpAuthentication, WpErrorCode,
 };
@@ -34,6 +35,9 @@ pub mod backend;
 // The first user is also the current user
 pub const FIRST_USER_ID: UserId = UserId(1);
 pub const FIRST_USER_EMAIL: &str = "test@example.com";
+// The account password of the first user, as opposed to its application password, which is set in
+// `scripts/setup-test-site.sh`
+pub const FIRST_USER_PASSWORD: &str = "strongpassword";
 pub const SECOND_USER_ID: UserId = UserId(2);
 pub const SECOND_USER_EMAIL: &str = "themeshaperwp+demos@gmail.com";
 pub const SECOND_USER_SLUG: &str = "themedemos";
@@ -41,6 +45,8 @@ pub const HELLO_DOLLY_PLUGIN_SLUG: &str = "hello-dolly/hello";
 pub const CLASSIC_EDITOR_PLUGIN_SLUG: &str = "classic-editor/classic-editor";
 pub const WP_ORG_PLUGIN_SLUG_CLASSIC_WIDGETS: &str = "classic-widgets";
 pub const FIRST_POST_ID: PostId = PostId(1);
+pub const SAMPLE_PAGE_ID: PageId = PageId(2);
+pub const FIRST_COMMENT_ID: CommentId = CommentId(1);
 pub const MEDIA_ID_611: MediaId = MediaId(611);
 pub const CATEGORY_ID_1: CategoryId = CategoryId(1);
 pub const TAG_ID_100: TagId = TagId(100);
@@ -120,66 +126,27 @@ impl<T: std::fmt::Debug> AssertWpError<T> for Result<T, WpApiError> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct AsyncWpNetworking {
-    client: reqwest::Client,
-}
-
-impl Default for AsyncWpNetworking {
-    fn default() -> Self {
-        Self {
-            client: reqwest::Client::new(),
-        }
-    }
+    executor: ReqwestRequestExecutor,
 }
 
 impl AsyncWpNetworking {
     pub async fn async_request(
         &self,
         wp_request: Arc<WpNetworkRequest>,
-    ) -> Result<WpNetworkResponse, reqwest::Error> {
-        let mut request = self
-            .client
-            .request(
-                Self::request_method(wp_request.method()),
-                wp_request.url().0.as_str(),
-            )
-            .headers(wp_request.header_map().as_header_map());
-        if let Some(body) = wp_request.body() {
-            request = request.body(body.contents());
-        }
-        let mut response = request.send().await?;
-
-        let header_map = std::mem::take(response.headers_mut());
-        Ok(WpNetworkResponse {
-            status_code: response.status().as_u16(),
-            body: response.bytes().await.unwrap().to_vec(),
-            header_map: Arc::new(WpNetworkHeaderMap::new(header_map)),
-        })
-    }
-
-    fn request_method(method: RequestMethod) -> http::Method {
-        match method {
-            RequestMethod::GET => reqwest::Method::GET,
-            RequestMethod::POST => reqwest::Method::POST,
-            RequestMethod::PUT => reqwest::Method::PUT,
-            RequestMethod::DELETE => reqwest::Method::DELETE,
-            RequestMethod::HEAD => reqwest::Method::HEAD,
-        }
+    ) -> Result<WpNetworkResponse, RequestExecutionError> {
+        self.executor.execute(wp_request).await
     }
 }
+
 #[async_trait]
 impl RequestExecutor for AsyncWpNetworking {
     async fn execute(
         &self,
         request: Arc<WpNetworkRequest>,
     ) -> Result<WpNetworkResponse, RequestExecutionError> {
-        self.async_request(request).await.map_err(|err| {
-            RequestExecutionError::RequestExecutionFailed {
-                status_code: err.status().map(|s| s.as_u16()),
-                reason: err.to_string(),
-            }
-        })
+        self.async_request(request).await
     }
 }
 
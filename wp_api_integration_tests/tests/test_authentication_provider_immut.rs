@@ -0,0 +1,52 @@
+use std::sync::{Arc, Mutex};
+
+use serial_test::parallel;
+use wp_api::{authentication::AuthenticationProvider, WpApiClient, WpAuthentication};
+use wp_api_integration_tests::{
+    test_site_url, AssertResponse, AsyncWpNetworking, TestCredentials, FIRST_USER_ID,
+};
+
+#[tokio::test]
+#[parallel]
+async fn reauthenticate_with_authentication_provider() {
+    let authentication_provider = Arc::new(TestAuthenticationProvider {
+        password: Mutex::new("invalid_password".to_string()),
+    });
+    let api_client = WpApiClient::with_authentication_provider(
+        test_site_url(),
+        authentication_provider.clone(),
+        Arc::new(AsyncWpNetworking::default()),
+    );
+    let user = api_client
+        .users()
+        .retrieve_me_with_edit_context()
+        .await
+        .assert_response();
+    assert_eq!(user.id, FIRST_USER_ID);
+    assert_eq!(
+        *authentication_provider.password.lock().unwrap(),
+        TestCredentials::instance().admin_password
+    );
+}
+
+// Starts with an invalid password, then uses the admin's application password once it
+// reauthenticates
+#[derive(Debug)]
+struct TestAuthenticationProvider {
+    password: Mutex<String>,
+}
+
+#[async_trait::async_trait]
+impl AuthenticationProvider for TestAuthenticationProvider {
+    fn authentication(&self) -> WpAuthentication {
+        WpAuthentication::from_username_and_password(
+            TestCredentials::instance().admin_username.to_string(),
+            self.password.lock().unwrap().clone(),
+        )
+    }
+
+    async fn reauthenticate(&self) -> bool {
+        *self.password.lock().unwrap() = TestCredentials::instance().admin_password.to_string();
+        true
+    }
+}
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use serial_test::serial;
+use wp_api::{
+    batch::{BatchResponse, BatchValidation},
+    custom_posts::{CustomPostCreateParams, CustomPostType},
+    posts::{PostCreateParams, PostDeleteResponse, PostId, PostWithEditContext},
+    WpApiError, WpApiRequestBuilder, WpAuthentication, WpErrorCode,
+};
+use wp_api_integration_tests::{
+    api_client, backend::RestoreServer, test_site_url, AssertResponse, TestCredentials,
+    FIRST_POST_ID,
+};
+
+#[tokio::test]
+#[serial]
+async fn batch_create_and_delete_posts() {
+    let request_builder = request_builder();
+    let posts = request_builder.posts();
+    let response = api_client()
+        .batch()
+        .batch(
+            vec![
+                Arc::new(
+                    posts
+                        .create(&PostCreateParams {
+                            title: Some("foo".to_string()),
+                            ..Default::default()
+                        })
+                        .unwrap(),
+                ),
+                Arc::new(posts.delete(&FIRST_POST_ID).unwrap()),
+            ],
+            BatchValidation::Normal,
+        )
+        .await
+        .assert_response();
+    assert!(!response.failed_validation);
+    let created_post = parse::<PostWithEditContext>(&response, 0);
+    assert_eq!(created_post.title.raw, "foo");
+    let delete_response = parse::<PostDeleteResponse>(&response, 1);
+    assert!(delete_response.deleted);
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn batch_with_individual_errors() {
+    let request_builder = request_builder();
+    let posts = request_builder.posts();
+    let response = api_client()
+        .batch()
+        .batch(
+            vec![
+                Arc::new(
+                    posts
+                        .create(&PostCreateParams {
+                            title: Some("foo".to_string()),
+                            ..Default::default()
+                        })
+                        .unwrap(),
+                ),
+                Arc::new(posts.delete(&PostId(99999999)).unwrap()),
+            ],
+            BatchValidation::Normal,
+        )
+        .await
+        .assert_response();
+    assert!(!response.failed_validation);
+    parse::<PostWithEditContext>(&response, 0);
+    assert!(matches!(
+        response.parse::<PostDeleteResponse>(1),
+        Some(Err(WpApiError::WpError {
+            error_code: WpErrorCode::PostInvalidId,
+            ..
+        }))
+    ));
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn batch_with_require_all_validate() {
+    let response = api_client()
+        .batch()
+        .batch(
+            vec![
+                Arc::new(
+                    request_builder()
+                        .posts()
+                        .create(&PostCreateParams {
+                            title: Some("foo".to_string()),
+                            ..Default::default()
+                        })
+                        .unwrap(),
+                ),
+                Arc::new(
+                    request_builder()
+                        .custom_posts()
+                        .create(
+                            &CustomPostType::new("wp/v2", "posts"),
+                            &CustomPostCreateParams {
+                                post: PostCreateParams::default(),
+                                extra_fields: [(
+                                    "status".to_string(),
+                                    "invalid-status".to_string(),
+                                )]
+                                .into(),
+                            },
+                        )
+                        .unwrap(),
+                ),
+            ],
+            BatchValidation::RequireAllValidate,
+        )
+        .await
+        .assert_response();
+    assert!(response.failed_validation);
+    assert!(response.responses[0].is_none());
+    assert!(matches!(
+        response.parse::<PostWithEditContext>(1),
+        Some(Err(WpApiError::WpError {
+            error_code: WpErrorCode::InvalidParam,
+            ..
+        }))
+    ));
+
+    RestoreServer::db().await;
+}
+
+fn request_builder() -> WpApiRequestBuilder {
+    WpApiRequestBuilder::new(
+        test_site_url(),
+        WpAuthentication::from_username_and_password(
+            TestCredentials::instance().admin_username.to_string(),
+            TestCredentials::instance().admin_password.to_string(),
+        ),
+    )
+}
+
+fn parse<T: serde::de::DeserializeOwned + std::fmt::Debug>(
+    response: &BatchResponse,
+    index: usize,
+) -> T {
+    response
+        .parse::<T>(index)
+        .expect("Request should have a response")
+        .assert_response()
+}
@@ -0,0 +1,31 @@
+use serial_test::parallel;
+use wp_api::{categories::CategoryCreateParams, posts::CategoryId, WpErrorCode};
+use wp_api_integration_tests::{api_client, AssertResponse, AssertWpError, CATEGORY_ID_1};
+
+#[tokio::test]
+#[parallel]
+async fn create_category_err_term_exists() {
+    let existing_category = api_client()
+        .categories()
+        .retrieve_with_edit_context(&CATEGORY_ID_1)
+        .await
+        .assert_response();
+    api_client()
+        .categories()
+        .create(&CategoryCreateParams {
+            name: existing_category.name,
+            ..Default::default()
+        })
+        .await
+        .assert_wp_error(WpErrorCode::TermExists);
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_category_err_term_invalid() {
+    api_client()
+        .categories()
+        .retrieve_with_edit_context(&CategoryId(99999999))
+        .await
+        .assert_wp_error(WpErrorCode::TermInvalid);
+}
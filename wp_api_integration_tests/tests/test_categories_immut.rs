@@ -0,0 +1,162 @@
+use rstest::*;
+use rstest_reuse::{self, apply, template};
+use serial_test::parallel;
+use wp_api::categories::{
+    CategoryListParams, SparseCategoryFieldWithEditContext, SparseCategoryFieldWithEmbedContext,
+    SparseCategoryFieldWithViewContext, WpApiParamCategoriesOrderBy,
+};
+use wp_api::posts::CategoryId;
+use wp_api::{generate, WpApiParamOrder};
+use wp_api_integration_tests::{api_client, AssertResponse, CATEGORY_ID_1, FIRST_POST_ID};
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_edit_context(#[case] params: CategoryListParams) {
+    api_client()
+        .categories()
+        .list_with_edit_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_embed_context(#[case] params: CategoryListParams) {
+    api_client()
+        .categories()
+        .list_with_embed_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_view_context(#[case] params: CategoryListParams) {
+    api_client()
+        .categories()
+        .list_with_view_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_by_hide_empty() {
+    api_client()
+        .categories()
+        .list_with_edit_context(&generate!(CategoryListParams, (hide_empty, Some(true))))
+        .await
+        .assert_response()
+        .iter()
+        .for_each(|category| assert!(category.count > 0));
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_edit_context() {
+    let category = api_client()
+        .categories()
+        .retrieve_with_edit_context(&CATEGORY_ID_1)
+        .await
+        .assert_response();
+    assert_eq!(category.id, CATEGORY_ID_1);
+    assert_eq!(category.taxonomy, "category");
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_embed_context() {
+    api_client()
+        .categories()
+        .retrieve_with_embed_context(&CATEGORY_ID_1)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_view_context() {
+    api_client()
+        .categories()
+        .retrieve_with_view_context(&CATEGORY_ID_1)
+        .await
+        .assert_response();
+}
+
+#[template]
+#[rstest]
+#[case::default(CategoryListParams::default())]
+#[case::page(generate!(CategoryListParams, (page, Some(1))))]
+#[case::per_page(generate!(CategoryListParams, (per_page, Some(3))))]
+#[case::search(generate!(CategoryListParams, (search, Some("foo".to_string()))))]
+#[case::exclude(generate!(CategoryListParams, (exclude, vec![CATEGORY_ID_1])))]
+#[case::include(generate!(CategoryListParams, (include, vec![CATEGORY_ID_1, CategoryId(2)])))]
+#[case::order(generate!(CategoryListParams, (order, Some(WpApiParamOrder::Desc))))]
+#[case::orderby(generate!(CategoryListParams, (orderby, Some(WpApiParamCategoriesOrderBy::Count))))]
+#[case::hide_empty(generate!(CategoryListParams, (hide_empty, Some(true))))]
+#[case::parent(generate!(CategoryListParams, (parent, Some(CategoryId(0)))))]
+#[case::post(generate!(CategoryListParams, (post, Some(FIRST_POST_ID))))]
+#[case::slug(generate!(CategoryListParams, (slug, vec!["foo".to_string(), "bar".to_string()])))]
+pub fn list_cases(#[case] params: CategoryListParams) {}
+
+mod filter {
+    use super::*;
+
+    wp_api::generate_sparse_category_field_with_edit_context_test_cases!();
+    wp_api::generate_sparse_category_field_with_embed_context_test_cases!();
+    wp_api::generate_sparse_category_field_with_view_context_test_cases!();
+
+    #[apply(sparse_category_field_with_edit_context_test_cases)]
+    #[case(&[SparseCategoryFieldWithEditContext::Name, SparseCategoryFieldWithEditContext::Parent])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_categories_with_edit_context(
+        #[case] fields: &[SparseCategoryFieldWithEditContext],
+    ) {
+        api_client()
+            .categories()
+            .filter_list_with_edit_context(&CategoryListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|category| {
+                category.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+
+    #[apply(sparse_category_field_with_embed_context_test_cases)]
+    #[case(&[SparseCategoryFieldWithEmbedContext::Id, SparseCategoryFieldWithEmbedContext::Slug])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_retrieve_category_with_embed_context(
+        #[case] fields: &[SparseCategoryFieldWithEmbedContext],
+    ) {
+        let category = api_client()
+            .categories()
+            .filter_retrieve_with_embed_context(&CATEGORY_ID_1, fields)
+            .await
+            .assert_response();
+        category.assert_that_instance_fields_nullability_match_provided_fields(fields)
+    }
+
+    #[apply(sparse_category_field_with_view_context_test_cases)]
+    #[case(&[SparseCategoryFieldWithViewContext::Count, SparseCategoryFieldWithViewContext::Taxonomy])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_categories_with_view_context(
+        #[case] fields: &[SparseCategoryFieldWithViewContext],
+    ) {
+        api_client()
+            .categories()
+            .filter_list_with_view_context(&CategoryListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|category| {
+                category.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+}
@@ -0,0 +1,76 @@
+use serial_test::serial;
+use wp_api::{
+    categories::{CategoryCreateParams, CategoryUpdateParams},
+    WpErrorCode,
+};
+use wp_api_integration_tests::{
+    api_client, backend::RestoreServer, AssertResponse, AssertWpError, CATEGORY_ID_1,
+};
+
+#[tokio::test]
+#[serial]
+async fn create_category() {
+    let created_category = api_client()
+        .categories()
+        .create(&CategoryCreateParams {
+            name: "foo".to_string(),
+            description: Some("bar".to_string()),
+            parent: Some(CATEGORY_ID_1),
+            ..Default::default()
+        })
+        .await
+        .assert_response();
+    assert_eq!(created_category.name, "foo");
+    assert_eq!(created_category.description, "bar");
+    assert_eq!(created_category.parent, CATEGORY_ID_1);
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn update_category() {
+    let updated_category = api_client()
+        .categories()
+        .update(
+            &CATEGORY_ID_1,
+            &CategoryUpdateParams {
+                name: Some("new name".to_string()),
+                slug: Some("new-slug".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(updated_category.name, "new name");
+    assert_eq!(updated_category.slug, "new-slug");
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_category() {
+    let created_category = api_client()
+        .categories()
+        .create(&CategoryCreateParams {
+            name: "foo".to_string(),
+            ..Default::default()
+        })
+        .await
+        .assert_response();
+    let category_delete_response = api_client()
+        .categories()
+        .delete(&created_category.id)
+        .await
+        .assert_response();
+    assert!(category_delete_response.deleted);
+    assert_eq!(category_delete_response.previous.id, created_category.id);
+    api_client()
+        .categories()
+        .retrieve_with_edit_context(&created_category.id)
+        .await
+        .assert_wp_error(WpErrorCode::TermInvalid);
+
+    RestoreServer::db().await;
+}
@@ -0,0 +1,44 @@
+use serial_test::parallel;
+use wp_api::{
+    comments::{CommentCreateParams, CommentId, CommentRetrieveParams},
+    posts::PostId,
+    WpErrorCode,
+};
+use wp_api_integration_tests::{api_client, AssertWpError, FIRST_POST_ID};
+
+#[tokio::test]
+#[parallel]
+async fn create_comment_err_comment_content_invalid() {
+    api_client()
+        .comments()
+        .create(&CommentCreateParams {
+            post: Some(FIRST_POST_ID),
+            ..Default::default()
+        })
+        .await
+        .assert_wp_error(WpErrorCode::CommentContentInvalid);
+}
+
+#[tokio::test]
+#[parallel]
+async fn create_comment_err_comment_invalid_post_id() {
+    api_client()
+        .comments()
+        .create(&CommentCreateParams {
+            post: Some(PostId(99999999)),
+            content: Some("foo".to_string()),
+            ..Default::default()
+        })
+        .await
+        .assert_wp_error(WpErrorCode::CommentInvalidPostId);
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_comment_err_comment_invalid_id() {
+    api_client()
+        .comments()
+        .retrieve_with_edit_context(&CommentId(99999999), &CommentRetrieveParams::default())
+        .await
+        .assert_wp_error(WpErrorCode::CommentInvalidId);
+}
@@ -0,0 +1,170 @@
+use rstest::*;
+use rstest_reuse::{self, apply, template};
+use serial_test::parallel;
+use wp_api::comments::{
+    CommentListParams, CommentRetrieveParams, CommentStatus, CommentType,
+    SparseCommentFieldWithEditContext, SparseCommentFieldWithEmbedContext,
+    SparseCommentFieldWithViewContext, WpApiParamCommentsOrderBy,
+};
+use wp_api::{generate, WpApiParamOrder};
+use wp_api_integration_tests::{api_client, AssertResponse, FIRST_COMMENT_ID, FIRST_POST_ID};
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_edit_context(#[case] params: CommentListParams) {
+    api_client()
+        .comments()
+        .list_with_edit_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_embed_context(#[case] params: CommentListParams) {
+    api_client()
+        .comments()
+        .list_with_embed_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_view_context(#[case] params: CommentListParams) {
+    api_client()
+        .comments()
+        .list_with_view_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_by_post() {
+    api_client()
+        .comments()
+        .list_with_edit_context(&generate!(CommentListParams, (post, vec![FIRST_POST_ID])))
+        .await
+        .assert_response()
+        .iter()
+        .for_each(|comment| assert_eq!(comment.post, FIRST_POST_ID));
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_edit_context() {
+    let comment = api_client()
+        .comments()
+        .retrieve_with_edit_context(&FIRST_COMMENT_ID, &CommentRetrieveParams::default())
+        .await
+        .assert_response();
+    assert_eq!(comment.id, FIRST_COMMENT_ID);
+    assert_eq!(comment.status, CommentStatus::Approved);
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_embed_context() {
+    api_client()
+        .comments()
+        .retrieve_with_embed_context(&FIRST_COMMENT_ID, &CommentRetrieveParams::default())
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_view_context() {
+    api_client()
+        .comments()
+        .retrieve_with_view_context(&FIRST_COMMENT_ID, &CommentRetrieveParams::default())
+        .await
+        .assert_response();
+}
+
+#[template]
+#[rstest]
+#[case::default(CommentListParams::default())]
+#[case::page(generate!(CommentListParams, (page, Some(1))))]
+#[case::per_page(generate!(CommentListParams, (per_page, Some(3))))]
+#[case::search(generate!(CommentListParams, (search, Some("foo".to_string()))))]
+#[case::author_email(generate!(CommentListParams, (author_email, Some("foo@example.com".to_string()))))]
+#[case::exclude(generate!(CommentListParams, (exclude, vec![FIRST_COMMENT_ID])))]
+#[case::include(generate!(CommentListParams, (include, vec![FIRST_COMMENT_ID])))]
+#[case::offset(generate!(CommentListParams, (offset, Some(2))))]
+#[case::order(generate!(CommentListParams, (order, Some(WpApiParamOrder::Asc))))]
+#[case::orderby(generate!(CommentListParams, (orderby, Some(WpApiParamCommentsOrderBy::Post))))]
+#[case::parent(generate!(CommentListParams, (parent, vec![FIRST_COMMENT_ID])))]
+#[case::post(generate!(CommentListParams, (post, vec![FIRST_POST_ID])))]
+#[case::status_approved(generate!(CommentListParams, (status, Some(CommentStatus::Approved))))]
+#[case::status_hold(generate!(CommentListParams, (status, Some(CommentStatus::Hold))))]
+#[case::status_spam(generate!(CommentListParams, (status, Some(CommentStatus::Spam))))]
+#[case::comment_type(generate!(CommentListParams, (comment_type, Some(CommentType::Pingback))))]
+pub fn list_cases(#[case] params: CommentListParams) {}
+
+mod filter {
+    use super::*;
+
+    wp_api::generate_sparse_comment_field_with_edit_context_test_cases!();
+    wp_api::generate_sparse_comment_field_with_embed_context_test_cases!();
+    wp_api::generate_sparse_comment_field_with_view_context_test_cases!();
+
+    #[apply(sparse_comment_field_with_edit_context_test_cases)]
+    #[case(&[SparseCommentFieldWithEditContext::AuthorEmail, SparseCommentFieldWithEditContext::CommentType])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_comments_with_edit_context(
+        #[case] fields: &[SparseCommentFieldWithEditContext],
+    ) {
+        api_client()
+            .comments()
+            .filter_list_with_edit_context(&CommentListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|comment| {
+                comment.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+
+    #[apply(sparse_comment_field_with_embed_context_test_cases)]
+    #[case(&[SparseCommentFieldWithEmbedContext::Id, SparseCommentFieldWithEmbedContext::AuthorName])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_retrieve_comment_with_embed_context(
+        #[case] fields: &[SparseCommentFieldWithEmbedContext],
+    ) {
+        let comment = api_client()
+            .comments()
+            .filter_retrieve_with_embed_context(
+                &FIRST_COMMENT_ID,
+                &CommentRetrieveParams::default(),
+                fields,
+            )
+            .await
+            .assert_response();
+        comment.assert_that_instance_fields_nullability_match_provided_fields(fields)
+    }
+
+    #[apply(sparse_comment_field_with_view_context_test_cases)]
+    #[case(&[SparseCommentFieldWithViewContext::Post, SparseCommentFieldWithViewContext::Status])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_comments_with_view_context(
+        #[case] fields: &[SparseCommentFieldWithViewContext],
+    ) {
+        api_client()
+            .comments()
+            .filter_list_with_view_context(&CommentListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|comment| {
+                comment.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+}
@@ -0,0 +1,97 @@
+use serial_test::serial;
+use wp_api::{
+    comments::{CommentCreateParams, CommentRetrieveParams, CommentStatus, CommentUpdateParams},
+    WpErrorCode,
+};
+use wp_api_integration_tests::{
+    api_client, backend::RestoreServer, AssertResponse, AssertWpError, FIRST_COMMENT_ID,
+    FIRST_POST_ID,
+};
+
+#[tokio::test]
+#[serial]
+async fn create_comment() {
+    let created_comment = api_client()
+        .comments()
+        .create(&CommentCreateParams {
+            post: Some(FIRST_POST_ID),
+            parent: Some(FIRST_COMMENT_ID),
+            content: Some("foo".to_string()),
+            ..Default::default()
+        })
+        .await
+        .assert_response();
+    assert_eq!(created_comment.post, FIRST_POST_ID);
+    assert_eq!(created_comment.parent, FIRST_COMMENT_ID);
+    assert_eq!(created_comment.content.raw, "foo");
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn update_comment() {
+    let updated_comment = api_client()
+        .comments()
+        .update(
+            &FIRST_COMMENT_ID,
+            &CommentUpdateParams {
+                content: Some("new content".to_string()),
+                author_name: Some("new name".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(updated_comment.content.raw, "new content");
+    assert_eq!(updated_comment.author_name, "new name");
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn moderate_comment() {
+    let api_client = api_client();
+    let comments = api_client.comments();
+    let held_comment = comments.hold(&FIRST_COMMENT_ID).await.assert_response();
+    assert_eq!(held_comment.status, CommentStatus::Hold);
+    let spammed_comment = comments.spam(&FIRST_COMMENT_ID).await.assert_response();
+    assert_eq!(spammed_comment.status, CommentStatus::Spam);
+    let approved_comment = comments.approve(&FIRST_COMMENT_ID).await.assert_response();
+    assert_eq!(approved_comment.status, CommentStatus::Approved);
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_comment() {
+    let comment_delete_response = api_client()
+        .comments()
+        .delete(&FIRST_COMMENT_ID)
+        .await
+        .assert_response();
+    assert!(comment_delete_response.deleted);
+    assert_eq!(comment_delete_response.previous.id, FIRST_COMMENT_ID);
+    api_client()
+        .comments()
+        .retrieve_with_edit_context(&FIRST_COMMENT_ID, &CommentRetrieveParams::default())
+        .await
+        .assert_wp_error(WpErrorCode::CommentInvalidId);
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn trash_comment() {
+    let trashed_comment = api_client()
+        .comments()
+        .trash(&FIRST_COMMENT_ID)
+        .await
+        .assert_response();
+    assert_eq!(trashed_comment.status, CommentStatus::Trash);
+
+    RestoreServer::db().await;
+}
@@ -0,0 +1,76 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    custom_posts::CustomPostType,
+    post_types::PostType,
+    posts::{PostId, PostListParams, PostRetrieveParams},
+};
+use wp_api_integration_tests::{api_client, AssertResponse, FIRST_POST_ID, SAMPLE_PAGE_ID};
+
+#[tokio::test]
+#[parallel]
+async fn list_with_edit_context() {
+    let custom_post_type = custom_post_type(PostType::Post).await;
+    let custom_posts = api_client()
+        .custom_posts()
+        .list_with_edit_context(&custom_post_type, &PostListParams::default())
+        .await
+        .assert_response();
+    assert!(!custom_posts.is_empty());
+    custom_posts
+        .iter()
+        .for_each(|custom_post| assert_eq!(custom_post.post.post_type.as_deref(), Some("post")));
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_with_embed_context() {
+    api_client()
+        .custom_posts()
+        .list_with_embed_context(
+            &CustomPostType::new("wp/v2", "pages"),
+            &PostListParams::default(),
+        )
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_with_view_context() {
+    api_client()
+        .custom_posts()
+        .list_with_view_context(
+            &CustomPostType::new("wp/v2", "posts"),
+            &PostListParams::default(),
+        )
+        .await
+        .assert_response();
+}
+
+#[rstest]
+#[case(PostType::Post, FIRST_POST_ID)]
+#[case(PostType::Page, PostId(SAMPLE_PAGE_ID.0))]
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_edit_context(#[case] post_type: PostType, #[case] post_id: PostId) {
+    let custom_post = api_client()
+        .custom_posts()
+        .retrieve_with_edit_context(
+            &custom_post_type(post_type).await,
+            &post_id,
+            &PostRetrieveParams::default(),
+        )
+        .await
+        .assert_response();
+    assert_eq!(custom_post.post.id, Some(post_id));
+}
+
+async fn custom_post_type(post_type: PostType) -> CustomPostType {
+    let post_type_details = api_client()
+        .post_types()
+        .retrieve_with_edit_context(&post_type)
+        .await
+        .assert_response();
+    CustomPostType::from(&post_type_details)
+}
@@ -0,0 +1,96 @@
+use serial_test::serial;
+use wp_api::{
+    custom_posts::{CustomPostCreateParams, CustomPostType, CustomPostUpdateParams},
+    posts::{PostCreateParams, PostStatus, PostUpdateParams},
+};
+use wp_api_integration_tests::{api_client, backend::RestoreServer, AssertResponse, FIRST_POST_ID};
+
+#[tokio::test]
+#[serial]
+async fn create_custom_post() {
+    let created_custom_post = api_client()
+        .custom_posts()
+        .create(
+            &pages(),
+            &CustomPostCreateParams {
+                post: PostCreateParams {
+                    title: Some("foo".to_string()),
+                    ..Default::default()
+                },
+                extra_fields: [("menu_order".to_string(), "7".to_string())].into(),
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(created_custom_post.post.post_type.as_deref(), Some("page"));
+    assert_eq!(
+        created_custom_post
+            .extra_fields
+            .get("menu_order")
+            .map(String::as_str),
+        Some("7")
+    );
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_custom_post() {
+    let response = api_client()
+        .custom_posts()
+        .delete(&posts(), &FIRST_POST_ID)
+        .await
+        .assert_response();
+    assert!(response.deleted);
+    assert_eq!(response.previous.post.id, Some(FIRST_POST_ID));
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn trash_custom_post() {
+    let trashed_custom_post = api_client()
+        .custom_posts()
+        .trash(&posts(), &FIRST_POST_ID)
+        .await
+        .assert_response();
+    assert_eq!(
+        trashed_custom_post.post.status,
+        Some(PostStatus::Custom("trash".to_string()))
+    );
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn update_custom_post() {
+    let updated_custom_post = api_client()
+        .custom_posts()
+        .update(
+            &posts(),
+            &FIRST_POST_ID,
+            &CustomPostUpdateParams {
+                post: PostUpdateParams {
+                    status: Some(PostStatus::Draft),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(updated_custom_post.post.status, Some(PostStatus::Draft));
+
+    RestoreServer::db().await;
+}
+
+fn pages() -> CustomPostType {
+    CustomPostType::new("wp/v2", "pages")
+}
+
+fn posts() -> CustomPostType {
+    CustomPostType::new("wp/v2", "posts")
+}
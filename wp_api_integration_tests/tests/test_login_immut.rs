@@ -1,8 +1,15 @@
 use rstest::rstest;
 use serial_test::parallel;
 use std::sync::Arc;
-use wp_api::login::WpLoginClient;
-use wp_api_integration_tests::{AssertResponse, AsyncWpNetworking};
+use wp_api::{
+    login::{CookieAuthenticationError, WpLoginClient},
+    request::RequestMethod,
+    WpApiClient, WpAuthentication,
+};
+use wp_api_integration_tests::{
+    test_site_url, AssertResponse, AsyncWpNetworking, TestCredentials, FIRST_USER_ID,
+    FIRST_USER_PASSWORD,
+};
 
 const LOCALHOST_AUTH_URL: &str = "http://localhost/wp-admin/authorize-application.php";
 const AUTOMATTIC_WIDGETS_AUTH_URL: &str =
@@ -50,3 +57,70 @@ async fn test_login_flow(#[case] site_url: &str, #[case] expected_auth_url: &str
         Some(expected_auth_url.to_string())
     );
 }
+
+#[tokio::test]
+#[parallel]
+async fn api_details_route_index() {
+    let client = WpLoginClient::new(Arc::new(AsyncWpNetworking::default()));
+    let api_details = client
+        .api_discovery("http://localhost".to_string())
+        .await
+        .assert_response()
+        .api_details;
+    assert!(api_details.supports_route("/wp/v2/posts".to_string(), RequestMethod::POST));
+    assert!(api_details.supports_route("/wp/v2/posts/1".to_string(), RequestMethod::DELETE));
+    assert!(!api_details.supports_route("/wp/v2/posts".to_string(), RequestMethod::DELETE));
+    assert!(api_details.supports_route(
+        "/wp-site-health/v1/tests/background-updates".to_string(),
+        RequestMethod::GET
+    ));
+    let posts = api_details.find_route("/wp/v2/posts".to_string()).unwrap();
+    assert_eq!(posts.namespace, "wp/v2");
+    assert!(posts.links.is_some());
+}
+
+#[tokio::test]
+#[parallel]
+async fn authenticate_with_cookies() {
+    let client = WpLoginClient::new(Arc::new(AsyncWpNetworking::default()));
+    let authentication = client
+        .authenticate_with_cookies(
+            &test_site_url(),
+            TestCredentials::instance().admin_username,
+            FIRST_USER_PASSWORD,
+        )
+        .await
+        .assert_response();
+    assert!(matches!(authentication, WpAuthentication::Cookie { .. }));
+    let user = WpApiClient::new(
+        test_site_url(),
+        authentication,
+        Arc::new(AsyncWpNetworking::default()),
+    )
+    .users()
+    .retrieve_me_with_edit_context()
+    .await
+    .assert_response();
+    assert_eq!(user.id, FIRST_USER_ID);
+}
+
+#[tokio::test]
+#[parallel]
+async fn authenticate_with_cookies_using_invalid_password() {
+    let client = WpLoginClient::new(Arc::new(AsyncWpNetworking::default()));
+    let result = client
+        .authenticate_with_cookies(
+            &test_site_url(),
+            TestCredentials::instance().admin_username,
+            "invalid_password",
+        )
+        .await;
+    assert!(
+        matches!(
+            result,
+            Err(CookieAuthenticationError::InvalidCredentials { .. })
+        ),
+        "{:?}",
+        result
+    );
+}
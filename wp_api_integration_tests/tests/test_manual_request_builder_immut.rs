@@ -28,7 +28,9 @@ async fn list_users_with_edit_context(#[case] params: UserListParams) {
 
     let request_builder = WpApiRequestBuilder::new(test_site_url(), authentication);
     let wp_request = request_builder.users().list_with_edit_context(&params);
-    let response = async_wp_networking.async_request(wp_request.into()).await;
+    let response = async_wp_networking
+        .async_request(wp_request.unwrap().into())
+        .await;
     let result = response
         .unwrap()
         .parse::<Vec<UserWithEditContext>, WpApiError>();
@@ -0,0 +1,162 @@
+use rstest::*;
+use rstest_reuse::{self, apply, template};
+use serial_test::parallel;
+use wp_api::media::{
+    MediaListParams, SparseMediaFieldWithEditContext, SparseMediaFieldWithEmbedContext,
+    SparseMediaFieldWithViewContext, WpApiParamMediaOrderBy, WpApiParamMediaType,
+};
+use wp_api::posts::MediaId;
+use wp_api::{generate, WpApiParamOrder};
+use wp_api_integration_tests::{api_client, AssertResponse, FIRST_USER_ID, MEDIA_ID_611};
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_edit_context(#[case] params: MediaListParams) {
+    api_client()
+        .media()
+        .list_with_edit_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_embed_context(#[case] params: MediaListParams) {
+    api_client()
+        .media()
+        .list_with_embed_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_view_context(#[case] params: MediaListParams) {
+    api_client()
+        .media()
+        .list_with_view_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_by_media_type() {
+    api_client()
+        .media()
+        .list_with_edit_context(&generate!(
+            MediaListParams,
+            (media_type, Some(WpApiParamMediaType::Image))
+        ))
+        .await
+        .assert_response()
+        .iter()
+        .for_each(|media| assert!(media.mime_type.starts_with("image/")));
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_edit_context() {
+    let media = api_client()
+        .media()
+        .retrieve_with_edit_context(&MEDIA_ID_611)
+        .await
+        .assert_response();
+    assert_eq!(media.id, MEDIA_ID_611);
+    assert!(!media.source_url.is_empty());
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_embed_context() {
+    api_client()
+        .media()
+        .retrieve_with_embed_context(&MEDIA_ID_611)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_view_context() {
+    api_client()
+        .media()
+        .retrieve_with_view_context(&MEDIA_ID_611)
+        .await
+        .assert_response();
+}
+
+#[template]
+#[rstest]
+#[case::default(MediaListParams::default())]
+#[case::page(generate!(MediaListParams, (page, Some(1))))]
+#[case::per_page(generate!(MediaListParams, (per_page, Some(3))))]
+#[case::search(generate!(MediaListParams, (search, Some("foo".to_string()))))]
+#[case::author(generate!(MediaListParams, (author, vec![FIRST_USER_ID])))]
+#[case::exclude(generate!(MediaListParams, (exclude, vec![MEDIA_ID_611])))]
+#[case::include(generate!(MediaListParams, (include, vec![MEDIA_ID_611, MediaId(612)])))]
+#[case::offset(generate!(MediaListParams, (offset, Some(2))))]
+#[case::order(generate!(MediaListParams, (order, Some(WpApiParamOrder::Asc))))]
+#[case::orderby(generate!(MediaListParams, (orderby, Some(WpApiParamMediaOrderBy::Title))))]
+#[case::slug(generate!(MediaListParams, (slug, vec!["foo".to_string(), "bar".to_string()])))]
+#[case::media_type(generate!(MediaListParams, (media_type, Some(WpApiParamMediaType::Image))))]
+#[case::mime_type(generate!(MediaListParams, (mime_type, Some("image/jpeg".to_string()))))]
+pub fn list_cases(#[case] params: MediaListParams) {}
+
+mod filter {
+    use super::*;
+
+    wp_api::generate_sparse_media_field_with_edit_context_test_cases!();
+    wp_api::generate_sparse_media_field_with_embed_context_test_cases!();
+    wp_api::generate_sparse_media_field_with_view_context_test_cases!();
+
+    #[apply(sparse_media_field_with_edit_context_test_cases)]
+    #[case(&[SparseMediaFieldWithEditContext::SourceUrl, SparseMediaFieldWithEditContext::MediaDetails])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_media_with_edit_context(#[case] fields: &[SparseMediaFieldWithEditContext]) {
+        api_client()
+            .media()
+            .filter_list_with_edit_context(&MediaListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|media| {
+                media.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+
+    #[apply(sparse_media_field_with_embed_context_test_cases)]
+    #[case(&[SparseMediaFieldWithEmbedContext::Id, SparseMediaFieldWithEmbedContext::AltText])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_retrieve_media_with_embed_context(
+        #[case] fields: &[SparseMediaFieldWithEmbedContext],
+    ) {
+        let media = api_client()
+            .media()
+            .filter_retrieve_with_embed_context(&MEDIA_ID_611, fields)
+            .await
+            .assert_response();
+        media.assert_that_instance_fields_nullability_match_provided_fields(fields)
+    }
+
+    #[apply(sparse_media_field_with_view_context_test_cases)]
+    #[case(&[SparseMediaFieldWithViewContext::MimeType, SparseMediaFieldWithViewContext::PostType])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_media_with_view_context(#[case] fields: &[SparseMediaFieldWithViewContext]) {
+        api_client()
+            .media()
+            .filter_list_with_view_context(&MediaListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|media| {
+                media.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+}
@@ -0,0 +1,89 @@
+use serial_test::serial;
+use wp_api::{
+    media::{MediaCreateParams, MediaType, MediaUpdateParams, MediaUploadFile},
+    WpErrorCode,
+};
+use wp_api_integration_tests::{
+    api_client, backend::RestoreServer, AssertResponse, AssertWpError, FIRST_POST_ID, MEDIA_ID_611,
+};
+
+#[tokio::test]
+#[serial]
+async fn upload_media() {
+    let uploaded_media = api_client()
+        .media()
+        .upload(
+            &MediaCreateParams {
+                title: Some("foo".to_string()),
+                alt_text: Some("bar".to_string()),
+                post: Some(FIRST_POST_ID),
+                ..Default::default()
+            },
+            hello_text_file(),
+        )
+        .await
+        .assert_response();
+    assert_eq!(uploaded_media.title.raw, "foo");
+    assert_eq!(uploaded_media.alt_text, "bar");
+    assert_eq!(uploaded_media.post, Some(FIRST_POST_ID));
+    assert_eq!(uploaded_media.media_type, MediaType::File);
+    assert_eq!(uploaded_media.mime_type, "text/plain");
+    assert!(uploaded_media.source_url.ends_with(".txt"));
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn update_media() {
+    let updated_media = api_client()
+        .media()
+        .update(
+            &MEDIA_ID_611,
+            &MediaUpdateParams {
+                alt_text: Some("new alt text".to_string()),
+                caption: Some("new caption".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(updated_media.alt_text, "new alt text");
+    assert_eq!(updated_media.caption.raw, "new caption");
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_media() {
+    // Uploaded media is deleted instead of an existing one, because deleting a media item also
+    // removes its files which can't be restored by `RestoreServer::db()`.
+    let uploaded_media = api_client()
+        .media()
+        .upload(&MediaCreateParams::default(), hello_text_file())
+        .await
+        .assert_response();
+    let media_delete_response = api_client()
+        .media()
+        .delete(&uploaded_media.id)
+        .await
+        .assert_response();
+    assert!(media_delete_response.deleted);
+    assert_eq!(media_delete_response.previous.id, uploaded_media.id);
+    api_client()
+        .media()
+        .retrieve_with_edit_context(&uploaded_media.id)
+        .await
+        .assert_wp_error(WpErrorCode::PostInvalidId);
+
+    RestoreServer::db().await;
+}
+
+fn hello_text_file() -> MediaUploadFile {
+    MediaUploadFile {
+        file_name: "hello.txt".to_string(),
+        mime_type: "text/plain".to_string(),
+        contents: b"Hello, World!".to_vec(),
+    }
+}
@@ -0,0 +1,165 @@
+use rstest::*;
+use rstest_reuse::{self, apply, template};
+use serial_test::parallel;
+use wp_api::pages::{
+    PageId, PageListParams, PageRetrieveParams, SparsePageFieldWithEditContext,
+    SparsePageFieldWithEmbedContext, SparsePageFieldWithViewContext, WpApiParamPagesOrderBy,
+};
+use wp_api::posts::PostStatus;
+use wp_api::{generate, WpApiParamOrder};
+use wp_api_integration_tests::{api_client, AssertResponse, FIRST_USER_ID, SAMPLE_PAGE_ID};
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_edit_context(#[case] params: PageListParams) {
+    api_client()
+        .pages()
+        .list_with_edit_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_embed_context(#[case] params: PageListParams) {
+    api_client()
+        .pages()
+        .list_with_embed_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_view_context(#[case] params: PageListParams) {
+    api_client()
+        .pages()
+        .list_with_view_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_by_parent() {
+    let parent_id = SAMPLE_PAGE_ID;
+    api_client()
+        .pages()
+        .list_with_edit_context(&generate!(PageListParams, (parent, vec![parent_id])))
+        .await
+        .assert_response()
+        .iter()
+        .for_each(|page| assert_eq!(page.parent, parent_id));
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_edit_context() {
+    let page = api_client()
+        .pages()
+        .retrieve_with_edit_context(&SAMPLE_PAGE_ID, &PageRetrieveParams::default())
+        .await
+        .assert_response();
+    assert_eq!(page.id, SAMPLE_PAGE_ID);
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_embed_context() {
+    api_client()
+        .pages()
+        .retrieve_with_embed_context(&SAMPLE_PAGE_ID, &PageRetrieveParams::default())
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_view_context() {
+    api_client()
+        .pages()
+        .retrieve_with_view_context(&SAMPLE_PAGE_ID, &PageRetrieveParams::default())
+        .await
+        .assert_response();
+}
+
+#[template]
+#[rstest]
+#[case::default(PageListParams::default())]
+#[case::page(generate!(PageListParams, (page, Some(1))))]
+#[case::per_page(generate!(PageListParams, (per_page, Some(3))))]
+#[case::search(generate!(PageListParams, (search, Some("foo".to_string()))))]
+#[case::author(generate!(PageListParams, (author, vec![FIRST_USER_ID])))]
+#[case::exclude(generate!(PageListParams, (exclude, vec![PageId(1), PageId(2)])))]
+#[case::include(generate!(PageListParams, (include, vec![PageId(2)])))]
+#[case::menu_order(generate!(PageListParams, (menu_order, Some(0))))]
+#[case::offset(generate!(PageListParams, (offset, Some(2))))]
+#[case::order(generate!(PageListParams, (order, Some(WpApiParamOrder::Asc))))]
+#[case::orderby(generate!(PageListParams, (orderby, Some(WpApiParamPagesOrderBy::MenuOrder))))]
+#[case::parent(generate!(PageListParams, (parent, vec![PageId(0)])))]
+#[case::parent_exclude(generate!(PageListParams, (parent_exclude, vec![PageId(0)])))]
+#[case::slug(generate!(PageListParams, (slug, vec!["foo".to_string(), "bar".to_string()])))]
+#[case::status(generate!(PageListParams, (status, vec![PostStatus::Publish, PostStatus::Draft])))]
+pub fn list_cases(#[case] params: PageListParams) {}
+
+mod filter {
+    use super::*;
+
+    wp_api::generate_sparse_page_field_with_edit_context_test_cases!();
+    wp_api::generate_sparse_page_field_with_embed_context_test_cases!();
+    wp_api::generate_sparse_page_field_with_view_context_test_cases!();
+
+    #[apply(sparse_page_field_with_edit_context_test_cases)]
+    #[case(&[SparsePageFieldWithEditContext::Parent, SparsePageFieldWithEditContext::MenuOrder])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_pages_with_edit_context(#[case] fields: &[SparsePageFieldWithEditContext]) {
+        api_client()
+            .pages()
+            .filter_list_with_edit_context(&PageListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|page| {
+                page.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+
+    #[apply(sparse_page_field_with_embed_context_test_cases)]
+    #[case(&[SparsePageFieldWithEmbedContext::Id, SparsePageFieldWithEmbedContext::Author])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_retrieve_pages_with_embed_context(
+        #[case] fields: &[SparsePageFieldWithEmbedContext],
+    ) {
+        let page = api_client()
+            .pages()
+            .filter_retrieve_with_embed_context(
+                &SAMPLE_PAGE_ID,
+                &PageRetrieveParams::default(),
+                fields,
+            )
+            .await
+            .assert_response();
+        page.assert_that_instance_fields_nullability_match_provided_fields(fields)
+    }
+
+    #[apply(sparse_page_field_with_view_context_test_cases)]
+    #[case(&[SparsePageFieldWithViewContext::Template, SparsePageFieldWithViewContext::Parent])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_pages_with_view_context(#[case] fields: &[SparsePageFieldWithViewContext]) {
+        api_client()
+            .pages()
+            .filter_list_with_view_context(&PageListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|page| {
+                page.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+}
@@ -0,0 +1,81 @@
+use serial_test::serial;
+use wp_api::{
+    pages::{PageCreateParams, PageRetrieveParams, PageUpdateParams},
+    posts::PostStatus,
+    WpErrorCode,
+};
+use wp_api_integration_tests::{
+    api_client, backend::RestoreServer, AssertResponse, AssertWpError, SAMPLE_PAGE_ID,
+};
+
+#[tokio::test]
+#[serial]
+async fn create_page_with_parent_and_menu_order() {
+    let created_page = api_client()
+        .pages()
+        .create(&PageCreateParams {
+            title: Some("foo".to_string()),
+            parent: Some(SAMPLE_PAGE_ID),
+            menu_order: Some(7),
+            ..Default::default()
+        })
+        .await
+        .assert_response();
+    assert_eq!(created_page.title.raw, "foo");
+    assert_eq!(created_page.parent, SAMPLE_PAGE_ID);
+    assert_eq!(created_page.menu_order, 7);
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn update_page_status_and_menu_order() {
+    let updated_page = api_client()
+        .pages()
+        .update(
+            &SAMPLE_PAGE_ID,
+            &PageUpdateParams {
+                status: Some(PostStatus::Draft),
+                menu_order: Some(3),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(updated_page.status, PostStatus::Draft);
+    assert_eq!(updated_page.menu_order, 3);
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_page() {
+    let page_delete_response = api_client()
+        .pages()
+        .delete(&SAMPLE_PAGE_ID)
+        .await
+        .assert_response();
+    assert!(page_delete_response.deleted);
+    api_client()
+        .pages()
+        .retrieve_with_edit_context(&SAMPLE_PAGE_ID, &PageRetrieveParams::default())
+        .await
+        .assert_wp_error(WpErrorCode::PostInvalidId);
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn trash_page() {
+    let trashed_page = api_client()
+        .pages()
+        .trash(&SAMPLE_PAGE_ID)
+        .await
+        .assert_response();
+    assert_eq!(trashed_page.status, PostStatus::Custom("trash".to_string()));
+
+    RestoreServer::db().await;
+}
@@ -0,0 +1,65 @@
+use futures::TryStreamExt;
+use serial_test::parallel;
+use wp_api::{generate, posts::PostListParams, users::UserListParams};
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[tokio::test]
+#[parallel]
+async fn paginated_list_with_edit_context() {
+    let params = generate!(PostListParams, (per_page, Some(2)));
+    let first_page = api_client()
+        .posts()
+        .paginated_list_with_edit_context(&params)
+        .await
+        .assert_response();
+    assert_eq!(first_page.data.len(), 2);
+    assert!(first_page.total_items.is_some_and(|total| total > 2));
+    assert!(first_page.prev_page_params.is_none());
+    let next_page_params = first_page
+        .next_page_params
+        .expect("There should be a second page");
+    assert_eq!(next_page_params.page, Some(2));
+
+    let second_page = api_client()
+        .posts()
+        .paginated_list_with_edit_context(&next_page_params)
+        .await
+        .assert_response();
+    assert_eq!(
+        second_page.prev_page_params.and_then(|params| params.page),
+        Some(1)
+    );
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_all_with_edit_context() {
+    let api_client = api_client();
+    let params = generate!(PostListParams, (per_page, Some(2)));
+    let total_items = api_client
+        .posts()
+        .paginated_list_with_edit_context(&params)
+        .await
+        .assert_response()
+        .total_items;
+    let posts = api_client
+        .posts()
+        .list_all_with_edit_context(&params)
+        .try_concat()
+        .await
+        .assert_response();
+    assert_eq!(Some(posts.len() as u32), total_items);
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_all_users_with_view_context() {
+    let pages = api_client()
+        .users()
+        .list_all_with_view_context(&generate!(UserListParams, (per_page, Some(1))))
+        .try_collect::<Vec<_>>()
+        .await
+        .assert_response();
+    assert!(pages.len() > 1);
+    pages.iter().for_each(|page| assert_eq!(page.len(), 1));
+}
@@ -0,0 +1,89 @@
+use rstest::*;
+use rstest_reuse::{self, apply, template};
+use serial_test::parallel;
+use wp_api::{
+    generate,
+    post_revisions::{
+        PostRevisionListParams, SparsePostRevisionFieldWithEditContext,
+        WpApiParamPostRevisionsOrderBy,
+    },
+    WpApiParamOrder,
+};
+use wp_api_integration_tests::{api_client, AssertResponse, FIRST_POST_ID};
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_edit_context(#[case] params: PostRevisionListParams) {
+    api_client()
+        .post_revisions()
+        .list_with_edit_context(&FIRST_POST_ID, &params)
+        .await
+        .assert_response()
+        .iter()
+        .for_each(|revision| assert_eq!(revision.parent, FIRST_POST_ID));
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_embed_context(#[case] params: PostRevisionListParams) {
+    api_client()
+        .post_revisions()
+        .list_with_embed_context(&FIRST_POST_ID, &params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_view_context(#[case] params: PostRevisionListParams) {
+    api_client()
+        .post_revisions()
+        .list_with_view_context(&FIRST_POST_ID, &params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn filter_list_with_edit_context() {
+    api_client()
+        .post_revisions()
+        .filter_list_with_edit_context(
+            &FIRST_POST_ID,
+            &PostRevisionListParams::default(),
+            &[
+                SparsePostRevisionFieldWithEditContext::Id,
+                SparsePostRevisionFieldWithEditContext::Parent,
+            ],
+        )
+        .await
+        .assert_response()
+        .iter()
+        .for_each(|revision| {
+            assert!(revision.id.is_some());
+            assert!(revision.title.is_none());
+        });
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_autosaves_with_edit_context() {
+    api_client()
+        .post_revisions()
+        .list_autosaves_with_edit_context(&FIRST_POST_ID)
+        .await
+        .assert_response();
+}
+
+#[template]
+#[rstest]
+#[case(PostRevisionListParams::default())]
+#[case(generate!(PostRevisionListParams, (page, Some(1))))]
+#[case(generate!(PostRevisionListParams, (per_page, Some(3))))]
+#[case(generate!(PostRevisionListParams, (search, Some("foo".to_string()))))]
+#[case(generate!(PostRevisionListParams, (order, Some(WpApiParamOrder::Asc))))]
+#[case(generate!(PostRevisionListParams, (orderby, Some(WpApiParamPostRevisionsOrderBy::Id))))]
+pub fn list_cases(#[case] params: PostRevisionListParams) {}
@@ -0,0 +1,60 @@
+use serial_test::serial;
+use wp_api::{
+    post_revisions::{PostAutosaveCreateParams, PostRevisionListParams},
+    posts::PostUpdateParams,
+};
+use wp_api_integration_tests::{api_client, backend::RestoreServer, AssertResponse, FIRST_POST_ID};
+
+#[tokio::test]
+#[serial]
+async fn create_autosave() {
+    let autosave = api_client()
+        .post_revisions()
+        .create_autosave(
+            &FIRST_POST_ID,
+            &PostAutosaveCreateParams {
+                title: Some("foo".to_string()),
+                content: Some("bar".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(autosave.title.raw, "foo");
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_post_revision() {
+    // Updating the post creates a revision that can be deleted
+    api_client()
+        .posts()
+        .update(
+            &FIRST_POST_ID,
+            &PostUpdateParams {
+                title: Some("foo".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    let revision = api_client()
+        .post_revisions()
+        .list_with_edit_context(&FIRST_POST_ID, &PostRevisionListParams::default())
+        .await
+        .assert_response()
+        .into_iter()
+        .next()
+        .expect("Updating the post should create a revision");
+    let response = api_client()
+        .post_revisions()
+        .delete(&FIRST_POST_ID, &revision.id)
+        .await
+        .assert_response();
+    assert!(response.deleted);
+    assert_eq!(response.previous.id, revision.id);
+
+    RestoreServer::db().await;
+}
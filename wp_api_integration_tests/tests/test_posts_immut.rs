@@ -7,7 +7,10 @@ use wp_api::posts::{
     SparsePostFieldWithViewContext, TagId, WpApiParamPostsOrderBy, WpApiParamPostsSearchColumn,
     WpApiParamPostsTaxRelation,
 };
-use wp_api::{generate, WpApiParamOrder};
+use wp_api::{
+    embed::WpEmbedRelation, generate, links::WpLinkRelation, users::UserWithViewContext,
+    WpApiParamOrder, WpContext,
+};
 use wp_api_integration_tests::{
     api_client, AssertResponse, TestCredentials, FIRST_POST_ID, FIRST_USER_ID, SECOND_USER_ID,
 };
@@ -45,6 +48,23 @@ async fn list_with_view_context(#[case] params: PostListParams) {
         .assert_response();
 }
 
+#[tokio::test]
+#[parallel]
+async fn list_with_edit_context_with_response() {
+    let response = api_client()
+        .posts()
+        .list_with_edit_context_with_response(&PostListParams::default())
+        .await
+        .assert_response();
+    assert_eq!(response.status_code, 200);
+    assert!(!response.data.is_empty());
+    assert!(response
+        .header_map
+        .as_header_map()
+        .get("X-WP-Total")
+        .is_some());
+}
+
 #[tokio::test]
 #[parallel]
 async fn retrieve_with_edit_context() {
@@ -75,6 +95,68 @@ async fn retrieve_with_view_context(#[case] params: PostRetrieveParams) {
         .assert_response();
 }
 
+#[tokio::test]
+#[parallel]
+async fn embed_retrieve_with_view_context() {
+    let post = api_client()
+        .posts()
+        .embed_retrieve_with_view_context(
+            &FIRST_POST_ID,
+            &PostRetrieveParams::default(),
+            &[WpEmbedRelation::Author, WpEmbedRelation::Term],
+        )
+        .await
+        .assert_response();
+    let embedded = post.embedded.unwrap();
+    assert_eq!(
+        embedded
+            .author
+            .iter()
+            .map(|author| author.id)
+            .collect::<Vec<_>>(),
+        vec![post.author]
+    );
+    assert!(embedded.featured_media.is_empty());
+    assert!(embedded
+        .terms
+        .iter()
+        .flatten()
+        .any(|term| term.taxonomy == "category"));
+}
+
+#[tokio::test]
+#[parallel]
+async fn embed_list_with_edit_context() {
+    let posts = api_client()
+        .posts()
+        .embed_list_with_edit_context(&PostListParams::default(), &[])
+        .await
+        .assert_response();
+    assert!(posts.iter().all(|post| post.embedded.is_some()));
+}
+
+#[tokio::test]
+#[parallel]
+async fn follow_author_link() {
+    let client = api_client();
+    let post = client
+        .posts()
+        .retrieve_with_view_context(&FIRST_POST_ID, &PostRetrieveParams::default())
+        .await
+        .assert_response();
+    let author = client
+        .links()
+        .follow_link_as::<UserWithViewContext>(
+            &post.links.unwrap(),
+            WpLinkRelation::Author,
+            Some(WpContext::View),
+        )
+        .await
+        .assert_response()
+        .unwrap();
+    assert_eq!(author.id, post.author);
+}
+
 #[tokio::test]
 #[parallel]
 async fn retrieve_password_protected_with_edit_context() {
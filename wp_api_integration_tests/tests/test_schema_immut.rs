@@ -0,0 +1,49 @@
+use serial_test::parallel;
+use wp_api::request::RequestMethod;
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[tokio::test]
+#[parallel]
+async fn route_options() {
+    let route = api_client()
+        .schema()
+        .route("/wp/v2/posts".to_string())
+        .await
+        .assert_response();
+    assert_eq!(route.namespace, "wp/v2");
+    assert!(route.allows_method(&RequestMethod::GET));
+    assert!(route.allows_method(&RequestMethod::POST));
+    assert!(!route.allows_method(&RequestMethod::DELETE));
+    assert!(route
+        .args(&RequestMethod::GET)
+        .unwrap()
+        .contains_key("per_page"));
+    let schema = route.schema.unwrap();
+    assert_eq!(schema.title.as_deref(), Some("post"));
+    assert!(schema.properties["id"].readonly);
+}
+
+#[tokio::test]
+#[parallel]
+async fn single_post_route_options() {
+    let route = api_client()
+        .schema()
+        .route("/wp/v2/posts/1".to_string())
+        .await
+        .assert_response();
+    assert!(route.allows_method(&RequestMethod::DELETE));
+}
+
+#[tokio::test]
+#[parallel]
+async fn namespace_index() {
+    let index = api_client()
+        .schema()
+        .namespace_index("wp/v2".to_string())
+        .await
+        .assert_response();
+    assert_eq!(index.namespace, "wp/v2");
+    let posts = &index.routes["/wp/v2/posts"];
+    assert!(posts.allows_method(&RequestMethod::POST));
+    assert!(posts.schema.is_some());
+}
@@ -0,0 +1,27 @@
+use serial_test::parallel;
+use std::sync::Arc;
+use wp_api::{
+    request::RequestMethod,
+    site_capabilities::{SiteCapabilitiesService, WpVersion},
+};
+use wp_api_integration_tests::{test_site_url, AssertResponse, AsyncWpNetworking};
+
+#[tokio::test]
+#[parallel]
+async fn site_capabilities() {
+    let service = SiteCapabilitiesService::new(Arc::new(AsyncWpNetworking::default()));
+    let capabilities = service
+        .capabilities(test_site_url())
+        .await
+        .assert_response();
+    assert!(capabilities.is_at_least(WpVersion::new(6, 3)));
+    assert!(capabilities.has_site_health());
+    assert!(capabilities.has_block_editor());
+    assert!(!capabilities.has_woocommerce());
+    assert!(capabilities.application_passwords_enabled());
+    assert!(capabilities.supports_route("/wp/v2/posts".to_string(), RequestMethod::POST));
+    assert!(Arc::ptr_eq(
+        &service.cached_capabilities(test_site_url()).unwrap(),
+        &capabilities
+    ));
+}
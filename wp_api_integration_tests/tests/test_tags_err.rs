@@ -0,0 +1,31 @@
+use serial_test::parallel;
+use wp_api::{posts::TagId, tags::TagCreateParams, WpErrorCode};
+use wp_api_integration_tests::{api_client, AssertResponse, AssertWpError, TAG_ID_100};
+
+#[tokio::test]
+#[parallel]
+async fn create_tag_err_term_exists() {
+    let existing_tag = api_client()
+        .tags()
+        .retrieve_with_edit_context(&TAG_ID_100)
+        .await
+        .assert_response();
+    api_client()
+        .tags()
+        .create(&TagCreateParams {
+            name: existing_tag.name,
+            ..Default::default()
+        })
+        .await
+        .assert_wp_error(WpErrorCode::TermExists);
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_tag_err_term_invalid() {
+    api_client()
+        .tags()
+        .retrieve_with_edit_context(&TagId(99999999))
+        .await
+        .assert_wp_error(WpErrorCode::TermInvalid);
+}
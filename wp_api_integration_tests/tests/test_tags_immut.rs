@@ -0,0 +1,158 @@
+use rstest::*;
+use rstest_reuse::{self, apply, template};
+use serial_test::parallel;
+use wp_api::posts::TagId;
+use wp_api::tags::{
+    SparseTagFieldWithEditContext, SparseTagFieldWithEmbedContext, SparseTagFieldWithViewContext,
+    TagListParams, WpApiParamTagsOrderBy,
+};
+use wp_api::{generate, WpApiParamOrder};
+use wp_api_integration_tests::{api_client, AssertResponse, FIRST_POST_ID, TAG_ID_100};
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_edit_context(#[case] params: TagListParams) {
+    api_client()
+        .tags()
+        .list_with_edit_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_embed_context(#[case] params: TagListParams) {
+    api_client()
+        .tags()
+        .list_with_embed_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[apply(list_cases)]
+#[parallel]
+async fn list_with_view_context(#[case] params: TagListParams) {
+    api_client()
+        .tags()
+        .list_with_view_context(&params)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn list_by_hide_empty() {
+    api_client()
+        .tags()
+        .list_with_edit_context(&generate!(TagListParams, (hide_empty, Some(true))))
+        .await
+        .assert_response()
+        .iter()
+        .for_each(|tag| assert!(tag.count > 0));
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_edit_context() {
+    let tag = api_client()
+        .tags()
+        .retrieve_with_edit_context(&TAG_ID_100)
+        .await
+        .assert_response();
+    assert_eq!(tag.id, TAG_ID_100);
+    assert_eq!(tag.taxonomy, "post_tag");
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_embed_context() {
+    api_client()
+        .tags()
+        .retrieve_with_embed_context(&TAG_ID_100)
+        .await
+        .assert_response();
+}
+
+#[tokio::test]
+#[parallel]
+async fn retrieve_with_view_context() {
+    api_client()
+        .tags()
+        .retrieve_with_view_context(&TAG_ID_100)
+        .await
+        .assert_response();
+}
+
+#[template]
+#[rstest]
+#[case::default(TagListParams::default())]
+#[case::page(generate!(TagListParams, (page, Some(1))))]
+#[case::per_page(generate!(TagListParams, (per_page, Some(3))))]
+#[case::search(generate!(TagListParams, (search, Some("foo".to_string()))))]
+#[case::exclude(generate!(TagListParams, (exclude, vec![TAG_ID_100])))]
+#[case::include(generate!(TagListParams, (include, vec![TAG_ID_100, TagId(101)])))]
+#[case::order(generate!(TagListParams, (order, Some(WpApiParamOrder::Desc))))]
+#[case::orderby(generate!(TagListParams, (orderby, Some(WpApiParamTagsOrderBy::Count))))]
+#[case::hide_empty(generate!(TagListParams, (hide_empty, Some(true))))]
+#[case::offset(generate!(TagListParams, (offset, Some(2))))]
+#[case::post(generate!(TagListParams, (post, Some(FIRST_POST_ID))))]
+#[case::slug(generate!(TagListParams, (slug, vec!["foo".to_string(), "bar".to_string()])))]
+pub fn list_cases(#[case] params: TagListParams) {}
+
+mod filter {
+    use super::*;
+
+    wp_api::generate_sparse_tag_field_with_edit_context_test_cases!();
+    wp_api::generate_sparse_tag_field_with_embed_context_test_cases!();
+    wp_api::generate_sparse_tag_field_with_view_context_test_cases!();
+
+    #[apply(sparse_tag_field_with_edit_context_test_cases)]
+    #[case(&[SparseTagFieldWithEditContext::Name, SparseTagFieldWithEditContext::Description])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_tags_with_edit_context(#[case] fields: &[SparseTagFieldWithEditContext]) {
+        api_client()
+            .tags()
+            .filter_list_with_edit_context(&TagListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|tag| {
+                tag.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+
+    #[apply(sparse_tag_field_with_embed_context_test_cases)]
+    #[case(&[SparseTagFieldWithEmbedContext::Id, SparseTagFieldWithEmbedContext::Slug])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_retrieve_tag_with_embed_context(
+        #[case] fields: &[SparseTagFieldWithEmbedContext],
+    ) {
+        let tag = api_client()
+            .tags()
+            .filter_retrieve_with_embed_context(&TAG_ID_100, fields)
+            .await
+            .assert_response();
+        tag.assert_that_instance_fields_nullability_match_provided_fields(fields)
+    }
+
+    #[apply(sparse_tag_field_with_view_context_test_cases)]
+    #[case(&[SparseTagFieldWithViewContext::Count, SparseTagFieldWithViewContext::Taxonomy])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_tags_with_view_context(#[case] fields: &[SparseTagFieldWithViewContext]) {
+        api_client()
+            .tags()
+            .filter_list_with_view_context(&TagListParams::default(), fields)
+            .await
+            .assert_response()
+            .iter()
+            .for_each(|tag| {
+                tag.assert_that_instance_fields_nullability_match_provided_fields(fields)
+            });
+    }
+}
@@ -0,0 +1,74 @@
+use serial_test::serial;
+use wp_api::{
+    tags::{TagCreateParams, TagUpdateParams},
+    WpErrorCode,
+};
+use wp_api_integration_tests::{
+    api_client, backend::RestoreServer, AssertResponse, AssertWpError, TAG_ID_100,
+};
+
+#[tokio::test]
+#[serial]
+async fn create_tag() {
+    let created_tag = api_client()
+        .tags()
+        .create(&TagCreateParams {
+            name: "foo".to_string(),
+            description: Some("bar".to_string()),
+            ..Default::default()
+        })
+        .await
+        .assert_response();
+    assert_eq!(created_tag.name, "foo");
+    assert_eq!(created_tag.description, "bar");
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn update_tag() {
+    let updated_tag = api_client()
+        .tags()
+        .update(
+            &TAG_ID_100,
+            &TagUpdateParams {
+                name: Some("new name".to_string()),
+                slug: Some("new-slug".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .assert_response();
+    assert_eq!(updated_tag.name, "new name");
+    assert_eq!(updated_tag.slug, "new-slug");
+
+    RestoreServer::db().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn delete_tag() {
+    let created_tag = api_client()
+        .tags()
+        .create(&TagCreateParams {
+            name: "foo".to_string(),
+            ..Default::default()
+        })
+        .await
+        .assert_response();
+    let tag_delete_response = api_client()
+        .tags()
+        .delete(&created_tag.id)
+        .await
+        .assert_response();
+    assert!(tag_delete_response.deleted);
+    assert_eq!(tag_delete_response.previous.id, created_tag.id);
+    api_client()
+        .tags()
+        .retrieve_with_edit_context(&created_tag.id)
+        .await
+        .assert_wp_error(WpErrorCode::TermInvalid);
+
+    RestoreServer::db().await;
+}
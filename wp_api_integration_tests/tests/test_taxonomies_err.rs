@@ -0,0 +1,42 @@
+use rstest::*;
+use serial_test::parallel;
+use wp_api::{
+    taxonomies::{TaxonomyListParams, TaxonomyType},
+    WpErrorCode,
+};
+use wp_api_integration_tests::{api_client, api_client_as_subscriber, AssertWpError};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_taxonomies_err_cannot_view() {
+    api_client_as_subscriber()
+        .taxonomies()
+        .list_with_edit_context(&TaxonomyListParams::default())
+        .await
+        .assert_wp_error(WpErrorCode::CannotView);
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn retrieve_taxonomy_err_forbidden_context(
+    #[values(TaxonomyType::Category, TaxonomyType::PostTag)] taxonomy_type: TaxonomyType,
+) {
+    api_client_as_subscriber()
+        .taxonomies()
+        .retrieve_with_edit_context(&taxonomy_type)
+        .await
+        .assert_wp_error(WpErrorCode::ForbiddenContext);
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn retrieve_taxonomy_err_taxonomy_invalid() {
+    api_client()
+        .taxonomies()
+        .retrieve_with_edit_context(&TaxonomyType::Custom("does_not_exist".to_string()))
+        .await
+        .assert_wp_error(WpErrorCode::TaxonomyInvalid);
+}
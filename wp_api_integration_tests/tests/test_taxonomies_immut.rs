@@ -0,0 +1,183 @@
+use rstest::*;
+use rstest_reuse::{self, apply, template};
+use serial_test::parallel;
+use wp_api::post_types::PostType;
+use wp_api::taxonomies::{
+    SparseTaxonomyDetailsFieldWithEditContext, SparseTaxonomyDetailsFieldWithEmbedContext,
+    SparseTaxonomyDetailsFieldWithViewContext, TaxonomyCapabilities, TaxonomyListParams,
+    TaxonomyType,
+};
+
+use wp_api_integration_tests::{api_client, AssertResponse};
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_taxonomies_with_edit_context() {
+    let response = api_client()
+        .taxonomies()
+        .list_with_edit_context(&TaxonomyListParams::default())
+        .await
+        .assert_response();
+    assert_eq!(
+        response
+            .taxonomies
+            .get(&TaxonomyType::Category)
+            .expect("Our local WordPress test site supports `category` taxonomy")
+            .name,
+        "Categories"
+    );
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_taxonomies_with_embed_context() {
+    let response = api_client()
+        .taxonomies()
+        .list_with_embed_context(&TaxonomyListParams::default())
+        .await
+        .assert_response();
+    assert_eq!(
+        response
+            .taxonomies
+            .get(&TaxonomyType::PostTag)
+            .expect("Our local WordPress test site supports `post_tag` taxonomy")
+            .rest_base,
+        "tags"
+    );
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_taxonomies_with_view_context() {
+    let response = api_client()
+        .taxonomies()
+        .list_with_view_context(&TaxonomyListParams::default())
+        .await
+        .assert_response();
+    assert!(
+        response
+            .taxonomies
+            .get(&TaxonomyType::Category)
+            .expect("Our local WordPress test site supports `category` taxonomy")
+            .hierarchical
+    );
+}
+
+#[rstest]
+#[tokio::test]
+#[parallel]
+async fn list_taxonomies_by_post_type() {
+    let response = api_client()
+        .taxonomies()
+        .list_with_edit_context(&TaxonomyListParams {
+            post_type: Some(PostType::Post),
+        })
+        .await
+        .assert_response();
+    assert!(!response.taxonomies.is_empty());
+    response
+        .taxonomies
+        .values()
+        .for_each(|taxonomy| assert!(taxonomy.types.contains(&PostType::Post)));
+}
+
+#[apply(taxonomy_types)]
+#[tokio::test]
+#[parallel]
+async fn retrieve_taxonomy_with_edit_context(#[case] taxonomy_type: TaxonomyType) {
+    let taxonomy = api_client()
+        .taxonomies()
+        .retrieve_with_edit_context(&taxonomy_type)
+        .await
+        .assert_response();
+    assert_eq!(taxonomy.slug, taxonomy_type.to_string());
+    assert!(taxonomy
+        .capabilities
+        .contains_key(&TaxonomyCapabilities::ManageTerms));
+}
+
+#[apply(taxonomy_types)]
+#[tokio::test]
+#[parallel]
+async fn retrieve_taxonomy_with_embed_context(#[case] taxonomy_type: TaxonomyType) {
+    api_client()
+        .taxonomies()
+        .retrieve_with_embed_context(&taxonomy_type)
+        .await
+        .assert_response();
+}
+
+#[apply(taxonomy_types)]
+#[tokio::test]
+#[parallel]
+async fn retrieve_taxonomy_with_view_context(#[case] taxonomy_type: TaxonomyType) {
+    api_client()
+        .taxonomies()
+        .retrieve_with_view_context(&taxonomy_type)
+        .await
+        .assert_response();
+}
+
+#[template]
+#[rstest]
+#[case(TaxonomyType::Category)]
+#[case(TaxonomyType::PostTag)]
+#[case(TaxonomyType::NavMenu)]
+#[case(TaxonomyType::WpPatternCategory)]
+fn taxonomy_types(#[case] taxonomy_type: TaxonomyType) {}
+
+mod filter {
+    use super::*;
+
+    wp_api::generate_sparse_taxonomy_details_field_with_edit_context_test_cases!();
+    wp_api::generate_sparse_taxonomy_details_field_with_embed_context_test_cases!();
+    wp_api::generate_sparse_taxonomy_details_field_with_view_context_test_cases!();
+
+    #[apply(sparse_taxonomy_details_field_with_edit_context_test_cases)]
+    #[case(&[SparseTaxonomyDetailsFieldWithEditContext::Labels, SparseTaxonomyDetailsFieldWithEditContext::Visibility])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_retrieve_taxonomy_with_edit_context(
+        #[case] fields: &[SparseTaxonomyDetailsFieldWithEditContext],
+    ) {
+        let taxonomy = api_client()
+            .taxonomies()
+            .filter_retrieve_with_edit_context(&TaxonomyType::Category, fields)
+            .await
+            .assert_response();
+        taxonomy.assert_that_instance_fields_nullability_match_provided_fields(fields)
+    }
+
+    #[apply(sparse_taxonomy_details_field_with_embed_context_test_cases)]
+    #[case(&[SparseTaxonomyDetailsFieldWithEmbedContext::Name, SparseTaxonomyDetailsFieldWithEmbedContext::RestNamespace])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_retrieve_taxonomy_with_embed_context(
+        #[case] fields: &[SparseTaxonomyDetailsFieldWithEmbedContext],
+    ) {
+        let taxonomy = api_client()
+            .taxonomies()
+            .filter_retrieve_with_embed_context(&TaxonomyType::PostTag, fields)
+            .await
+            .assert_response();
+        taxonomy.assert_that_instance_fields_nullability_match_provided_fields(fields)
+    }
+
+    #[apply(sparse_taxonomy_details_field_with_view_context_test_cases)]
+    #[case(&[SparseTaxonomyDetailsFieldWithViewContext::Types, SparseTaxonomyDetailsFieldWithViewContext::Hierarchical])]
+    #[tokio::test]
+    #[parallel]
+    async fn filter_retrieve_taxonomy_with_view_context(
+        #[case] fields: &[SparseTaxonomyDetailsFieldWithViewContext],
+    ) {
+        let taxonomy = api_client()
+            .taxonomies()
+            .filter_retrieve_with_view_context(&TaxonomyType::Category, fields)
+            .await
+            .assert_response();
+        taxonomy.assert_that_instance_fields_nullability_match_provided_fields(fields)
+    }
+}
@@ -306,7 +306,8 @@
 //! `SparseFooFieldWithEmbedContext` & `SparseFooFieldWithViewContext` types as an `enum` which
 //! will include all the fields from their `Sparse` type counterpart as its `enum` variants. It'll
 //! also generate `fn as_field_name(&self) -> &str` function that maps each `enum` variant to its
-//! field name, making it easier to implement functions such as `as_str`. Note that it
+//! field name, or to its `#[serde(rename = "...")]` name if it has one, making it easier to
+//! implement functions such as `as_str`. Note that it
 //! intentionally doesn't generate the `as_str` function directly, as the field names might need to
 //! be mapped to a different casing.
 //!
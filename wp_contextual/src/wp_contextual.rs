@@ -222,8 +222,8 @@ fn generate_sparse_field_type(
     let mut as_field_names = Vec::with_capacity(fields.len());
     for f in fields {
         if let Some(f_ident) = &f.field.ident {
-            let field_name = f_ident.to_string();
-            let variant_ident = format_ident!("{}", field_name.to_case(Case::UpperCamel));
+            let variant_ident = format_ident!("{}", f_ident.to_string().to_case(Case::UpperCamel));
+            let field_name = serde_rename(&f.field).unwrap_or_else(|| f_ident.to_string());
             let field_name = field_name.as_str();
 
             variant_idents.push(variant_ident.clone());
@@ -251,6 +251,28 @@ fn generate_sparse_field_type(
     .into()
 }
 
+// Returns the name of `#[serde(rename = "...")]`, such as `_links` or `type`, because the fields
+// are requested by the same name they are serialized with.
+fn serde_rename(field: &syn::Field) -> Option<String> {
+    let mut rename = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+    {
+        // Only `rename` is used, so the other serde attributes are skipped without validating them
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                rename = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Lit>()?;
+            }
+            Ok(())
+        });
+    }
+    rename
+}
+
 fn generate_integration_test_helper(
     sparse_type_ident: Ident,
     sparse_field_type_ident: Ident,
@@ -6,6 +6,7 @@ fn tests() {
     t.pass("tests/basic_wp_contextual_option.rs");
     t.pass("tests/wp_contextual_field_with_multiple_segments.rs");
     t.pass("tests/wp_contextual_field_with_inner_type.rs");
+    t.pass("tests/wp_contextual_field_with_serde_rename.rs");
     t.compile_fail("tests/error_both_wp_contextual_field_and_wp_contextual_option.rs");
     t.compile_fail("tests/error_missing_sparse_prefix_from_wp_contextual.rs");
     t.compile_fail("tests/error_missing_sparse_prefix_from_wp_contextual_field.rs");
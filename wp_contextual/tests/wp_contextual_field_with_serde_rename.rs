@@ -0,0 +1,19 @@
+use wp_contextual::WpContextual;
+
+#[derive(serde::Serialize, serde::Deserialize, WpContextual)]
+pub struct SparseFoo {
+    #[serde(rename = "type")]
+    #[WpContext(edit, embed, view)]
+    pub foo_type: Option<String>,
+    #[serde(default, alias = "links", rename = "_links")]
+    #[WpContext(edit)]
+    pub links: Option<u32>,
+}
+
+fn main() {
+    assert_eq!(SparseFooFieldWithEditContext::FooType.as_field_name(), "type");
+    assert_eq!(SparseFooFieldWithEditContext::Links.as_field_name(), "_links");
+    assert_eq!(SparseFooFieldWithViewContext::FooType.as_field_name(), "type");
+}
+
+uniffi::setup_scaffolding!();
@@ -11,7 +11,7 @@ use syn::Ident;
 
 use crate::{
     parse::{ParsedEnum, RequestType},
-    variant_attr::FilterByType,
+    variant_attr::{EmbedByType, FilterByType, ParamsType, UrlPart},
 };
 
 mod helpers_to_generate_tokens;
@@ -21,7 +21,7 @@ pub(crate) fn generate_types(parsed_enum: &ParsedEnum, crate_config: &CrateConfi
     TokenStream::from_iter(
         &mut [
             generate_endpoint_type(&config, parsed_enum),
-            generate_request_builder(&config, parsed_enum),
+            generate_request_builder(&config, parsed_enum, crate_config),
             generate_async_request_executor(&config, parsed_enum, crate_config),
         ]
         .into_iter(),
@@ -34,48 +34,42 @@ fn generate_async_request_executor(
     crate_config: &CrateConfig,
 ) -> TokenStream {
     let static_api_base_url_type = &config.static_types.api_base_url;
-    let static_wp_authentication_type = &config.static_types.wp_authentication;
+    let static_authentication_provider_type = &config.static_types.authentication_provider;
     let static_request_executor_type = &crate_config.request_executor;
     let static_request_executor_type = quote! { std::sync::Arc<dyn #static_request_executor_type> };
+    let static_wp_response_type = &config.static_types.wp_response;
     let error_type = &crate_config.error_type;
     let generated_request_builder_ident = &config.generated_idents.request_builder;
     let generated_request_executor_ident = &config.generated_idents.request_executor;
 
-    let functions = parsed_enum.variants.iter().map(|variant| {
-        let url_parts = variant.attr.url_parts.as_slice();
-        let params_type = &variant.attr.params;
-
-        ContextAndFilterHandler::from_request_type(
-            variant.attr.request_type,
-            variant.attr.filter_by.clone(),
-        )
-        .into_iter()
-        .map(|context_and_filter_handler| {
-            let output_type = output_type(variant.attr.output.clone(), &context_and_filter_handler);
-            let request_from_request_builder = fn_body_get_request_from_request_builder(
-                &variant.variant_ident,
-                url_parts,
-                params_type.as_ref(),
-                variant.attr.request_type,
-                &context_and_filter_handler,
-            );
-            let fn_signature = fn_signature(
-                PartOf::RequestExecutor,
-                &variant.variant_ident,
-                url_parts,
-                params_type.as_ref(),
-                variant.attr.request_type,
-                &context_and_filter_handler,
-            );
+    let functions = request_executor_functions(
+        parsed_enum,
+        fn_signature,
+        |fn_signature, output_type, request_from_request_builder| {
             quote! {
                 pub async #fn_signature -> Result<#output_type, #error_type> {
                     #request_from_request_builder
                     self.request_executor.execute(std::sync::Arc::new(request)).await?.parse()
                }
             }
-        })
-        .collect::<TokenStream>()
-    });
+        },
+    );
+
+    // `uniffi` doesn't support generic types, so the variants returning `WpResponse` are only
+    // available in Rust.
+    let functions_with_response = request_executor_functions(
+        parsed_enum,
+        fn_signature_with_response,
+        |fn_signature, output_type, request_from_request_builder| {
+            quote! {
+                pub async #fn_signature -> Result<#static_wp_response_type<#output_type>, #error_type> {
+                    #request_from_request_builder
+                    let response = self.request_executor.execute(std::sync::Arc::new(request)).await?;
+                    #static_wp_response_type::from_response(response)
+               }
+            }
+        },
+    );
 
     quote! {
         #[derive(Debug, uniffi::Object)]
@@ -84,25 +78,89 @@ fn generate_async_request_executor(
             request_executor: #static_request_executor_type,
         }
         impl #generated_request_executor_ident {
-            pub fn new(api_base_url: #static_api_base_url_type, authentication: #static_wp_authentication_type, request_executor: #static_request_executor_type) -> Self {
+            #functions_with_response
+        }
+        impl #generated_request_executor_ident {
+            pub fn new(api_base_url: #static_api_base_url_type, authentication_provider: #static_authentication_provider_type, request_executor: #static_request_executor_type) -> Self {
                 Self {
-                    request_builder: #generated_request_builder_ident::new(api_base_url, authentication),
+                    request_builder: #generated_request_builder_ident::new(api_base_url, authentication_provider),
                     request_executor,
                 }
             }
         }
         #[uniffi::export]
         impl #generated_request_executor_ident {
-            #(#functions)*
+            #functions
         }
     }
 }
 
-fn generate_request_builder(config: &Config, parsed_enum: &ParsedEnum) -> TokenStream {
+type FnSignatureGenerator = fn(
+    PartOf,
+    &Ident,
+    &[UrlPart],
+    Option<&ParamsType>,
+    RequestType,
+    &ContextAndFilterHandler,
+) -> TokenStream;
+
+// Generates a request executor function for each variant & context. The functions only differ by
+// their signature, which `fn_signature` generates, and by how they execute the request, so
+// `generate_fn` generates each function from its signature, output type & the statement that gets
+// the request from the request builder.
+fn request_executor_functions(
+    parsed_enum: &ParsedEnum,
+    fn_signature: FnSignatureGenerator,
+    generate_fn: impl Fn(TokenStream, TokenStream, TokenStream) -> TokenStream,
+) -> TokenStream {
+    parsed_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let url_parts = variant.attr.url_parts.as_slice();
+            let params_type = variant.attr.params.as_ref();
+
+            ContextAndFilterHandler::from_request_type(
+                variant.attr.request_type,
+                variant.attr.filter_by.clone(),
+                variant.attr.embed_by.clone(),
+            )
+            .into_iter()
+            .map(|context_and_filter_handler| {
+                let output_type =
+                    output_type(variant.attr.output.clone(), &context_and_filter_handler);
+                let request_from_request_builder = fn_body_get_request_from_request_builder(
+                    &variant.variant_ident,
+                    url_parts,
+                    params_type,
+                    variant.attr.request_type,
+                    &context_and_filter_handler,
+                );
+                let fn_signature = fn_signature(
+                    PartOf::RequestExecutor,
+                    &variant.variant_ident,
+                    url_parts,
+                    params_type,
+                    variant.attr.request_type,
+                    &context_and_filter_handler,
+                );
+                generate_fn(fn_signature, output_type, request_from_request_builder)
+            })
+            .collect::<TokenStream>()
+        })
+        .collect()
+}
+
+fn generate_request_builder(
+    config: &Config,
+    parsed_enum: &ParsedEnum,
+    crate_config: &CrateConfig,
+) -> TokenStream {
     let static_api_base_url_type = &config.static_types.api_base_url;
     let static_inner_request_builder_type = &config.static_types.inner_request_builder;
-    let static_wp_authentication_type = &config.static_types.wp_authentication;
+    let static_authentication_provider_type = &config.static_types.authentication_provider;
     let static_wp_network_request_type = &config.static_types.wp_network_request;
+    let error_type = &crate_config.error_type;
     let generated_endpoint_ident = &config.generated_idents.endpoint;
     let generated_request_builder_ident = &config.generated_idents.request_builder;
 
@@ -113,6 +171,7 @@ fn generate_request_builder(config: &Config, parsed_enum: &ParsedEnum) -> TokenS
         ContextAndFilterHandler::from_request_type(
             variant.attr.request_type,
             variant.attr.filter_by.clone(),
+            variant.attr.embed_by.clone(),
         )
         .into_iter()
         .map(|context_and_filter_handler| {
@@ -134,7 +193,7 @@ fn generate_request_builder(config: &Config, parsed_enum: &ParsedEnum) -> TokenS
             let fn_body_build_request_from_url =
                 fn_body_build_request_from_url(params_type.as_ref(), variant.attr.request_type);
             quote! {
-                pub #fn_signature -> #static_wp_network_request_type {
+                pub #fn_signature -> Result<#static_wp_network_request_type, #error_type> {
                     #url_from_endpoint
                     #fn_body_build_request_from_url
                 }
@@ -150,10 +209,10 @@ fn generate_request_builder(config: &Config, parsed_enum: &ParsedEnum) -> TokenS
             inner: #static_inner_request_builder_type,
         }
         impl #generated_request_builder_ident {
-            pub fn new(api_base_url: #static_api_base_url_type, authentication: #static_wp_authentication_type) -> Self {
+            pub fn new(api_base_url: #static_api_base_url_type, authentication_provider: #static_authentication_provider_type) -> Self {
                 Self {
                     endpoint: #generated_endpoint_ident::new(api_base_url),
-                    inner: #static_inner_request_builder_type::new(authentication),
+                    inner: #static_inner_request_builder_type::new(authentication_provider),
                 }
             }
         }
@@ -180,33 +239,39 @@ fn generate_endpoint_type(config: &Config, parsed_enum: &ParsedEnum) -> TokenStr
         let additional_query_pairs =
             fn_body_additional_query_pairs(&parsed_enum.enum_ident, &variant.variant_ident);
 
-        ContextAndFilterHandler::from_request_type(request_type, variant.attr.filter_by.clone())
-            .into_iter()
-            .map(|context_and_filter_handler| {
-                let fn_signature = fn_signature(
-                    PartOf::Endpoint,
-                    &variant.variant_ident,
-                    url_parts,
-                    params_type.as_ref(),
-                    request_type,
-                    &context_and_filter_handler,
-                );
-                let context_query_pair =
-                    fn_body_context_query_pairs(&config.crate_ident, &context_and_filter_handler);
-                let fields_query_pairs =
-                    fn_body_fields_query_pairs(&config.crate_ident, &context_and_filter_handler);
-                quote! {
-                    pub #fn_signature -> #static_api_endpoint_url_type {
-                        #url_from_api_base_url
-                        #context_query_pair
-                        #query_pairs
-                        #additional_query_pairs
-                        #fields_query_pairs
-                        url.into()
-                    }
+        ContextAndFilterHandler::from_request_type(
+            request_type,
+            variant.attr.filter_by.clone(),
+            variant.attr.embed_by.clone(),
+        )
+        .into_iter()
+        .map(|context_and_filter_handler| {
+            let fn_signature = fn_signature(
+                PartOf::Endpoint,
+                &variant.variant_ident,
+                url_parts,
+                params_type.as_ref(),
+                request_type,
+                &context_and_filter_handler,
+            );
+            let context_query_pair =
+                fn_body_context_query_pairs(&config.crate_ident, &context_and_filter_handler);
+            let fields_query_pairs =
+                fn_body_fields_query_pairs(&config.crate_ident, &context_and_filter_handler);
+            let embed_query_pairs = fn_body_embed_query_pairs(&context_and_filter_handler);
+            quote! {
+                pub #fn_signature -> #static_api_endpoint_url_type {
+                    #url_from_api_base_url
+                    #context_query_pair
+                    #query_pairs
+                    #additional_query_pairs
+                    #fields_query_pairs
+                    #embed_query_pairs
+                    url.into()
                 }
-            })
-            .collect::<TokenStream>()
+            }
+        })
+        .collect::<TokenStream>()
     });
 
     quote! {
@@ -238,12 +303,14 @@ pub enum ContextAndFilterHandler {
     NoFilterTakeContextAsFunctionName(WpContext),
     FilterTakeContextAsFunctionName(WpContext, FilterByType),
     FilterNoContext(FilterByType),
+    EmbedTakeContextAsFunctionName(WpContext, EmbedByType),
 }
 
 impl ContextAndFilterHandler {
     fn from_request_type(
         request_type: RequestType,
         filter_by_type: Option<FilterByType>,
+        embed_by_type: Option<EmbedByType>,
     ) -> Vec<Self> {
         match request_type {
             crate::parse::RequestType::Get => {
@@ -263,6 +330,12 @@ impl ContextAndFilterHandler {
                             filter_by_type.clone(),
                         ));
                     }
+                    if let Some(ref embed_by_type) = embed_by_type {
+                        v.push(Self::EmbedTakeContextAsFunctionName(
+                            context,
+                            embed_by_type.clone(),
+                        ));
+                    }
                 });
                 v
             }
@@ -339,9 +412,10 @@ impl Config {
 pub struct ConfigStaticTypes {
     pub api_base_url: TokenStream,
     pub api_endpoint_url: TokenStream,
+    pub authentication_provider: TokenStream,
     pub inner_request_builder: TokenStream,
-    pub wp_authentication: TokenStream,
     pub wp_network_request: TokenStream,
+    pub wp_response: TokenStream,
 }
 
 impl ConfigStaticTypes {
@@ -349,9 +423,10 @@ impl ConfigStaticTypes {
         Self {
             api_base_url: quote! { std::sync::Arc<#crate_ident::request::endpoint::ApiBaseUrl> },
             api_endpoint_url: quote! { #crate_ident::request::endpoint::ApiEndpointUrl },
+            authentication_provider: quote! { std::sync::Arc<dyn #crate_ident::authentication::AuthenticationProvider> },
             inner_request_builder: quote! { #crate_ident::request::InnerRequestBuilder },
-            wp_authentication: quote! { #crate_ident::WpAuthentication },
             wp_network_request: quote! { #crate_ident::request::WpNetworkRequest },
+            wp_response: quote! { #crate_ident::request::WpResponse },
         }
     }
 }
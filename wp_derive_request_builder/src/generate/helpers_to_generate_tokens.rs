@@ -63,7 +63,8 @@ pub fn output_type(
             })
             .collect::<TokenStream>(),
         ContextAndFilterHandler::FilterNoContext(_) => TokenStream::from_iter(output_token_tree),
-        ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(context) => {
+        ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(context)
+        | ContextAndFilterHandler::EmbedTakeContextAsFunctionName(context, _) => {
             strip_sparse_prefix(output_token_tree, Some(*context))
         }
     }
@@ -77,7 +78,42 @@ pub fn fn_signature(
     request_type: RequestType,
     context_and_filter_handler: &ContextAndFilterHandler,
 ) -> TokenStream {
-    let fn_name = fn_name(variant_ident, context_and_filter_handler);
+    fn_signature_for_fn_name(
+        &fn_name(variant_ident, context_and_filter_handler),
+        part_of,
+        url_parts,
+        params_type,
+        request_type,
+        context_and_filter_handler,
+    )
+}
+
+pub fn fn_signature_with_response(
+    part_of: PartOf,
+    variant_ident: &Ident,
+    url_parts: &[UrlPart],
+    params_type: Option<&ParamsType>,
+    request_type: RequestType,
+    context_and_filter_handler: &ContextAndFilterHandler,
+) -> TokenStream {
+    fn_signature_for_fn_name(
+        &fn_name_with_response(variant_ident, context_and_filter_handler),
+        part_of,
+        url_parts,
+        params_type,
+        request_type,
+        context_and_filter_handler,
+    )
+}
+
+fn fn_signature_for_fn_name(
+    fn_name: &Ident,
+    part_of: PartOf,
+    url_parts: &[UrlPart],
+    params_type: Option<&ParamsType>,
+    request_type: RequestType,
+    context_and_filter_handler: &ContextAndFilterHandler,
+) -> TokenStream {
     let url_params = fn_url_params(url_parts);
     let provided_param = fn_provided_param(part_of, params_type, request_type);
     let fields_param = fn_fields_param(context_and_filter_handler);
@@ -152,6 +188,10 @@ pub fn fn_fields_param(context_and_filter_handler: &ContextAndFilterHandler) ->
             let filter_by_type_token_stream = &filter_by_type.tokens;
             quote! { fields: &[#filter_by_type_token_stream] }
         }
+        ContextAndFilterHandler::EmbedTakeContextAsFunctionName(_, embed_by_type) => {
+            let embed_by_type_token_stream = &embed_by_type.tokens;
+            quote! { embed: &[#embed_by_type_token_stream] }
+        }
     }
 }
 
@@ -177,9 +217,27 @@ pub fn fn_name(
                 context.to_string().to_lowercase()
             )
         }
+        ContextAndFilterHandler::EmbedTakeContextAsFunctionName(context, _) => {
+            format_ident!(
+                "embed_{}_with_{}_context",
+                basic_fn_name,
+                context.to_string().to_lowercase()
+            )
+        }
     }
 }
 
+// For example, given `list_with_edit_context`, it'll be `list_with_edit_context_with_response`
+pub fn fn_name_with_response(
+    variant_ident: &Ident,
+    context_and_filter_handler: &ContextAndFilterHandler,
+) -> Ident {
+    format_ident!(
+        "{}_with_response",
+        fn_name(variant_ident, context_and_filter_handler)
+    )
+}
+
 fn fn_arg_url_parts(url_parts: &[UrlPart]) -> TokenStream {
     url_parts
         .iter()
@@ -222,6 +280,7 @@ fn fn_arg_fields(context_and_filter_handler: &ContextAndFilterHandler) -> TokenS
         | ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(_) => TokenStream::new(),
         ContextAndFilterHandler::FilterTakeContextAsFunctionName(_, _)
         | ContextAndFilterHandler::FilterNoContext(_) => quote! { fields, },
+        ContextAndFilterHandler::EmbedTakeContextAsFunctionName(_, _) => quote! { embed, },
     }
 }
 
@@ -314,7 +373,8 @@ pub fn fn_body_fields_query_pairs(
 ) -> TokenStream {
     match context_and_filter_handler {
         ContextAndFilterHandler::None
-        | ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(_) => TokenStream::new(),
+        | ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(_)
+        | ContextAndFilterHandler::EmbedTakeContextAsFunctionName(_, _) => TokenStream::new(),
         ContextAndFilterHandler::FilterTakeContextAsFunctionName(_, _)
         | ContextAndFilterHandler::FilterNoContext(_) => quote! {
             use #crate_ident::SparseField;
@@ -331,6 +391,29 @@ pub fn fn_body_fields_query_pairs(
     }
 }
 
+// WordPress embeds all the link relations for `_embed=1`, so an empty `embed` list embeds everything
+pub fn fn_body_embed_query_pairs(
+    context_and_filter_handler: &ContextAndFilterHandler,
+) -> TokenStream {
+    match context_and_filter_handler {
+        ContextAndFilterHandler::None
+        | ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(_)
+        | ContextAndFilterHandler::FilterTakeContextAsFunctionName(_, _)
+        | ContextAndFilterHandler::FilterNoContext(_) => TokenStream::new(),
+        ContextAndFilterHandler::EmbedTakeContextAsFunctionName(_, _) => quote! {
+            let embed = embed
+                .iter()
+                .map(|r| r.as_str())
+                .collect::<Vec<&str>>()
+                .join(",");
+            url.query_pairs_mut().append_pair(
+                "_embed",
+                if embed.is_empty() { "1" } else { embed.as_str() },
+            );
+        },
+    }
+}
+
 pub fn fn_body_context_query_pairs(
     crate_ident: &Ident,
     context_and_filter_handler: &ContextAndFilterHandler,
@@ -340,7 +423,8 @@ pub fn fn_body_context_query_pairs(
             TokenStream::new()
         }
         ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(context)
-        | ContextAndFilterHandler::FilterTakeContextAsFunctionName(context, ..) => {
+        | ContextAndFilterHandler::FilterTakeContextAsFunctionName(context, ..)
+        | ContextAndFilterHandler::EmbedTakeContextAsFunctionName(context, ..) => {
             let context = format_ident!("{}", context.to_string());
             quote! {
                 url.query_pairs_mut().append_pair("context", #crate_ident::WpContext::#context.as_str());
@@ -388,7 +472,7 @@ pub fn fn_body_get_request_from_request_builder(
     let fn_arg_fields = fn_arg_fields(context_and_filter_handler);
 
     quote! {
-        let request = self.request_builder.#fn_name(#fn_arg_url_parts #fn_arg_provided_params #fn_arg_fields);
+        let request = self.request_builder.#fn_name(#fn_arg_url_parts #fn_arg_provided_params #fn_arg_fields)?;
     }
 }
 
@@ -396,7 +480,7 @@ pub fn fn_body_get_request_from_request_builder(
 mod tests {
     #![allow(clippy::too_many_arguments)]
     use super::*;
-    use crate::variant_attr::FilterByType;
+    use crate::variant_attr::{EmbedByType, FilterByType};
     use rstest::rstest;
     use syn::parse_quote;
 
@@ -470,6 +554,11 @@ mod tests {
         filter_take_context_as_argument(),
         "filter_list_with_edit_context"
     )]
+    #[case(
+        "Retrieve",
+        embed_take_context_as_argument(),
+        "embed_retrieve_with_view_context"
+    )]
     fn test_fn_name(
         #[case] ident: &str,
         #[case] context_and_filter_handler: ContextAndFilterHandler,
@@ -481,6 +570,30 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("Create", ContextAndFilterHandler::None, "create_with_response")]
+    #[case(
+        "List",
+        ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(WpContext::Edit),
+        "list_with_edit_context_with_response"
+    )]
+    #[case(
+        "List",
+        filter_take_context_as_argument(),
+        "filter_list_with_edit_context_with_response"
+    )]
+    fn test_fn_name_with_response(
+        #[case] ident: &str,
+        #[case] context_and_filter_handler: ContextAndFilterHandler,
+        #[case] expected_str: &str,
+    ) {
+        assert_eq!(
+            fn_name_with_response(&format_ident!("{}", ident), &context_and_filter_handler)
+                .to_string(),
+            expected_str
+        );
+    }
+
     #[rstest]
     #[case(ContextAndFilterHandler::None, "")]
     #[case(
@@ -492,6 +605,10 @@ mod tests {
         "fields : & [crate :: SparseUserFieldWithEditContext]"
     )]
     #[case(filter_no_context(), "fields : & [crate :: SparseUserField]")]
+    #[case(
+        embed_take_context_as_argument(),
+        "embed : & [crate :: embed :: WpEmbedRelation]"
+    )]
     fn test_fn_fields_param(
         #[case] context_and_filter_handler: ContextAndFilterHandler,
         #[case] expected_str: &str,
@@ -574,6 +691,7 @@ mod tests {
     )]
     #[case(filter_take_context_as_argument(), "fields ,")]
     #[case(filter_no_context(), "fields ,")]
+    #[case(embed_take_context_as_argument(), "embed ,")]
     fn test_fn_arg_fields(
         #[case] context_and_filter_handler: ContextAndFilterHandler,
         #[case] expected_str: &str,
@@ -604,6 +722,7 @@ mod tests {
     )]
     #[case(parse_quote!(SparseUser), filter_take_context_as_argument(), "SparseUserWithEditContext")]
     #[case(parse_quote!(Vec<SparseUser>), filter_no_context(), "Vec < SparseUser >")]
+    #[case(parse_quote!(Vec<SparseUser>), embed_take_context_as_argument(), "Vec < UserWithViewContext >")]
     fn test_output_type(
         #[case] output_token_stream: TokenStream,
         #[case] context_and_filter_handler: ContextAndFilterHandler,
@@ -787,6 +906,22 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_fn_signature_with_response() {
+        assert_eq!(
+            fn_signature_with_response(
+                PartOf::RequestExecutor,
+                &format_ident!("List"),
+                &url_static_users(),
+                referenced_params_type("UserListParams").as_ref(),
+                RequestType::ContextualGet,
+                &ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(WpContext::Edit),
+            )
+            .to_string(),
+            "fn list_with_edit_context_with_response (& self , params : & UserListParams ,)"
+        );
+    }
+
     #[rstest]
     #[case(
         format_ident!("Create"),
@@ -954,6 +1089,7 @@ mod tests {
     )]
     #[case(filter_take_context_as_argument(), false)]
     #[case(filter_no_context(), false)]
+    #[case(embed_take_context_as_argument(), true)]
     fn test_fn_body_fields_query_pairs(
         #[case] context_and_filter_handler: ContextAndFilterHandler,
         #[case] is_empty: bool,
@@ -967,6 +1103,26 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(ContextAndFilterHandler::None, true)]
+    #[case(
+        ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(WpContext::Edit),
+        true
+    )]
+    #[case(filter_take_context_as_argument(), true)]
+    #[case(filter_no_context(), true)]
+    #[case(embed_take_context_as_argument(), false)]
+    fn test_fn_body_embed_query_pairs(
+        #[case] context_and_filter_handler: ContextAndFilterHandler,
+        #[case] is_empty: bool,
+    ) {
+        // Test if the `_embed` query pair is included or not
+        assert_eq!(
+            fn_body_embed_query_pairs(&context_and_filter_handler).is_empty(),
+            is_empty
+        );
+    }
+
     #[rstest]
     #[case(ContextAndFilterHandler::None, "")]
     #[case(
@@ -978,6 +1134,10 @@ mod tests {
         "url . query_pairs_mut () . append_pair (\"context\" , crate :: WpContext :: Edit . as_str ()) ;"
     )]
     #[case(filter_no_context(), "")]
+    #[case(
+        embed_take_context_as_argument(),
+        "url . query_pairs_mut () . append_pair (\"context\" , crate :: WpContext :: View . as_str ()) ;"
+    )]
     fn test_fn_body_context_query_pairs(
         #[case] context_and_filter_handler: ContextAndFilterHandler,
         #[case] expected_str: &str,
@@ -1026,77 +1186,77 @@ mod tests {
         referenced_params_type("UserCreateParams"),
         RequestType::Post,
         ContextAndFilterHandler::None,
-        "let request = self . request_builder . create (params ,) ;")]
+        "let request = self . request_builder . create (params ,) ? ;")]
     #[case(
         format_ident!("Create"),
         url_static_users(),
         referenced_params_type("UserCreateParams"),
         RequestType::Post,
         filter_no_context(),
-        "let request = self . request_builder . filter_create (params , fields ,) ;")]
+        "let request = self . request_builder . filter_create (params , fields ,) ? ;")]
     #[case(
         format_ident!("Delete"),
         url_users_with_user_id(),
         referenced_params_type("UserDeleteParams"),
         RequestType::Delete,
         ContextAndFilterHandler::None,
-        "let request = self . request_builder . delete (user_id , params ,) ;")]
+        "let request = self . request_builder . delete (user_id , params ,) ? ;")]
     #[case(
         format_ident!("Delete"),
         url_users_with_user_id(),
         referenced_params_type("UserDeleteParams"),
         RequestType::Delete,
         filter_no_context(),
-        "let request = self . request_builder . filter_delete (user_id , params , fields ,) ;")]
+        "let request = self . request_builder . filter_delete (user_id , params , fields ,) ? ;")]
     #[case(
         format_ident!("DeleteMe"),
         url_static_users(),
         referenced_params_type("UserDeleteParams"),
         RequestType::Delete,
         ContextAndFilterHandler::None,
-        "let request = self . request_builder . delete_me (params ,) ;")]
+        "let request = self . request_builder . delete_me (params ,) ? ;")]
     #[case(
         format_ident!("List"),
         url_static_users(),
         referenced_params_type("UserListParams"),
         RequestType::ContextualGet,
         ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(WpContext::Edit),
-        "let request = self . request_builder . list_with_edit_context (params ,) ;")]
+        "let request = self . request_builder . list_with_edit_context (params ,) ? ;")]
     #[case(
         format_ident!("List"),
         url_static_users(),
         referenced_params_type("UserListParams"),
         RequestType::ContextualGet,
         filter_take_context_as_argument(),
-        "let request = self . request_builder . filter_list_with_edit_context (params , fields ,) ;")]
+        "let request = self . request_builder . filter_list_with_edit_context (params , fields ,) ? ;")]
     #[case(
         format_ident!("Retrieve"),
         url_users_with_user_id(),
         None,
         RequestType::ContextualGet,
         ContextAndFilterHandler::NoFilterTakeContextAsFunctionName(WpContext::Embed),
-        "let request = self . request_builder . retrieve_with_embed_context (user_id ,) ;")]
+        "let request = self . request_builder . retrieve_with_embed_context (user_id ,) ? ;")]
     #[case(
         format_ident!("Retrieve"),
         url_users_with_user_id(),
         None,
         RequestType::ContextualGet,
         filter_take_context_as_argument(),
-        "let request = self . request_builder . filter_retrieve_with_edit_context (user_id , fields ,) ;")]
+        "let request = self . request_builder . filter_retrieve_with_edit_context (user_id , fields ,) ? ;")]
     #[case(
         format_ident!("Update"),
         url_users_with_user_id(),
         referenced_params_type("UserUpdateParams"),
         RequestType::Post,
         ContextAndFilterHandler::None,
-        "let request = self . request_builder . update (user_id , params ,) ;")]
+        "let request = self . request_builder . update (user_id , params ,) ? ;")]
     #[case(
         format_ident!("Update"),
         url_users_with_user_id(),
         referenced_params_type("UserUpdateParams"),
         RequestType::Post,
         filter_no_context(),
-        "let request = self . request_builder . filter_update (user_id , params , fields ,) ;")]
+        "let request = self . request_builder . filter_update (user_id , params , fields ,) ? ;")]
     fn test_fn_body_get_request_from_request_builder(
         #[case] variant_ident: Ident,
         #[case] url_parts: Vec<UrlPart>,
@@ -1157,4 +1317,13 @@ mod tests {
             tokens: quote! { crate::SparseUserField },
         })
     }
+
+    fn embed_take_context_as_argument() -> ContextAndFilterHandler {
+        ContextAndFilterHandler::EmbedTakeContextAsFunctionName(
+            WpContext::View,
+            EmbedByType {
+                tokens: quote! { crate::embed::WpEmbedRelation },
+            },
+        )
+    }
 }
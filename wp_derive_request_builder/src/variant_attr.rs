@@ -12,6 +12,11 @@ pub struct FilterByType {
     pub tokens: TokenStream,
 }
 
+#[derive(Debug, Clone)]
+pub struct EmbedByType {
+    pub tokens: TokenStream,
+}
+
 #[derive(Debug, Clone)]
 pub struct ParamsType {
     pub tokens: TokenStream,
@@ -24,6 +29,7 @@ pub struct ParsedVariantAttribute {
     pub params: Option<ParamsType>,
     pub output: Vec<TokenTree>,
     pub filter_by: Option<FilterByType>,
+    pub embed_by: Option<EmbedByType>,
 }
 
 impl ParsedVariantAttribute {
@@ -33,6 +39,7 @@ impl ParsedVariantAttribute {
         params: Option<Vec<TokenTree>>,
         output: Vec<TokenTree>,
         filter_by: Option<Vec<TokenTree>>,
+        embed_by: Option<Vec<TokenTree>>,
     ) -> Self {
         let non_empty_token_tree_or_none =
             |tokens: Option<Vec<TokenTree>>| -> Option<Vec<TokenTree>> {
@@ -55,6 +62,9 @@ impl ParsedVariantAttribute {
             filter_by: non_empty_token_tree_or_none(filter_by).map(|tokens| FilterByType {
                 tokens: TokenStream::from_iter(tokens),
             }),
+            embed_by: non_empty_token_tree_or_none(embed_by).map(|tokens| EmbedByType {
+                tokens: TokenStream::from_iter(tokens),
+            }),
         }
     }
 
@@ -236,6 +246,7 @@ impl Parse for ParsedVariantAttribute {
         let mut params_tokens = None;
         let mut output_tokens = None;
         let mut filter_by_tokens = None;
+        let mut embed_by_tokens = None;
 
         for (ident, tokens) in pair_vec.into_iter() {
             match ident.to_string().as_str() {
@@ -243,6 +254,7 @@ impl Parse for ParsedVariantAttribute {
                 "params" => params_tokens = Some(tokens),
                 "output" => output_tokens = Some(tokens),
                 "filter_by" => filter_by_tokens = Some(tokens),
+                "embed_by" => embed_by_tokens = Some(tokens),
                 _ => {
                     return Err(ItemVariantAttributeParseError::ExpectingKeyValuePairs
                         .into_syn_error(meta_list_span));
@@ -284,6 +296,7 @@ impl Parse for ParsedVariantAttribute {
             params_tokens,
             output,
             filter_by_tokens,
+            embed_by_tokens,
         ))
     }
 }
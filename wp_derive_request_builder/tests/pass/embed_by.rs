@@ -0,0 +1,7 @@
+#[derive(wp_derive_request_builder::WpDerivedRequest)]
+enum PostsRequest {
+    #[contextual_get(url = "/posts", params = &PostListParams, output = Vec<SparsePost>, filter_by = SparsePostField, embed_by = WpEmbedRelation)]
+    List,
+}
+
+fn main() {}